@@ -0,0 +1,6 @@
+#define va_list __builtin_va_list
+#define __gnuc_va_list __builtin_va_list
+#define va_start __builtin_va_start
+#define va_arg __builtin_va_arg
+#define va_end __builtin_va_end
+#define va_copy __builtin_va_copy
@@ -3,10 +3,151 @@ use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
 
-use crate::typecell::{get_raw_type, Type};
+use crate::typecell::{get_raw_type, RawType, Type, TypeCell};
 
 const UNSUPPORTED_REG_SIZE: &str = "unsupported register size";
-const I32I8: &str = "\tmovsbl eax, al";
+
+// rax の値をそれぞれの型の値として 64bit 全体に符号拡張/ゼロ拡張する命令
+const I8: &str = "\tmovsx rax, al";
+const I16: &str = "\tmovsx rax, ax";
+const I32: &str = "\tmovsxd rax, eax";
+const U8: &str = "\tmovzx eax, al";
+const U16: &str = "\tmovzx eax, ax";
+const U32: &str = "\tmov eax, eax";
+
+// 浮動小数点数は xmm0 に保持する: 整数との変換は rax を経由する
+const I2F: &str = "\tcvtsi2ss xmm0, rax";
+const I2D: &str = "\tcvtsi2sd xmm0, rax";
+const F2D: &str = "\tcvtss2sd xmm0, xmm0";
+const D2F: &str = "\tcvtsd2ss xmm0, xmm0";
+
+// unsigned long は最上位ビットが立っていると符号付きとして変換されてしまうので、半分にしてから変換して2倍する(奇数の場合は最下位ビットを残して丸めを合わせる)
+const U2F: &str = "\ttest rax, rax
+\tjs 1f
+\tcvtsi2ss xmm0, rax
+\tjmp 2f
+1:
+\tmov rdi, rax
+\tand eax, 1
+\tshr rdi
+\tor rdi, rax
+\tcvtsi2ss xmm0, rdi
+\taddss xmm0, xmm0
+2:";
+const U2D: &str = "\ttest rax, rax
+\tjs 1f
+\tcvtsi2sd xmm0, rax
+\tjmp 2f
+1:
+\tmov rdi, rax
+\tand eax, 1
+\tshr rdi
+\tor rdi, rax
+\tcvtsi2sd xmm0, rdi
+\taddsd xmm0, xmm0
+2:";
+
+// long double は x87 のスタックトップ st(0) に保持する: 他の型との変換はメモリを経由する
+const I2LD: &str = "\tpush rax\n\tfild QWORD PTR [rsp]\n\tadd rsp, 8";
+// 最上位ビットが立っている場合は符号付きとして読み込まれるので 2^64 (float で 0x5f800000) を足す
+const U2LD: &str = "\tpush rax
+\tfild QWORD PTR [rsp]
+\tadd rsp, 8
+\ttest rax, rax
+\tjns 1f
+\tpush 0x5f800000
+\tfadd DWORD PTR [rsp]
+\tadd rsp, 8
+1:";
+const F2LD: &str = "\tsub rsp, 8\n\tmovss DWORD PTR [rsp], xmm0\n\tfld DWORD PTR [rsp]\n\tadd rsp, 8";
+const D2LD: &str = "\tsub rsp, 8\n\tmovsd QWORD PTR [rsp], xmm0\n\tfld QWORD PTR [rsp]\n\tadd rsp, 8";
+const LD2F: &str = "\tsub rsp, 8\n\tfstp DWORD PTR [rsp]\n\tmovss xmm0, DWORD PTR [rsp]\n\tadd rsp, 8";
+const LD2D: &str = "\tsub rsp, 8\n\tfstp QWORD PTR [rsp]\n\tmovsd xmm0, QWORD PTR [rsp]\n\tadd rsp, 8";
+
+/// st(0) を 0 方向に切り捨てて rax に読み込む(一時的に x87 の丸めモードを切り捨てに変更する)
+macro_rules! ld_trunc {
+    () => {
+        "\tsub rsp, 16
+\tfnstcw WORD PTR [rsp]
+\tmovzx eax, WORD PTR [rsp]
+\tor ah, 12
+\tmov WORD PTR [rsp+2], ax
+\tfldcw WORD PTR [rsp+2]
+\tfistp QWORD PTR [rsp+8]
+\tfldcw WORD PTR [rsp]
+\tmov rax, QWORD PTR [rsp+8]
+\tadd rsp, 16"
+    };
+}
+const LD2I8: &str = concat!(ld_trunc!(), "\n\tmovsx rax, al");
+const LD2I16: &str = concat!(ld_trunc!(), "\n\tmovsx rax, ax");
+const LD2I32: &str = concat!(ld_trunc!(), "\n\tmovsxd rax, eax");
+const LD2I64: &str = ld_trunc!();
+const LD2U8: &str = concat!(ld_trunc!(), "\n\tmovzx eax, al");
+const LD2U16: &str = concat!(ld_trunc!(), "\n\tmovzx eax, ax");
+const LD2U32: &str = concat!(ld_trunc!(), "\n\tmov eax, eax");
+// 2^63 (float で 0x5f000000) 以上の値は 2^63 を引いてから変換し、最上位ビットを立てる
+const LD2U64: &str = concat!(
+    "\tpush 0x5f000000
+\tfld DWORD PTR [rsp]
+\tadd rsp, 8
+\tfxch st(1)
+\tfcomi st(0), st(1)
+\tjae 1f
+\tfstp st(1)
+",
+    ld_trunc!(),
+    "
+\tjmp 2f
+1:
+\tfsub st(0), st(1)
+\tfstp st(1)
+",
+    ld_trunc!(),
+    "
+\tbtc rax, 63
+2:"
+);
+
+// 0 方向に切り捨てて rax に整数として読み込み、型に合わせて拡張し直す
+const F2I8: &str = "\tcvttss2si rax, xmm0\n\tmovsx rax, al";
+const F2I16: &str = "\tcvttss2si rax, xmm0\n\tmovsx rax, ax";
+const F2I32: &str = "\tcvttss2si rax, xmm0\n\tmovsxd rax, eax";
+const F2I64: &str = "\tcvttss2si rax, xmm0";
+const F2U8: &str = "\tcvttss2si rax, xmm0\n\tmovzx eax, al";
+const F2U16: &str = "\tcvttss2si rax, xmm0\n\tmovzx eax, ax";
+const F2U32: &str = "\tcvttss2si rax, xmm0\n\tmov eax, eax";
+const D2I8: &str = "\tcvttsd2si rax, xmm0\n\tmovsx rax, al";
+const D2I16: &str = "\tcvttsd2si rax, xmm0\n\tmovsx rax, ax";
+const D2I32: &str = "\tcvttsd2si rax, xmm0\n\tmovsxd rax, eax";
+const D2I64: &str = "\tcvttsd2si rax, xmm0";
+const D2U8: &str = "\tcvttsd2si rax, xmm0\n\tmovzx eax, al";
+const D2U16: &str = "\tcvttsd2si rax, xmm0\n\tmovzx eax, ax";
+const D2U32: &str = "\tcvttsd2si rax, xmm0\n\tmov eax, eax";
+
+// 2^63 以上の値は 2^63 を引いてから変換し、最上位ビットを立てる
+const F2U64: &str = "\tmov eax, 0x5f000000
+\tmovd xmm1, eax
+\tcomiss xmm0, xmm1
+\tjae 1f
+\tcvttss2si rax, xmm0
+\tjmp 2f
+1:
+\tsubss xmm0, xmm1
+\tcvttss2si rax, xmm0
+\tbtc rax, 63
+2:";
+const D2U64: &str = "\tmov rax, 0x43e0000000000000
+\tmovq xmm1, rax
+\tcomisd xmm0, xmm1
+\tjae 1f
+\tcvttsd2si rax, xmm0
+\tjmp 2f
+1:
+\tsubsd xmm0, xmm1
+\tcvttsd2si rax, xmm0
+\tbtc rax, 63
+2:";
 
 pub static ASMCODE: Lazy<Mutex<String>> =
     Lazy::new(|| Mutex::new("\t.intel_syntax noprefix\n\t.text\n.LText0:\n".to_string()));
@@ -14,7 +155,8 @@ pub static ASMCODE: Lazy<Mutex<String>> =
 pub static ARGS_REGISTERS: Lazy<Mutex<HashMap<usize, Vec<&str>>>> = Lazy::new(|| {
     let mut map = HashMap::new();
     let _ = map.insert(1, vec!["dil", "sil", "dl", "cl", "r8b", "r9b"]);
-    let _ = map.insert(4, vec!["edi", "esi", "edx", "rcx", "r8d", "r9d"]);
+    let _ = map.insert(2, vec!["di", "si", "dx", "cx", "r8w", "r9w"]);
+    let _ = map.insert(4, vec!["edi", "esi", "edx", "ecx", "r8d", "r9d"]);
     let _ = map.insert(8, vec!["rdi", "rsi", "rdx", "rcx", "r8", "r9"]);
     Mutex::new(map)
 });
@@ -23,12 +165,21 @@ static CTRL_COUNT: Lazy<Mutex<u32>> = Lazy::new(|| Mutex::new(0));
 static FUNC_COUNT: Lazy<Mutex<u32>> = Lazy::new(|| Mutex::new(0));
 
 /// キャストが生じる場合の操作をクエリするためのテーブル
+/// rax の値は常にその型に応じて 64bit 全体に拡張された状態に保つので、キャスト先の型で値が変わりうる場合のみ拡張し直せば良い
 pub static CAST_TABLE: Lazy<Mutex<Vec<Vec<&str>>>> = Lazy::new(|| {
     Mutex::new(vec![
-        //	I8		I32		U64
-        vec!["", I32I8, I32I8], // I8
-        vec![I32I8, "", ""],    // I32
-        vec![I32I8, "", ""],    // U64
+        //	I8	I16	I32	I64	U8	U16	U32	U64	F32	F64	F80
+        vec!["", "", "", "", U8, U16, U32, "", I2F, I2D, I2LD], // I8
+        vec![I8, "", "", "", U8, U16, U32, "", I2F, I2D, I2LD], // I16
+        vec![I8, I16, "", "", U8, U16, U32, "", I2F, I2D, I2LD], // I32
+        vec![I8, I16, I32, "", U8, U16, U32, "", I2F, I2D, I2LD], // I64
+        vec![I8, "", "", "", "", "", "", "", I2F, I2D, I2LD], // U8
+        vec![I8, I16, "", "", U8, "", "", "", I2F, I2D, I2LD], // U16
+        vec![I8, I16, I32, "", U8, U16, "", "", I2F, I2D, I2LD], // U32
+        vec![I8, I16, I32, "", U8, U16, U32, "", U2F, U2D, U2LD], // U64
+        vec![F2I8, F2I16, F2I32, F2I64, F2U8, F2U16, F2U32, F2U64, "", F2D, F2LD], // F32
+        vec![D2I8, D2I16, D2I32, D2I64, D2U8, D2U16, D2U32, D2U64, D2F, "", D2LD], // F64
+        vec![LD2I8, LD2I16, LD2I32, LD2I64, LD2U8, LD2U16, LD2U32, LD2U64, LD2F, LD2D, ""], // F80
     ])
 });
 
@@ -85,24 +236,94 @@ pub fn word_ptr(size: usize) -> &'static str {
     }
 }
 
-pub fn cast(from: Type, to: Type) {
+pub fn cast(from: &TypeCell, to: &TypeCell) {
+    // void へのキャストは値を捨てるだけ(x87 のスタックに積まれた値は取り除く)
+    if to.is_void() {
+        if from.typ == Type::LDouble {
+            use crate::asm_write;
+            asm_write!("\tfstp st(0)");
+        }
+        return;
+    }
+
+    // _Bool への変換では、 0 以外の値を全て 1 にする
+    if to.typ == Type::Bool && from.typ == Type::LDouble {
+        use crate::asm_write;
+        asm_write!("\tfldz");
+        asm_write!("\tfucomip st(0), st(1)");
+        asm_write!("\tfstp st(0)");
+        asm_write!("\tsetne al");
+        asm_write!("\tsetp dl");
+        asm_write!("\tor al, dl");
+        asm_write!("{}", U8);
+        return;
+    }
+    if to.typ == Type::Bool && from.is_floating() {
+        // NaN も 0 ではないので 1 とする
+        use crate::asm_write;
+        asm_write!("\txorps xmm1, xmm1");
+        asm_write!("\tucomi{} xmm0, xmm1", fp_suffix(from));
+        asm_write!("\tsetne al");
+        asm_write!("\tsetp dl");
+        asm_write!("\tor al, dl");
+        asm_write!("{}", U8);
+        return;
+    }
+    if to.typ == Type::Bool && from.typ != Type::Bool {
+        use crate::asm_write;
+        asm_write!("\tcmp rax, 0");
+        asm_write!("\tsetne al");
+        asm_write!("{}", U8);
+        return;
+    }
+
     let t1 = get_raw_type(from) as usize;
     let t2 = get_raw_type(to) as usize;
     let cast_access = CAST_TABLE.try_lock().unwrap();
     let cast_asm = cast_access[t1][t2];
-    if cast_asm != "" {
+    if !cast_asm.is_empty() {
         use crate::asm_write;
         asm_write!("{}", cast_asm);
     }
 }
 
-// 現在は unsigned なデータ型を扱わないので movsx のみで OK
+/// 32bit 以下のレジスタで計算した結果を、 typ の値として rax 全体に拡張し直す
+pub fn extend(typ: &TypeCell) {
+    let extension = match get_raw_type(typ) {
+        RawType::I8 => I8,
+        RawType::I16 => I16,
+        RawType::I32 => I32,
+        RawType::U8 => U8,
+        RawType::U16 => U16,
+        RawType::U32 => U32,
+        RawType::I64 | RawType::U64 | RawType::F32 | RawType::F64 | RawType::F80 => "",
+    };
+    if !extension.is_empty() {
+        use crate::asm_write;
+        asm_write!("{}", extension);
+    }
+}
+
+/// 浮動小数点数の命令のサフィックス(float なら ss, double なら sd)
+#[inline]
+pub fn fp_suffix(typ: &TypeCell) -> &'static str {
+    match typ.typ {
+        Type::Float => "ss",
+        Type::Double => "sd",
+        _ => panic!("{} is not a floating type.", typ.typ),
+    }
+}
+
+// メモリから値を読む時点で rax 全体に符号拡張/ゼロ拡張しておく
 #[macro_export]
 macro_rules! mov_op {
-    ($size:expr) => {
-        match $size {
-            1 => "movsx",
-            _ => "mov",
+    ($size:expr, $is_unsigned:expr) => {
+        match ($size, $is_unsigned) {
+            (1, false) | (2, false) => ("movsx", "rax"),
+            (1, true) | (2, true) => ("movzx", "eax"),
+            (4, false) => ("movsxd", "rax"),
+            (4, true) => ("mov", "eax"),
+            _ => ("mov", "rax"),
         }
     };
 }
@@ -146,25 +367,19 @@ macro_rules! mov_to {
     };
 }
 
+/// 読み込み先は常に rax (unsigned の場合は eax を使うことで上位ビットを 0 にする)
 #[macro_export]
 macro_rules! mov_from {
-    ($size:expr, $operand1:expr, $operand2:expr) => {
+    ($size:expr, $is_unsigned:expr, $operand:expr) => {
         let _word = word_ptr($size);
-        let _mov = mov_op!($size);
-        asm_write!("\t{} {}, {} [{}]", _mov, $operand1, _word, $operand2)
+        let (_mov, _ax) = mov_op!($size, $is_unsigned);
+        asm_write!("\t{} {}, {} [{}]", _mov, _ax, _word, $operand)
     };
 
-    ($size:expr, $operand1:expr, $operand2:expr, $offset:expr) => {
+    ($size:expr, $is_unsigned:expr, $operand:expr, $offset:expr) => {
         let _word = word_ptr($size);
-        let _mov = mov_op!($size);
-        asm_write!(
-            "\t{} {}, {} [{}-{}]",
-            _mov,
-            $operand1,
-            _word,
-            $operand2,
-            $offset
-        )
+        let (_mov, _ax) = mov_op!($size, $is_unsigned);
+        asm_write!("\t{} {}, {} [{}-{}]", _mov, _ax, _word, $operand, $offset)
     };
 }
 
@@ -177,9 +392,10 @@ macro_rules! mov_glb_addr {
 
 #[macro_export]
 macro_rules! mov_from_glb {
-    ($size:expr, $operand:expr, $name:expr) => {
+    ($size:expr, $is_unsigned:expr, $name:expr) => {
         let _word = word_ptr($size);
-        asm_write!("\tmov {}, {} {}[rip]", $operand, _word, $name)
+        let (_mov, _ax) = mov_op!($size, $is_unsigned);
+        asm_write!("\t{} {}, {} {}[rip]", _mov, _ax, _word, $name)
     };
 }
 
@@ -219,20 +435,70 @@ macro_rules! lea {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::typecell::Type;
 
     #[test]
     fn cast_test() {
+        let int = TypeCell::new(Type::Int);
+        let ptr = int.make_ptr_to();
+        let chr = TypeCell::new(Type::Char);
+        let uint = int.make_unsigned();
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&int, &int);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), String::new());
+
         ASMCODE.try_lock().unwrap().clear();
-        cast(Type::Int, Type::Int);
+        cast(&int, &ptr);
         assert_eq!(*ASMCODE.try_lock().unwrap(), String::new());
 
         ASMCODE.try_lock().unwrap().clear();
-        cast(Type::Int, Type::Ptr);
+        cast(&chr, &ptr);
         assert_eq!(*ASMCODE.try_lock().unwrap(), String::new());
 
         ASMCODE.try_lock().unwrap().clear();
-        cast(Type::Char, Type::Ptr);
-        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", I32I8));
+        cast(&int, &chr);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", I8));
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&int, &uint);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", U32));
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&chr.make_unsigned(), &int);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), String::new());
+    }
+
+    #[test]
+    fn float_cast_test() {
+        let int = TypeCell::new(Type::Int);
+        let float = TypeCell::new(Type::Float);
+        let double = TypeCell::new(Type::Double);
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&int, &double);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", I2D));
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&float, &double);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", F2D));
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&double, &int);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", D2I32));
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&double, &double);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), String::new());
+
+        let ldouble = TypeCell::new(Type::LDouble);
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&int, &ldouble);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", I2LD));
+
+        ASMCODE.try_lock().unwrap().clear();
+        cast(&ldouble, &double);
+        assert_eq!(*ASMCODE.try_lock().unwrap(), format!("{}\n", LD2D));
     }
 
     #[test]
@@ -0,0 +1,508 @@
+// long double (x87 の 80bit 拡張倍精度浮動小数点数) のコンパイル時計算
+// f64 を経由すると精度が落ちるため、多倍長整数を使って 1 演算ごとに 64bit の仮数部へ丸める
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result};
+
+const EXP_BIAS: i64 = 16383;
+const EXP_MAX: i64 = 0x7fff;
+const MANT_TOP: u64 = 1 << 63;
+
+/// 非負の多倍長整数(32bit ごとのリトルエンディアンで、上位の 0 は持たない)
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct BigUint(Vec<u32>);
+
+impl BigUint {
+    fn from_u128(mut val: u128) -> Self {
+        let mut digits = vec![];
+        while val > 0 {
+            digits.push(val as u32);
+            val >>= 32;
+        }
+        BigUint(digits)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    /// 有効なビット数
+    fn bits(&self) -> i64 {
+        match self.0.last() {
+            Some(top) => 32 * (self.0.len() as i64 - 1) + (32 - top.leading_zeros() as i64),
+            None => 0,
+        }
+    }
+
+    /// self = self * mul + add
+    fn mul_add_small(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for digit in self.0.iter_mut() {
+            let val = *digit as u64 * mul as u64 + carry;
+            *digit = val as u32;
+            carry = val >> 32;
+        }
+        if carry > 0 {
+            self.0.push(carry as u32);
+        }
+    }
+
+    fn shl(&self, n: i64) -> Self {
+        let bits = (n % 32) as u32;
+        let mut digits = vec![0; (n / 32) as usize];
+        let mut carry = 0;
+        for &digit in &self.0 {
+            if bits == 0 {
+                digits.push(digit);
+            } else {
+                digits.push((digit << bits) | carry);
+                carry = digit >> (32 - bits);
+            }
+        }
+        digits.push(carry);
+        let mut shifted = BigUint(digits);
+        shifted.trim();
+        shifted
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut digits = vec![];
+        let mut carry = 0;
+        for i in 0..self.0.len().max(other.0.len()) {
+            let val =
+                *self.0.get(i).unwrap_or(&0) as u64 + *other.0.get(i).unwrap_or(&0) as u64 + carry;
+            digits.push(val as u32);
+            carry = val >> 32;
+        }
+        digits.push(carry as u32);
+        let mut sum = BigUint(digits);
+        sum.trim();
+        sum
+    }
+
+    /// self - other (self >= other であること)
+    fn sub(&self, other: &Self) -> Self {
+        let mut digits = vec![];
+        let mut borrow = 0;
+        for (i, &digit) in self.0.iter().enumerate() {
+            let val = digit as i64 - *other.0.get(i).unwrap_or(&0) as i64 - borrow;
+            borrow = (val < 0) as i64;
+            digits.push((val + (borrow << 32)) as u32);
+        }
+        let mut diff = BigUint(digits);
+        diff.trim();
+        diff
+    }
+
+    /// 商 (128bit に収まること) と、余りが 0 でないかどうかを返す
+    fn div_rem(&self, den: &Self) -> (u128, bool) {
+        let mut rem = self.clone();
+        let mut quot = 0;
+        for i in (0..=(self.bits() - den.bits()).max(0)).rev() {
+            let sub = den.shl(i);
+            if rem >= sub {
+                rem = rem.sub(&sub);
+                quot |= 1 << i;
+            }
+        }
+        (quot, !rem.is_zero())
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+/// x87 の 80bit 拡張倍精度浮動小数点数: 仮数部は整数ビットを明示的に持つ
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Float80 {
+    pub sign: bool, // 負であれば true
+    pub exp: u16,   // バイアス 16383 の指数部(15bit)
+    pub mant: u64,  // 仮数部(正規化数であれば最上位ビットが立つ)
+}
+
+impl Float80 {
+    const INF: Float80 = Float80 {
+        sign: false,
+        exp: EXP_MAX as u16,
+        mant: MANT_TOP,
+    };
+    const NAN: Float80 = Float80 {
+        sign: true,
+        exp: EXP_MAX as u16,
+        mant: 0xc000000000000000,
+    };
+
+    #[inline]
+    fn zero(sign: bool) -> Self {
+        Float80 {
+            sign,
+            exp: 0,
+            mant: 0,
+        }
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.exp == 0 && self.mant == 0
+    }
+
+    #[inline]
+    fn is_inf(&self) -> bool {
+        self.exp as i64 == EXP_MAX && self.mant == MANT_TOP
+    }
+
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.exp as i64 == EXP_MAX && self.mant != MANT_TOP
+    }
+
+    #[inline]
+    pub fn negate(self) -> Self {
+        Float80 {
+            sign: !self.sign,
+            ..self
+        }
+    }
+
+    /// 有限の値を mant * 2^exp の形で返す
+    #[inline]
+    fn split(&self) -> (u64, i64) {
+        (self.mant, (self.exp as i64).max(1) - EXP_BIAS - 63)
+    }
+
+    /// 符号付きの num / den * 2^exp を、最も近い値(同じ距離なら仮数部が偶数の方)に丸める
+    fn round(sign: bool, num: &BigUint, den: &BigUint, exp: i64) -> Self {
+        if num.is_zero() {
+            return Self::zero(sign);
+        }
+        // 商が [2^64, 2^66) に収まるようにずらして割り、丸めに使う 1bit を余分に求める
+        let shift = 65 - (num.bits() - den.bits());
+        let (mut quot, mut sticky) = if shift >= 0 {
+            num.shl(shift).div_rem(den)
+        } else {
+            num.div_rem(&den.shl(-shift))
+        };
+        let mut exp = exp - shift + 1;
+        if quot >> 65 != 0 {
+            sticky |= quot & 1 != 0;
+            quot >>= 1;
+            exp += 1;
+        }
+        let mut biased = exp + EXP_BIAS + 63;
+        if biased <= 0 {
+            // 非正規化数になる場合は、指数部を最小に合わせた分だけ仮数部を削る
+            let lost = 1 - biased;
+            if lost >= 128 {
+                sticky |= quot != 0;
+                quot = 0;
+            } else {
+                sticky |= quot & ((1 << lost) - 1) != 0;
+                quot >>= lost;
+            }
+            biased = 0;
+        }
+        let mut mant = quot >> 1;
+        if quot & 1 != 0 && (sticky || mant & 1 != 0) {
+            mant += 1;
+        }
+        if mant >> 64 != 0 {
+            mant >>= 1;
+            biased += 1;
+        } else if biased == 0 && mant as u64 & MANT_TOP != 0 {
+            biased = 1;
+        }
+        if biased >= EXP_MAX {
+            return Self::INF.with_sign(sign);
+        }
+        Float80 {
+            sign,
+            exp: biased as u16,
+            mant: mant as u64,
+        }
+    }
+
+    #[inline]
+    fn with_sign(self, sign: bool) -> Self {
+        Float80 { sign, ..self }
+    }
+
+    /// 10 進数の浮動小数点数リテラル(サフィックスを含んでもよい)を変換する
+    pub fn parse(body: &str) -> Self {
+        let body = body.trim_end_matches(['f', 'F', 'l', 'L']);
+        let (digits, exp) = match body.find(['e', 'E']) {
+            Some(pos) => (&body[..pos], body[pos + 1..].parse::<i64>().unwrap()),
+            None => (body, 0),
+        };
+        let mut num = BigUint::from_u128(0);
+        let mut exp10 = exp;
+        let mut after_point = false;
+        for c in digits.chars() {
+            if c == '.' {
+                after_point = true;
+                continue;
+            }
+            num.mul_add_small(10, c.to_digit(10).unwrap());
+            if after_point {
+                exp10 -= 1;
+            }
+        }
+        if num.is_zero() {
+            return Self::zero(false);
+        }
+        // 極端な指数は 0 か無限大にしかならない
+        if exp10 > 5000 {
+            return Self::INF;
+        } else if exp10 < -5000 - digits.len() as i64 {
+            return Self::zero(false);
+        }
+        let mut den = BigUint::from_u128(1);
+        for _ in 0..exp10.abs() {
+            if exp10 > 0 {
+                num.mul_add_small(10, 0);
+            } else {
+                den.mul_add_small(10, 0);
+            }
+        }
+        Self::round(false, &num, &den, 0)
+    }
+
+    pub fn from_i64(val: i64) -> Self {
+        Self::round(
+            val < 0,
+            &BigUint::from_u128(val.unsigned_abs() as u128),
+            &BigUint::from_u128(1),
+            0,
+        )
+    }
+
+    pub fn from_u64(val: u64) -> Self {
+        Self::round(
+            false,
+            &BigUint::from_u128(val as u128),
+            &BigUint::from_u128(1),
+            0,
+        )
+    }
+
+    pub fn from_f64(val: f64) -> Self {
+        if val.is_nan() {
+            return Self::NAN;
+        } else if val.is_infinite() {
+            return Self::INF.with_sign(val < 0.0);
+        }
+        let bits = val.to_bits();
+        let exp = ((bits >> 52) & 0x7ff) as i64;
+        let frac = bits & ((1 << 52) - 1);
+        let (mant, exp) = if exp == 0 {
+            (frac, -1074)
+        } else {
+            (frac | (1 << 52), exp - 1075)
+        };
+        Self::round(
+            val.is_sign_negative(),
+            &BigUint::from_u128(mant as u128),
+            &BigUint::from_u128(1),
+            exp,
+        )
+    }
+
+    /// f64 に変換する(仮数部を f64 にしてから 2 のべき乗をかけるので、正規化数の範囲では正確に丸まる)
+    pub fn to_f64(self) -> f64 {
+        if self.is_nan() {
+            return f64::NAN;
+        }
+        let val = if self.is_inf() {
+            f64::INFINITY
+        } else {
+            let (mant, mut exp) = self.split();
+            let mut val = mant as f64;
+            while exp != 0 {
+                let step = exp.clamp(-1000, 1000);
+                val *= 2f64.powi(step as i32);
+                exp -= step;
+            }
+            val
+        };
+        if self.sign {
+            -val
+        } else {
+            val
+        }
+    }
+
+    /// 0 方向に切り捨てて整数にする(範囲外の値は飽和させる)
+    pub fn to_i128(self) -> i128 {
+        if self.is_nan() {
+            return 0;
+        }
+        let (mant, exp) = self.split();
+        let val = if exp >= 64 {
+            i128::MAX
+        } else if exp >= 0 {
+            (mant as i128) << exp
+        } else if exp > -64 {
+            (mant >> -exp) as i128
+        } else {
+            0
+        };
+        if self.sign {
+            -val
+        } else {
+            val
+        }
+    }
+
+    fn add_abs(self, other: Self, sign: bool, subtract: bool) -> Self {
+        let (lmant, lexp) = self.split();
+        let (rmant, rexp) = other.split();
+        let exp = lexp.min(rexp);
+        let left = BigUint::from_u128(lmant as u128).shl(lexp - exp);
+        let right = BigUint::from_u128(rmant as u128).shl(rexp - exp);
+        let one = BigUint::from_u128(1);
+        if !subtract {
+            return Self::round(sign, &left.add(&right), &one, exp);
+        }
+        match left.cmp(&right) {
+            Ordering::Greater => Self::round(sign, &left.sub(&right), &one, exp),
+            Ordering::Less => Self::round(!sign, &right.sub(&left), &one, exp),
+            // 同じ大きさの値の差は +0
+            Ordering::Equal => Self::zero(false),
+        }
+    }
+
+    pub fn add(self, other: Self) -> Self {
+        if self.is_nan() || other.is_nan() {
+            return Self::NAN;
+        } else if self.is_inf() || other.is_inf() {
+            if self.is_inf() && other.is_inf() && self.sign != other.sign {
+                return Self::NAN;
+            }
+            return if self.is_inf() { self } else { other };
+        } else if self.is_zero() && other.is_zero() {
+            return Self::zero(self.sign && other.sign);
+        }
+        self.add_abs(other, self.sign, self.sign != other.sign)
+    }
+
+    pub fn sub(self, other: Self) -> Self {
+        self.add(other.negate())
+    }
+
+    pub fn mul(self, other: Self) -> Self {
+        let sign = self.sign != other.sign;
+        if self.is_nan() || other.is_nan() {
+            return Self::NAN;
+        } else if self.is_inf() || other.is_inf() {
+            if self.is_zero() || other.is_zero() {
+                return Self::NAN;
+            }
+            return Self::INF.with_sign(sign);
+        }
+        let (lmant, lexp) = self.split();
+        let (rmant, rexp) = other.split();
+        Self::round(
+            sign,
+            &BigUint::from_u128(lmant as u128 * rmant as u128),
+            &BigUint::from_u128(1),
+            lexp + rexp,
+        )
+    }
+
+    pub fn div(self, other: Self) -> Self {
+        let sign = self.sign != other.sign;
+        if self.is_nan() || other.is_nan() || (self.is_inf() && other.is_inf()) {
+            return Self::NAN;
+        } else if self.is_inf() {
+            return Self::INF.with_sign(sign);
+        } else if other.is_inf() {
+            return Self::zero(sign);
+        } else if other.is_zero() {
+            if self.is_zero() {
+                return Self::NAN;
+            }
+            return Self::INF.with_sign(sign);
+        }
+        let (lmant, lexp) = self.split();
+        let (rmant, rexp) = other.split();
+        Self::round(
+            sign,
+            &BigUint::from_u128(lmant as u128),
+            &BigUint::from_u128(rmant as u128),
+            lexp - rexp,
+        )
+    }
+
+    /// 符号と指数部をあわせた上位 16bit
+    #[inline]
+    fn sign_exp(&self) -> u16 {
+        ((self.sign as u16) << 15) | self.exp
+    }
+
+    /// メモリ上の 80bit の表現(下位 64bit が仮数部、その上の 16bit が符号と指数部)
+    #[inline]
+    pub fn to_bits(self) -> u128 {
+        ((self.sign_exp() as u128) << 64) | self.mant as u128
+    }
+}
+
+impl Display for Float80 {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:#06x}:{:#018x}", self.sign_exp(), self.mant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Float80;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Float80::parse("0.1L").to_string(),
+            "0x3ffb:0xcccccccccccccccd"
+        );
+        assert_eq!(
+            Float80::parse(".5").to_string(),
+            "0x3ffe:0x8000000000000000"
+        );
+        assert_eq!(
+            Float80::parse("1e4933L").to_string(),
+            "0x7fff:0x8000000000000000"
+        );
+    }
+
+    #[test]
+    fn arith() {
+        let third = Float80::from_i64(1).div(Float80::from_i64(3));
+        assert_eq!(third.to_string(), "0x3ffd:0xaaaaaaaaaaaaaaab");
+        assert_eq!(
+            Float80::from_u64(u64::MAX).to_string(),
+            "0x403e:0xffffffffffffffff"
+        );
+        assert_eq!(
+            Float80::from_i64(0).sub(Float80::from_i64(0)).to_string(),
+            "0x0000:0x0000000000000000"
+        );
+        assert_eq!(Float80::parse("0.1").to_f64(), 0.1);
+        assert_eq!(Float80::parse("7.9").negate().to_i128(), -7);
+    }
+}
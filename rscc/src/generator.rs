@@ -1,11 +1,13 @@
 use crate::{
-    asm::{cast, get_ctrl_count, get_func_count, reg_ax, word_ptr, ARGS_REGISTERS, ASMCODE},
-    asm_write, error_with_node, exit_eprintln, lea, mov, mov_from, mov_from_glb, mov_glb_addr,
-    mov_op, mov_to, movsx,
+    asm::{
+        cast, extend, fp_suffix, get_ctrl_count, get_func_count, reg_ax, word_ptr, ARGS_REGISTERS,
+        ASMCODE,
+    },
+    asm_write, error_with_node, lea, mov, mov_from, mov_from_glb, mov_glb_addr, mov_op, mov_to,
     node::{NodeRef, Nodekind},
     operate,
-    parser::ORDERED_LITERALS,
-    typecell::Type,
+    parser::{ORDERED_FLOATS, ORDERED_LITERALS},
+    typecell::{Type, TypeCell},
 };
 use std::rc::Rc;
 
@@ -18,7 +20,8 @@ pub fn generate(trees: Vec<NodeRef>) {
 
 fn load_literals() {
     let literals_access = ORDERED_LITERALS.try_lock().unwrap();
-    if literals_access.is_empty() {
+    let floats_access = ORDERED_FLOATS.try_lock().unwrap();
+    if literals_access.is_empty() && floats_access.is_empty() {
         return;
     }
 
@@ -27,6 +30,168 @@ fn load_literals() {
         asm_write!("{}:", name);
         asm_write!("\t.string \"{}\"", body);
     }
+
+    // 浮動小数点数はビット列をそのまま置く(long double は 16 バイトのうち下位 10 バイトを使う)
+    for (name, bytes, value) in floats_access.iter() {
+        asm_write!("\t.align {}", bytes);
+        asm_write!("{}:", name);
+        match *bytes {
+            4 => {
+                asm_write!("\t.long {}", value);
+            }
+            8 => {
+                asm_write!("\t.quad {}", value);
+            }
+            _ => {
+                asm_write!("\t.quad {}", *value as u64);
+                asm_write!("\t.quad {}", (*value >> 64) as u64);
+            }
+        }
+    }
+}
+
+/// 浮動小数点数をアドレス operand から xmm0 (long double の場合は x87 のスタック)に読み込む
+fn load_float(typ: &TypeCell, operand: impl Into<String>) {
+    if typ.typ == Type::LDouble {
+        asm_write!("\tfld TBYTE PTR {}", operand.into());
+        return;
+    }
+    let sfx = fp_suffix(typ);
+    asm_write!(
+        "\tmov{} xmm0, {} {}",
+        sfx,
+        word_ptr(typ.bytes()),
+        operand.into()
+    );
+}
+
+/// xmm0 (long double の場合は x87 のスタックから取り除いた値)の浮動小数点数をアドレス operand に書き込む
+fn store_float(typ: &TypeCell, operand: impl Into<String>) {
+    if typ.typ == Type::LDouble {
+        asm_write!("\tfstp TBYTE PTR {}", operand.into());
+        return;
+    }
+    let sfx = fp_suffix(typ);
+    asm_write!(
+        "\tmov{} {} {}, xmm0",
+        sfx,
+        word_ptr(typ.bytes()),
+        operand.into()
+    );
+}
+
+/// System V ABI における引数の渡し方
+enum ArgClass {
+    Integer(usize), // 汎用レジスタで渡す(レジスタの番号)
+    Sse(usize),     // xmm レジスタで渡す(レジスタの番号)
+    Memory(usize),  // スタックで渡す(引数領域の先頭からのオフセット)
+}
+
+/// 引数の型からそれぞれの渡し方を決め、スタックで渡す引数の領域のサイズとともに返す
+fn classify_args(typs: &[TypeCell]) -> (Vec<ArgClass>, usize) {
+    let mut classes = vec![];
+    let (mut int_ix, mut float_ix, mut stack_size) = (0, 0, 0);
+    for typ in typs {
+        if typ.typ == Type::LDouble {
+            // long double は 16 バイトに align してスタックで渡す
+            stack_size = (stack_size + 15) & !15;
+            classes.push(ArgClass::Memory(stack_size));
+            stack_size += 16;
+        } else if typ.is_floating() && float_ix < 8 {
+            classes.push(ArgClass::Sse(float_ix));
+            float_ix += 1;
+        } else if !typ.is_floating() && int_ix < 6 {
+            classes.push(ArgClass::Integer(int_ix));
+            int_ix += 1;
+        } else {
+            // レジスタが足りなくなった引数は、引数の順に 8 バイトずつスタックに並べて渡す
+            classes.push(ArgClass::Memory(stack_size));
+            stack_size += 8;
+        }
+    }
+    (classes, stack_size)
+}
+
+/// 可変長引数をとる関数のプロローグで、 va_list の初期値(gp_offset, fp_offset, overflow_arg_area, reg_save_area)と
+/// 引数の受け渡しに使われうるレジスタの値を rbp - area から始まる領域に保存する
+fn save_va_regs(area: usize, classes: &[ArgClass], stack_size: usize) {
+    let n_ints = classes
+        .iter()
+        .filter(|class| matches!(class, ArgClass::Integer(_)))
+        .count();
+    let n_floats = classes
+        .iter()
+        .filter(|class| matches!(class, ArgClass::Sse(_)))
+        .count();
+    mov_to!(4, "rbp", 8 * n_ints, area);
+    mov_to!(4, "rbp", 48 + 16 * n_floats, area - 4);
+    // 名前付きの引数のうちスタックで渡されたものの直後から、可変長引数がスタックに並んでいる
+    lea!("rax", format!("rbp+{}", 16 + stack_size));
+    mov_to!(8, "rbp", "rax", area - 8);
+    lea!("rax", "rbp", area - 24);
+    mov_to!(8, "rbp", "rax", area - 16);
+    for (ix, reg) in ARGS_REGISTERS
+        .try_lock()
+        .unwrap()
+        .get(&8)
+        .unwrap()
+        .iter()
+        .enumerate()
+    {
+        mov_to!(8, "rbp", reg, area - 24 - 8 * ix);
+    }
+    for ix in 0..8 {
+        asm_write!("\tmovsd QWORD PTR [rbp-{}], xmm{}", area - 72 - 16 * ix, ix);
+    }
+}
+
+/// rax が指す va_list から型 typ の次の可変長引数を取り出し、そのアドレスを rax に入れる
+/// レジスタの保存領域に残りがあればそこから、なければスタックで渡された領域から取り出す
+fn gen_va_arg(typ: &TypeCell) {
+    let c = get_ctrl_count();
+    mov!("rdx", "rax");
+    if typ.typ == Type::LDouble {
+        // long double は常にスタックで、16 バイトに align されて渡される
+        mov!("rax", "QWORD PTR [rdx+8]");
+        operate!("add", "rax", 15);
+        operate!("and", "rax", -16);
+        lea!("rcx", "rax+16");
+        mov!("QWORD PTR [rdx+8]", "rcx");
+        return;
+    }
+    // 整数は gp_offset, 浮動小数点数は fp_offset で保存領域の中の次の位置を表す
+    let (offset_pos, limit, step) = if typ.is_floating() {
+        (4, 176, 16)
+    } else {
+        (0, 48, 8)
+    };
+    mov!("ecx", format!("DWORD PTR [rdx+{}]", offset_pos));
+    operate!("cmp", "ecx", limit - step);
+    operate!("ja", format!(".LVaStack{}", c));
+    mov!("rax", "QWORD PTR [rdx+16]");
+    operate!("add", "rax", "rcx");
+    operate!("add", "ecx", step);
+    mov!(format!("DWORD PTR [rdx+{}]", offset_pos), "ecx");
+    operate!("jmp", format!(".LVaEnd{}", c));
+    asm_write!(".LVaStack{}:", c);
+    mov!("rax", "QWORD PTR [rdx+8]");
+    lea!("rcx", "rax+8");
+    mov!("QWORD PTR [rdx+8]", "rcx");
+    asm_write!(".LVaEnd{}:", c);
+}
+
+/// 文として評価する: 値は使われないので、 long double の値は x87 のスタックから取り除く
+fn gen_stmt(node: &NodeRef) {
+    gen_expr(node);
+    let node = node.borrow();
+    if ![Nodekind::ReturnNd, Nodekind::ZeroClrNd].contains(&node.kind)
+        && node
+            .typ
+            .as_ref()
+            .is_some_and(|typ| typ.typ == Type::LDouble)
+    {
+        operate!("fstp", "st(0)");
+    }
 }
 
 /// 各計算結果が rax に保持された形になるようなコードを出力
@@ -44,7 +209,10 @@ fn gen_expr(node: &NodeRef) {
                 let c = get_func_count();
 
                 asm_write!("\t.text");
-                asm_write!("\t.globl {}", name);
+                // static な関数は内部リンケージなので .globl を付けない
+                if !node.is_static {
+                    asm_write!("\t.globl {}", name);
+                }
                 asm_write!("\t.type {}, @function", name);
                 asm_write!("{}:", name);
                 asm_write!(".LFB{}:", c); // function begin label
@@ -56,51 +224,111 @@ fn gen_expr(node: &NodeRef) {
                 if pull > 0 {
                     operate!("sub", "rsp", pull);
                 }
-
-                // 受け取った引数の挿入: 現在は6つの引数までなのでレジスタから値を持ってくる
-                if node.args.len() > 6 {
-                    exit_eprintln!("現在7つ以上の引数はサポートされていません。");
+                // alloca を使う場合は、確保済みの領域の底(初めはスタックフレームの底)を保持しておく
+                if let Some(bottom) = node.offset {
+                    mov_to!(8, "rbp", "rsp", bottom);
                 }
-                for (ix, arg) in (&node.args).iter().enumerate() {
+
+                // 受け取った引数の挿入: レジスタで渡された引数はレジスタから、それ以外はスタックから値を持ってくる
+                let typs: Vec<TypeCell> = node
+                    .args
+                    .iter()
+                    .map(|arg| arg.borrow().typ.clone().unwrap())
+                    .collect();
+                let (classes, stack_size) = classify_args(&typs);
+                for ((arg, typ), class) in node.args.iter().zip(&typs).zip(&classes) {
                     let offset = *arg.borrow().offset.as_ref().unwrap();
-                    let size = arg.borrow().typ.as_ref().unwrap().bytes();
-                    let arg_reg = ARGS_REGISTERS.try_lock().unwrap().get(&size).unwrap()[ix];
+                    let size = typ.bytes();
+                    match *class {
+                        ArgClass::Integer(ix) => {
+                            let arg_reg =
+                                ARGS_REGISTERS.try_lock().unwrap().get(&size).unwrap()[ix];
+                            mov_to!(size, "rbp", arg_reg, offset);
+                        }
+                        ArgClass::Sse(ix) => {
+                            asm_write!(
+                                "\tmov{} {} [rbp-{}], xmm{}",
+                                fp_suffix(typ),
+                                word_ptr(size),
+                                offset,
+                                ix
+                            );
+                        }
+                        // スタックで渡された引数は、戻りアドレスと退避した rbp の上に並んでいる
+                        ArgClass::Memory(pos) if typ.typ == Type::LDouble => {
+                            load_float(typ, format!("[rbp+{}]", 16 + pos));
+                            store_float(typ, format!("[rbp-{}]", offset));
+                        }
+                        ArgClass::Memory(pos) => {
+                            mov!(
+                                reg_ax(size),
+                                format!("{} [rbp+{}]", word_ptr(size), 16 + pos)
+                            );
+                            mov_to!(size, "rbp", reg_ax(size), offset);
+                        }
+                    }
+                }
 
-                    mov_to!(size, "rbp", arg_reg, offset);
+                if let Some(area) = node.va_area {
+                    save_va_regs(area, &classes, stack_size);
                 }
 
                 // 関数内の文の処理
                 for stmt in node.stmts.as_ref().unwrap().iter() {
-                    gen_expr(stmt);
+                    gen_stmt(stmt);
                 }
                 asm_write!(".LFE{}:", c); // function begin label
                 asm_write!("\t.size {}, .-{}", name, name);
             // 上の stmts の処理で return が書かれることになっているので、エピローグなどはここに書く必要はない
             } else {
-                // 現在はグローバル変数の初期化はサポートしないため、常に .bss で指定
+                // extern 宣言は他の翻訳単位で領域が確保されるため何も出力しない
+                if node.is_extern {
+                    return;
+                }
                 let typ = node.typ.clone().unwrap();
                 let bytes = typ.bytes();
                 let is_initialized = node.init_data.len() > 0;
-                asm_write!("\t.globl {}", name);
-                if is_initialized {
-                    let is_ptr = if typ.is_array() {
-                        typ.get_base_cell().is_pointer()
+                let base_typ = if typ.is_array() {
+                    typ.get_base_cell()
+                } else {
+                    typ.clone()
+                };
+                // 外部リンケージを持つ仮定義は共通シンボルとし、リンカに領域を確保させる
+                if node.is_tentative && !node.is_static && !base_typ.is_const {
+                    asm_write!("\t.comm {},{},{}", name, bytes, typ.var_align());
+                    return;
+                }
+                if !node.is_static {
+                    asm_write!("\t.globl {}", name);
+                }
+                if base_typ.is_const {
+                    // const なグローバル変数は読み込み専用のセクションに置く(アドレスを含む場合はリロケーションが必要なので .data.rel.ro)
+                    if base_typ.is_pointer() && is_initialized {
+                        asm_write!("\t.section .data.rel.ro.local,\"aw\"");
                     } else {
-                        typ.is_pointer()
-                    };
-                    if is_ptr {
+                        asm_write!("\t.section .rodata");
+                    }
+                } else if is_initialized {
+                    if base_typ.is_pointer() {
                         asm_write!("\t.section .data.rel.local");
                     }
                     asm_write!("\t.data");
                 } else {
                     asm_write!("\t.bss");
                 }
+                if typ.var_align() > 1 {
+                    asm_write!("\t.align {}", typ.var_align());
+                }
                 asm_write!("\t.type {}, @object", name);
                 asm_write!("\t.size {}, {}", name, bytes);
                 asm_write!("{}:", name);
                 if node.init_data.len() > 0 {
                     for data in &node.init_data {
-                        if let Some(label) = &data.label {
+                        if let Some(ldouble) = &data.ldouble {
+                            let bits = ldouble.to_bits();
+                            asm_write!("\t.quad {}", bits as u64);
+                            asm_write!("\t.quad {}", (bits >> 64) as u64);
+                        } else if let Some(label) = &data.label {
                             if data.size != 8 {
                                 panic!("something wrong with initializing data size");
                             }
@@ -142,7 +370,31 @@ fn gen_expr(node: &NodeRef) {
             return;
         }
         Nodekind::NumNd => {
-            mov!("rax", node.borrow().val.unwrap());
+            let node = node.borrow();
+            if node.fval.is_some() {
+                // 浮動小数点数のリテラルは .rodata から読む
+                let name = node.name.clone().unwrap();
+                load_float(node.typ.as_ref().unwrap(), format!("{}[rip]", name));
+            } else {
+                mov!("rax", node.val.unwrap());
+            }
+            return;
+        }
+        Nodekind::CondNd => {
+            let c = get_ctrl_count();
+            let els: String = format!(".LCond.Else{}", c);
+            let end: String = format!(".LCond.End{}", c);
+
+            // 選ばれた方の値のみを評価する
+            gen_expr(node.borrow().enter.as_ref().unwrap());
+            operate!("cmp", "rax", 0);
+            operate!("je", els);
+            gen_expr(node.borrow().branch.as_ref().unwrap());
+            operate!("jmp", end);
+
+            asm_write!("{}:", els);
+            gen_expr(node.borrow().els.as_ref().unwrap());
+            asm_write!("{}:", end);
             return;
         }
         Nodekind::LogAndNd => {
@@ -173,21 +425,21 @@ fn gen_expr(node: &NodeRef) {
         }
         Nodekind::LogOrNd => {
             let c = get_ctrl_count();
-            let t_anchor: String = format!(".LLogic.False{}", c);
+            let t_anchor: String = format!(".LLogic.True{}", c);
             let e_anchor: String = format!(".LLogic.End{}", c);
 
-            // && の左側 (short circuit であることに注意)
+            // || の左側 (short circuit であることに注意)
             gen_expr(node.borrow().left.as_ref().unwrap());
             operate!("cmp", "rax", 0);
-            operate!("jne", t_anchor); // 0 なら false ゆえ残りの式の評価はせずに飛ぶ
+            operate!("jne", t_anchor); // 0 でないなら true ゆえ残りの式の評価はせずに飛ぶ
 
-            // && の右側
+            // || の右側
             gen_expr(node.borrow().right.as_ref().unwrap());
             operate!("cmp", "rax", 0);
             operate!("jne", t_anchor);
 
             // false の場合、 rax に 0 をセットして end
-            mov!("rax", 1);
+            mov!("rax", 0);
             operate!("jmp", e_anchor);
 
             asm_write!("{}:", t_anchor);
@@ -206,31 +458,50 @@ fn gen_expr(node: &NodeRef) {
             operate!("movzb", "rax", "al");
             return;
         }
+        Nodekind::NegNd => {
+            gen_expr(node.borrow().left.as_ref().unwrap());
+            // 符号ビットだけを反転する
+            let typ = node.borrow().typ.clone().unwrap();
+            if typ.typ == Type::LDouble {
+                operate!("fchs");
+            } else if typ.typ == Type::Float {
+                mov!("eax", "0x80000000");
+                operate!("movd", "xmm1", "eax");
+                operate!("xorps", "xmm0", "xmm1");
+            } else {
+                mov!("rax", "0x8000000000000000");
+                operate!("movq", "xmm1", "rax");
+                operate!("xorpd", "xmm0", "xmm1");
+            }
+            return;
+        }
         Nodekind::BitNotNd => {
             gen_expr(node.borrow().left.as_ref().unwrap());
             operate!("not", "rax");
+            extend(node.borrow().typ.as_ref().unwrap());
             return;
         }
         Nodekind::LvarNd => {
             // 葉、かつローカル変数なので、あらかじめ代入した値へのアクセスを行う
-            // 配列のみ、それ単体でアドレスとして解釈されるため gen_addr の結果をそのまま使うことにしてスルー
-            let typ = node.borrow().typ.clone();
-            if typ.clone().unwrap().typ != Type::Array {
-                // movsx などで eax を使うことに注意
-                let bytes = typ.unwrap().bytes();
-                let ax = if bytes < 4 { "eax" } else { reg_ax(bytes) };
-
+            // 配列と関数のみ、それ単体でアドレスとして解釈されるため gen_addr の結果をそのまま使うことにしてスルー
+            let typ = node.borrow().typ.clone().unwrap();
+            if typ.is_floating() {
                 if node.borrow().is_local {
                     let offset = node.borrow().offset.unwrap();
-                    mov_from!(bytes, ax, "rbp", offset);
+                    load_float(&typ, format!("[rbp-{}]", offset));
                 } else {
                     let name = node.borrow().name.clone().unwrap();
-                    mov_from_glb!(bytes, ax, name);
+                    load_float(&typ, format!("{}[rip]", name));
                 }
-
-                // rax で push するために、 eax ならば符号拡張が必要(現在は4と8しかサポートしていないためこうなる)
-                if bytes == 4 {
-                    operate!("cdqe");
+            } else if !typ.is_one_of(&[Type::Array, Type::Func]) {
+                // 読み込み時に型に応じて rax 全体へ符号拡張/ゼロ拡張する
+                let bytes = typ.bytes();
+                if node.borrow().is_local {
+                    let offset = node.borrow().offset.unwrap();
+                    mov_from!(bytes, typ.is_unsigned, "rbp", offset);
+                } else {
+                    let name = node.borrow().name.clone().unwrap();
+                    mov_from_glb!(bytes, typ.is_unsigned, name);
                 }
             } else {
                 gen_addr(node);
@@ -245,16 +516,13 @@ fn gen_expr(node: &NodeRef) {
             if left.borrow().kind == Nodekind::AddrNd {
                 gen_expr(left.borrow().left.as_ref().unwrap());
             } else {
-                // 参照を外した後でも配列なのであれば、アドレスが指す値を評価せずそのまま使用する
+                // 参照を外した後でも配列(または関数)なのであれば、アドレスが指す値を評価せずそのまま使用する
                 gen_expr(&left);
-                if node.borrow().typ.as_ref().unwrap().typ != Type::Array {
-                    let left_typ = left.borrow().typ.clone().unwrap();
-                    let bytes = if left_typ.typ == Type::Array {
-                        8
-                    } else {
-                        left_typ.bytes()
-                    };
-                    mov_from!(bytes, "rax", "rax");
+                let typ = node.borrow().typ.clone().unwrap();
+                if typ.is_floating() {
+                    load_float(&typ, "[rax]");
+                } else if !typ.is_one_of(&[Type::Array, Type::Func]) {
+                    mov_from!(typ.bytes(), typ.is_unsigned, "rax");
                 }
             }
             return;
@@ -264,18 +532,7 @@ fn gen_expr(node: &NodeRef) {
             return;
         }
         Nodekind::FunCallNd => {
-            // 引数をレジスタに格納する処理
-            push_args(&node.borrow().args);
-
-            mov!("rax", "rsp");
-            operate!("and", "rsp", "~0x0f"); // 16の倍数に align
-            operate!("sub", "rsp", 8);
-            operate!("push", "rax");
-
-            // この時点で ARGS_REGISTERS に記載の6つのレジスタには引数が入っている必要がある
-            mov!("rax", 0); // 可変長引数をとる際、浮動小数点の数を al に入れる必要があるが、今は浮動小数点がサポートされていないため単に0を入れる
-            operate!("call", node.borrow().name.as_ref().unwrap());
-            operate!("pop", "rsp");
+            gen_funcall(node);
             return;
         }
         Nodekind::AssignNd => {
@@ -286,33 +543,43 @@ fn gen_expr(node: &NodeRef) {
 
             // 上記gen_expr2つでスタックに変数の値を格納すべきアドレスと、代入する値(式の評価値)がこの順で積んであるはずなので2回popして代入する
             let typ = node.borrow().typ.clone().unwrap();
+            operate!("pop", "rdi");
+            if typ.is_floating() {
+                // long double は書き込みで x87 のスタックから取り除かれるので、式の値として複製しておく
+                if typ.typ == Type::LDouble {
+                    operate!("fld", "st(0)");
+                }
+                store_float(&typ, "[rdi]");
+                return;
+            }
             let bytes = if typ.typ == Type::Array {
                 8
             } else {
                 typ.bytes()
             };
-            operate!("pop", "rdi");
             mov_to!(bytes, "rdi", reg_ax(bytes));
             return;
         }
         Nodekind::CastNd => {
             let node = node.borrow();
             let left = node.left.as_ref().unwrap();
-            let from = left.borrow().typ.as_ref().unwrap().typ;
-            let to = node.typ.as_ref().unwrap().typ;
+            let from = left.borrow().typ.clone().unwrap();
+            let to = node.typ.clone().unwrap();
             gen_expr(left);
-            cast(from, to);
+            cast(&from, &to);
             return;
         }
         Nodekind::CommaNd => {
             // 式の評価値として1つ目の結果は捨て、2つめの評価値のみが rax に残る
-            gen_expr(node.borrow().left.as_ref().unwrap());
+            gen_stmt(node.borrow().left.as_ref().unwrap());
             gen_expr(node.borrow().right.as_ref().unwrap());
             return;
         }
         Nodekind::ReturnNd => {
-            // リターンならleftの値を評価してretする。
-            gen_expr(node.borrow().left.as_ref().unwrap());
+            // リターンならleftの値を評価してretする。(void の関数では left がない)
+            if let Some(left) = node.borrow().left.as_ref() {
+                gen_expr(left);
+            }
             mov!("rsp", "rbp");
             operate!("pop", "rbp");
             operate!("ret");
@@ -332,16 +599,16 @@ fn gen_expr(node: &NodeRef) {
 
                 // falseは0なので、cmp rax, 0が真ならelseに飛ぶ
                 operate!("je", els);
-                gen_expr(node.borrow().branch.as_ref().unwrap()); // if(true)の場合の処理
+                gen_stmt(node.borrow().branch.as_ref().unwrap()); // if(true)の場合の処理
                 operate!("jmp", end); // elseを飛ばしてendへ
 
                 // elseの後ろの処理
                 asm_write!("{}:", els);
-                gen_expr(ptr);
+                gen_stmt(ptr);
             } else {
                 // elseがない場合の処理
                 operate!("je", end);
-                gen_expr(node.borrow().branch.as_ref().unwrap());
+                gen_stmt(node.borrow().branch.as_ref().unwrap());
             }
             asm_write!("{}:", end);
             return;
@@ -350,26 +617,47 @@ fn gen_expr(node: &NodeRef) {
             let c: u32 = get_ctrl_count();
             let begin: String = format!(".LBegin{}", c);
             let end: String = format!(".LEnd{}", c);
+            let label = node.borrow().label.unwrap();
 
             asm_write!("{}:", begin);
+            asm_write!(".LContinue{}:", label);
 
             gen_expr(node.borrow().enter.as_ref().unwrap());
             operate!("cmp", "rax", 0); // falseは0なので、cmp rax, 0が真ならエンドに飛ぶ
             operate!("je", end);
 
-            gen_expr(node.borrow().branch.as_ref().unwrap());
+            gen_stmt(node.borrow().branch.as_ref().unwrap());
             operate!("jmp", begin);
 
             asm_write!("{}:", end);
+            asm_write!(".LBreak{}:", label);
+            return;
+        }
+        Nodekind::DoWhileNd => {
+            let c: u32 = get_ctrl_count();
+            let begin: String = format!(".LBegin{}", c);
+            let label = node.borrow().label.unwrap();
+
+            asm_write!("{}:", begin);
+            gen_stmt(node.borrow().branch.as_ref().unwrap());
+
+            // continue は条件の評価に飛ぶ
+            asm_write!(".LContinue{}:", label);
+            gen_expr(node.borrow().enter.as_ref().unwrap());
+            operate!("cmp", "rax", 0); // 真(0でない)なら先頭に戻る
+            operate!("jne", begin);
+
+            asm_write!(".LBreak{}:", label);
             return;
         }
         Nodekind::ForNd => {
             let c: u32 = get_ctrl_count();
             let begin: String = format!(".LBegin{}", c);
             let end: String = format!(".LEnd{}", c);
+            let label = node.borrow().label.unwrap();
 
             if let Some(init) = node.borrow().init.as_ref() {
-                gen_expr(init);
+                gen_stmt(init);
             }
 
             asm_write!("{}:", begin);
@@ -380,19 +668,70 @@ fn gen_expr(node: &NodeRef) {
                 operate!("je", end);
             }
 
-            gen_expr(node.borrow().branch.as_ref().unwrap()); // for文内の処理
+            gen_stmt(node.borrow().branch.as_ref().unwrap()); // for文内の処理
 
+            // continue は routine に飛ぶ
+            asm_write!(".LContinue{}:", label);
             if let Some(routine) = &node.borrow().routine {
-                gen_expr(routine); // インクリメントなどの処理
+                gen_stmt(routine); // インクリメントなどの処理
             }
             operate!("jmp", begin);
 
             asm_write!("{}:", end);
+            asm_write!(".LBreak{}:", label);
+            return;
+        }
+        Nodekind::SwitchNd => {
+            gen_switch(node);
+            return;
+        }
+        Nodekind::CaseNd => {
+            asm_write!(".LCase{}:", node.borrow().label.unwrap());
+            gen_stmt(node.borrow().branch.as_ref().unwrap());
+            return;
+        }
+        Nodekind::LabelNd => {
+            asm_write!(".LLabel{}:", node.borrow().label.unwrap());
+            gen_stmt(node.borrow().branch.as_ref().unwrap());
+            return;
+        }
+        Nodekind::GotoNd => {
+            if let Some(dest) = node.borrow().left.as_ref() {
+                // 計算型 goto はアドレスに間接ジャンプする
+                gen_expr(dest);
+                operate!("jmp", "rax");
+            } else {
+                // 可変長配列のスコープから抜ける場合は、飛ぶ前にスタックを復元する
+                if let Some(restore) = node.borrow().init.as_ref() {
+                    gen_stmt(restore);
+                }
+                operate!("jmp", format!(".LLabel{}", node.borrow().label.unwrap()));
+            }
+            return;
+        }
+        Nodekind::LabelAddrNd => {
+            lea!(
+                "rax",
+                format!("rip+.LLabel{}", node.borrow().label.unwrap())
+            );
+            return;
+        }
+        Nodekind::BreakNd | Nodekind::ContinueNd => {
+            // 可変長配列のスコープから抜ける場合は、飛ぶ前にスタックを復元する
+            if let Some(restore) = node.borrow().init.as_ref() {
+                gen_stmt(restore);
+            }
+            let dest = if node.borrow().kind == Nodekind::BreakNd {
+                "Break"
+            } else {
+                "Continue"
+            };
+            operate!("jmp", format!(".L{}{}", dest, node.borrow().label.unwrap()));
             return;
         }
         Nodekind::BlockNd => {
             for child in &node.borrow().children {
-                gen_expr(child);
+                gen_stmt(child);
             }
             return;
         }
@@ -404,6 +743,56 @@ fn gen_expr(node: &NodeRef) {
             zero_clear(offset, bytes);
             return;
         }
+        Nodekind::AllocaNd => {
+            // 式の評価途中でスタックに積まれている一時的な値を、確保する領域の分だけ下にずらしてから確保する
+            gen_expr(node.borrow().left.as_ref().unwrap());
+            let bottom = node.borrow().offset.unwrap();
+            operate!("add", "rax", 15);
+            operate!("and", "rax", -16);
+            mov!("rdx", "rax");
+            mov!("rcx", format!("QWORD PTR [rbp-{}]", bottom));
+            operate!("sub", "rcx", "rsp");
+            mov!("rsi", "rsp");
+            operate!("sub", "rsp", "rdx");
+            mov!("rdi", "rsp");
+            operate!("rep", "movsb");
+            mov!("rax", format!("QWORD PTR [rbp-{}]", bottom));
+            operate!("sub", "rax", "rdx");
+            mov_to!(8, "rbp", "rax", bottom);
+            return;
+        }
+        Nodekind::StackRestoreNd => {
+            // left で底を以前の値に戻し、その位置までスタックを解放する
+            gen_expr(node.borrow().left.as_ref().unwrap());
+            mov!("rsp", "rax");
+            return;
+        }
+        Nodekind::VaStartNd => {
+            // 関数の先頭で用意した va_list の初期値をコピーする
+            gen_expr(node.borrow().left.as_ref().unwrap());
+            let area = node.borrow().offset.unwrap();
+            for i in 0..3 {
+                mov!("rdx", format!("QWORD PTR [rbp-{}]", area - 8 * i));
+                mov!(format!("QWORD PTR [rax+{}]", 8 * i), "rdx");
+            }
+            return;
+        }
+        Nodekind::VaArgNd => {
+            gen_expr(node.borrow().left.as_ref().unwrap());
+            gen_va_arg(&node.borrow().typ.as_ref().unwrap().make_deref().unwrap());
+            return;
+        }
+        Nodekind::VaCopyNd => {
+            gen_expr(node.borrow().right.as_ref().unwrap());
+            operate!("push", "rax");
+            gen_expr(node.borrow().left.as_ref().unwrap());
+            operate!("pop", "rdx");
+            for i in 0..3 {
+                mov!("rcx", format!("QWORD PTR [rdx+{}]", 8 * i));
+                mov!(format!("QWORD PTR [rax+{}]", 8 * i), "rcx");
+            }
+            return;
+        }
         Nodekind::NopNd => {
             return;
         }
@@ -412,16 +801,24 @@ fn gen_expr(node: &NodeRef) {
 
     let left = Rc::clone(node.borrow().left.as_ref().unwrap());
     let right = Rc::clone(node.borrow().right.as_ref().unwrap());
+    if left.borrow().typ.as_ref().unwrap().is_floating() {
+        gen_float_binary(node, &left, &right);
+        return;
+    }
     gen_expr(&left);
     operate!("push", "rax");
     gen_expr(&right);
 
-    // long や long long などが実装されるまではポインタなら8バイト、そうでなければ4バイトのレジスタを使うことにする
-    let (ax, di, dx, cq) = if left.borrow().typ.as_ref().unwrap().ptr_end.is_some() {
-        ("rax", "rdi", "rdx", "cqo")
+    // 計算に使うレジスタは(キャスト済みの)左辺の型のサイズで決める: 8バイトの型なら 64bit, そうでなければ 32bit
+    let left_typ = left.borrow().typ.clone().unwrap();
+    let (ax, di, dx) = if left_typ.ptr_end.is_some() || left_typ.bytes() == 8 {
+        ("rax", "rdi", "rdx")
     } else {
-        ("eax", "edi", "edx", "cdq")
+        ("eax", "edi", "edx")
     };
+    // 比較では左辺の型、それ以外ではノード自体の型の符号の有無によって命令を選ぶ(型が未確定の場合は左辺の型とする)
+    let typ = node.borrow().typ.clone().unwrap_or(left_typ.clone());
+    let is_unsigned = typ.is_unsigned;
 
     if [Nodekind::LShiftNd, Nodekind::RShiftNd].contains(&node.borrow().kind) {
         mov!("rcx", "rax");
@@ -441,20 +838,23 @@ fn gen_expr(node: &NodeRef) {
         Nodekind::MulNd => {
             operate!("imul", ax, di);
         }
-        Nodekind::DivNd => {
-            operate!(cq); // rax -> rdx:rax に拡張(ただの 0 fill)
-            operate!("idiv", di); // rdi で割る: rax が商で rdx が剰余になる
-        }
-        Nodekind::ModNd => {
-            operate!(cq);
-            operate!("idiv", di);
-            mov!(ax, dx);
+        Nodekind::DivNd | Nodekind::ModNd => {
+            if is_unsigned {
+                mov!("edx", 0); // rdx:rax を割られる数とするので、上位を 0 にしておく
+                operate!("div", di);
+            } else {
+                operate!(if ax == "rax" { "cqo" } else { "cdq" }); // rax -> rdx:rax に符号拡張
+                operate!("idiv", di); // rdi で割る: rax が商で rdx が剰余になる
+            }
+            if node.borrow().kind == Nodekind::ModNd {
+                mov!(ax, dx);
+            }
         }
         Nodekind::LShiftNd => {
             operate!("sal", ax, "cl");
         }
         Nodekind::RShiftNd => {
-            operate!("sar", ax, "cl");
+            operate!(if is_unsigned { "shr" } else { "sar" }, ax, "cl");
         }
         Nodekind::BitAndNd => {
             operate!("and", ax, di);
@@ -477,12 +877,19 @@ fn gen_expr(node: &NodeRef) {
         }
         Nodekind::LThanNd => {
             operate!("cmp", ax, di);
-            operate!("setl", "al");
+            operate!(if left_typ.is_unsigned { "setb" } else { "setl" }, "al");
             operate!("movzb", "rax", "al");
         }
         Nodekind::LEqNd => {
             operate!("cmp", ax, di);
-            operate!("setle", "al");
+            operate!(
+                if left_typ.is_unsigned {
+                    "setbe"
+                } else {
+                    "setle"
+                },
+                "al"
+            );
             operate!("movzb", "rax", "al");
         }
         _ => {
@@ -490,6 +897,134 @@ fn gen_expr(node: &NodeRef) {
             error_with_node!("不正な Nodekind です。", &*node.borrow());
         }
     }
+
+    // 32bit のレジスタで計算した結果は、ノードの型に応じて rax 全体に拡張し直す
+    if ax == "eax" {
+        extend(&typ);
+    }
+}
+
+/// 浮動小数点数同士の二項演算: 左辺を xmm0, 右辺を xmm1 に置いて計算する
+fn gen_float_binary(node: &NodeRef, left: &NodeRef, right: &NodeRef) {
+    let left_typ = left.borrow().typ.clone().unwrap();
+    if left_typ.typ == Type::LDouble {
+        gen_ldouble_binary(node, left, right);
+        return;
+    }
+    let sfx = fp_suffix(&left_typ);
+
+    gen_expr(left);
+    operate!("sub", "rsp", 8);
+    store_float(&left_typ, "[rsp]");
+    gen_expr(right);
+    operate!("movaps", "xmm1", "xmm0");
+    load_float(&left_typ, "[rsp]");
+    operate!("add", "rsp", 8);
+
+    // 比較は ucomis* で行う: NaN との比較ではパリティフラグが立つので、 == は偽、 != は真にする
+    // <, <= は左右を入れ替えて seta, setae を使うことで NaN の場合に偽にできる
+    let kind = node.borrow().kind;
+    match kind {
+        Nodekind::AddNd => {
+            operate!(format!("add{}", sfx), "xmm0", "xmm1");
+        }
+        Nodekind::SubNd => {
+            operate!(format!("sub{}", sfx), "xmm0", "xmm1");
+        }
+        Nodekind::MulNd => {
+            operate!(format!("mul{}", sfx), "xmm0", "xmm1");
+        }
+        Nodekind::DivNd => {
+            operate!(format!("div{}", sfx), "xmm0", "xmm1");
+        }
+        Nodekind::EqNd => {
+            operate!(format!("ucomi{}", sfx), "xmm0", "xmm1");
+            operate!("sete", "al");
+            operate!("setnp", "dl");
+            operate!("and", "al", "dl");
+            operate!("movzb", "rax", "al");
+        }
+        Nodekind::NEqNd => {
+            operate!(format!("ucomi{}", sfx), "xmm0", "xmm1");
+            operate!("setne", "al");
+            operate!("setp", "dl");
+            operate!("or", "al", "dl");
+            operate!("movzb", "rax", "al");
+        }
+        Nodekind::LThanNd => {
+            operate!(format!("ucomi{}", sfx), "xmm1", "xmm0");
+            operate!("seta", "al");
+            operate!("movzb", "rax", "al");
+        }
+        Nodekind::LEqNd => {
+            operate!(format!("ucomi{}", sfx), "xmm1", "xmm0");
+            operate!("setae", "al");
+            operate!("movzb", "rax", "al");
+        }
+        _ => {
+            error_with_node!("不正な Nodekind です。", &*node.borrow());
+        }
+    }
+}
+
+/// long double 同士の二項演算: 左辺を st(0), 右辺を st(1) に置いて計算する
+fn gen_ldouble_binary(node: &NodeRef, left: &NodeRef, right: &NodeRef) {
+    let left_typ = left.borrow().typ.clone().unwrap();
+    gen_expr(left);
+    operate!("sub", "rsp", 16);
+    store_float(&left_typ, "[rsp]");
+    gen_expr(right);
+    load_float(&left_typ, "[rsp]");
+    operate!("add", "rsp", 16);
+
+    // 計算結果は st(0) に置いて、右辺の値は取り除く
+    // 比較は fucomip で行い(左辺が取り除かれる)、残った値も取り除く: フラグの扱いは ucomis* と同様
+    let kind = node.borrow().kind;
+    match kind {
+        Nodekind::AddNd | Nodekind::SubNd | Nodekind::MulNd | Nodekind::DivNd => {
+            let op = match kind {
+                Nodekind::AddNd => "fadd",
+                Nodekind::SubNd => "fsub",
+                Nodekind::MulNd => "fmul",
+                _ => "fdiv",
+            };
+            operate!(op, "st(0)", "st(1)");
+            operate!("fstp", "st(1)");
+        }
+        Nodekind::EqNd => {
+            operate!("fucomip", "st(0)", "st(1)");
+            operate!("fstp", "st(0)");
+            operate!("sete", "al");
+            operate!("setnp", "dl");
+            operate!("and", "al", "dl");
+            operate!("movzb", "rax", "al");
+        }
+        Nodekind::NEqNd => {
+            operate!("fucomip", "st(0)", "st(1)");
+            operate!("fstp", "st(0)");
+            operate!("setne", "al");
+            operate!("setp", "dl");
+            operate!("or", "al", "dl");
+            operate!("movzb", "rax", "al");
+        }
+        Nodekind::LThanNd | Nodekind::LEqNd => {
+            operate!("fxch", "st(1)");
+            operate!("fucomip", "st(0)", "st(1)");
+            operate!("fstp", "st(0)");
+            operate!(
+                if kind == Nodekind::LThanNd {
+                    "seta"
+                } else {
+                    "setae"
+                },
+                "al"
+            );
+            operate!("movzb", "rax", "al");
+        }
+        _ => {
+            error_with_node!("不正な Nodekind です。", &*node.borrow());
+        }
+    }
 }
 
 /// アドレスを生成し、 rax に保存する
@@ -498,7 +1033,11 @@ fn gen_addr(node: &NodeRef) {
     let kind = node.kind;
     match kind {
         Nodekind::LvarNd => {
-            if node.is_local {
+            if node.is_local && node.typ.as_ref().is_some_and(|typ| typ.is_vla()) {
+                // 可変長配列の変数は alloca した領域のアドレスを保持している
+                let offset = node.offset.unwrap();
+                mov_from!(8, false, "rbp", offset);
+            } else if node.is_local {
                 // 変数に対応するアドレスをスタックにプッシュする
                 let offset = node.offset.unwrap();
                 lea!("rax", "rbp", offset);
@@ -517,49 +1056,185 @@ fn gen_addr(node: &NodeRef) {
     }
 }
 
-/// 関数呼び出し時の引数の処理を行う
-fn push_args(args: &Vec<NodeRef>) {
-    let argc = args.len();
-    if argc > 6 {
-        exit_eprintln!("現在7つ以上の引数はサポートされていません。");
-    }
-
-    // 計算時に rdi などを使う場合があるので、引数はまずはスタックに全て push したままにしておく
-    // おそらく、逆順にしておいた方がスタックに引数を積みたくなった場合に都合が良い
-    if argc != 0 {
-        operate!("sub", "rsp", argc * 8);
-        for i in 0..argc {
-            gen_expr(&args[i]);
-            if i == 0 {
-                asm_write!("\tmov QWORD PTR[rsp], rax");
+/// switch 文: case の値が密集している場合はジャンプテーブルで、そうでない場合は比較の連鎖で分岐する
+fn gen_switch(node: &NodeRef) {
+    let node = node.borrow();
+    let label = node.label.unwrap();
+    let enter = node.enter.as_ref().unwrap();
+    let bytes = enter.borrow().typ.as_ref().unwrap().bytes();
+    let ax = reg_ax(bytes);
+
+    let mut cases: Vec<(i64, String)> = vec![];
+    let mut default = format!(".LBreak{}", label);
+    for case in &node.children {
+        let case = case.borrow();
+        let case_label = format!(".LCase{}", case.label.unwrap());
+        if let Some(val) = case.val {
+            cases.push((val, case_label));
+        } else {
+            default = case_label;
+        }
+    }
+    cases.sort();
+
+    gen_expr(enter);
+    let range = cases
+        .first()
+        .zip(cases.last())
+        .map(|(min, max)| max.0 as i128 - min.0 as i128 + 1);
+    if cases.len() >= 4 && range.is_some_and(|range| range <= 3 * cases.len() as i128) {
+        // 最小値との差を添字としてテーブルを引く(最小値より小さい値は符号なしで比較すると範囲外になる)
+        let min = cases[0].0;
+        let table = format!(".LSwitchTable{}", label);
+        if bytes == 4 {
+            operate!("sub", ax, min as i32);
+            operate!("cmp", ax, range.unwrap() - 1);
+        } else {
+            mov!("rdx", min);
+            operate!("sub", ax, "rdx");
+            mov!("rdx", range.unwrap() - 1);
+            operate!("cmp", ax, "rdx");
+        }
+        operate!("ja", default);
+        // テーブルには各 case のラベルのテーブルからの相対位置を入れておく
+        lea!("rdx", format!("rip+{}", table));
+        asm_write!("\tmovsxd rax, DWORD PTR [rdx+rax*4]");
+        operate!("add", "rax", "rdx");
+        operate!("jmp", "rax");
+
+        asm_write!("\t.section .rodata");
+        asm_write!("\t.align 4");
+        asm_write!("{}:", table);
+        let mut iter = cases.iter().peekable();
+        for i in 0..range.unwrap() as i64 {
+            let target = match iter.next_if(|(val, _)| *val == min + i) {
+                Some((_, case_label)) => case_label,
+                None => &default,
+            };
+            asm_write!("\t.long {}-{}", target, table);
+        }
+        asm_write!("\t.text");
+    } else {
+        for (val, case_label) in &cases {
+            if bytes == 4 {
+                operate!("cmp", ax, *val as i32);
+            } else if *val as i32 as i64 == *val {
+                operate!("cmp", ax, val);
             } else {
-                asm_write!("\tmov QWORD PTR[rsp+{}], rax", i * 8);
+                mov!("rdx", val);
+                operate!("cmp", ax, "rdx");
             }
+            operate!("je", case_label);
         }
+        operate!("jmp", default);
     }
 
-    for i in 0..argc {
-        let typ = args[i].borrow().typ.clone().unwrap();
-        let bytes = if typ.typ == Type::Array {
-            8
+    gen_stmt(node.branch.as_ref().unwrap());
+    asm_write!(".LBreak{}:", label);
+}
+
+/// 関数呼び出しを行う
+fn gen_funcall(node: &NodeRef) {
+    let node = node.borrow();
+    let args = &node.args;
+    let typs: Vec<TypeCell> = args
+        .iter()
+        .map(|arg| arg.borrow().typ.clone().unwrap())
+        .collect();
+    let (classes, stack_size) = classify_args(&typs);
+    let stack_size = (stack_size + 15) & !15;
+
+    // 計算時に rdi などを使う場合があるので、引数はまずは全て評価してスタック上の一時領域に保存しておく
+    // 関数ポインタを介した呼び出しの場合は、呼び出し先のアドレスも一時領域の先頭に保存する
+    let callee = node.left.as_ref();
+    let mut tmp_offsets = vec![];
+    let mut tmp_size = if callee.is_some() { 8 } else { 0 };
+    for typ in &typs {
+        tmp_offsets.push(tmp_size);
+        tmp_size += if typ.typ == Type::LDouble { 16 } else { 8 };
+    }
+    if tmp_size > 0 {
+        operate!("sub", "rsp", tmp_size);
+    }
+    if let Some(callee) = callee {
+        gen_expr(callee);
+        asm_write!("\tmov QWORD PTR [rsp], rax");
+    }
+    for ((arg, typ), tmp) in args.iter().zip(&typs).zip(&tmp_offsets) {
+        gen_expr(arg);
+        if typ.is_floating() {
+            store_float(typ, format!("[rsp+{}]", tmp));
         } else {
-            typ.bytes()
-        };
-        let arg_reg = ARGS_REGISTERS.try_lock().unwrap().get(&bytes).unwrap()[i];
-        let arg_reg_r = ARGS_REGISTERS.try_lock().unwrap().get(&8).unwrap()[i];
-        let ax = reg_ax(bytes);
-        operate!("pop", "rax");
-        // rax で push するために符号拡張する
-        if bytes == 4 {
-            operate!("cdqe");
-        } else if bytes < 4 {
-            movsx!("rax", "al");
+            asm_write!("\tmov QWORD PTR [rsp+{}], rax", tmp);
+        }
+    }
+
+    // rax に一時領域の先頭を持っておき、 rsp を 16 の倍数に align した上で元の rsp を退避する
+    mov!("rax", "rsp");
+    operate!("and", "rsp", "~0x0f");
+    operate!("sub", "rsp", 8);
+    operate!("push", "rax");
+    if stack_size > 0 {
+        operate!("sub", "rsp", stack_size);
+    }
+
+    // スタックで渡す引数を先に並べる: 浮動小数点数の値も一時領域からビット列のままコピーするので、 xmm0 は壊さない
+    for ((typ, tmp), class) in typs.iter().zip(&tmp_offsets).zip(&classes) {
+        if let ArgClass::Memory(pos) = class {
+            if typ.typ == Type::LDouble {
+                load_float(typ, format!("[rax+{}]", tmp));
+                store_float(typ, format!("[rsp+{}]", pos));
+            } else {
+                // r11 は引数の受け渡しに使われないので、コピーの中継に使える
+                mov!("r11", format!("QWORD PTR [rax+{}]", tmp));
+                mov!(format!("QWORD PTR [rsp+{}]", pos), "r11");
+            }
         }
-        if bytes < 8 {
-            mov!(arg_reg_r, 0);
+    }
+
+    // 各引数の値は型に応じて rax 全体に拡張済みなので、 64bit のレジスタにそのまま渡せば良い
+    // 浮動小数点数は xmm0 から順に渡す
+    let mut n_floats = 0;
+    for ((typ, tmp), class) in typs.iter().zip(&tmp_offsets).zip(classes) {
+        match class {
+            ArgClass::Integer(ix) => {
+                let arg_reg = ARGS_REGISTERS.try_lock().unwrap().get(&8).unwrap()[ix];
+                mov!(arg_reg, format!("QWORD PTR [rax+{}]", tmp));
+            }
+            ArgClass::Sse(ix) => {
+                asm_write!(
+                    "\tmov{} xmm{}, {} [rax+{}]",
+                    fp_suffix(typ),
+                    ix,
+                    word_ptr(typ.bytes()),
+                    tmp
+                );
+                n_floats += 1;
+            }
+            ArgClass::Memory(_) => {}
         }
-        mov!(arg_reg, ax);
     }
+
+    if callee.is_some() {
+        // r10 は引数の受け渡しに使われないので、呼び出し先のアドレスを置いておける
+        mov!("r10", "QWORD PTR [rax]");
+    }
+    mov!("rax", n_floats); // 可変長引数をとる際、浮動小数点数の引数の数を al に入れる必要がある
+    if callee.is_some() {
+        operate!("call", "r10");
+    } else {
+        operate!("call", node.name.as_ref().unwrap());
+    }
+    if stack_size > 0 {
+        operate!("add", "rsp", stack_size);
+    }
+    operate!("pop", "rsp");
+    if tmp_size > 0 {
+        operate!("add", "rsp", tmp_size);
+    }
+
+    // 呼び出し先は戻り値の型のサイズ分のレジスタしか保証しないため、 rax 全体に拡張しておく
+    extend(node.typ.as_ref().unwrap());
 }
 
 /// rbp - offset から rbp - offset + bytes までゼロクリアを行う
@@ -4,3 +4,6 @@ use once_cell::sync::Lazy;
 
 pub static SRC: Lazy<Mutex<Vec<Vec<String>>>> = Lazy::new(|| Mutex::new(vec![]));
 pub static FILE_NAMES: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// -std=c11 が指定された場合 true: 宣言されていない関数の呼び出し(暗黙の関数宣言)をエラーにする
+pub static STRICT_STD: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
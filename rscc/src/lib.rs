@@ -4,26 +4,36 @@ use std::io::{BufRead, BufReader};
 use clap::Clap;
 
 mod asm;
+mod float80;
 mod generator;
 mod globals;
 mod initializer;
 mod node;
 mod options;
 mod parser;
+mod preprocessor;
 mod token;
 mod tokenizer;
 mod typecell;
 mod utils;
 use asm::ASMCODE;
 use generator::generate;
-use globals::{FILE_NAMES, SRC};
+use globals::{FILE_NAMES, SRC, STRICT_STD};
 use options::Opts;
 use parser::parse;
 use tokenizer::tokenize;
 use utils::{LIGHTBLUE, RED};
 
 pub fn compile() -> String {
-    let opts = Opts::parse();
+    // gcc と同じ -std=c11 の形式の指定は --std=c11 として解釈する
+    let opts = Opts::parse_from(std::env::args().map(|arg| {
+        if arg.starts_with("-std=") {
+            format!("-{}", arg)
+        } else {
+            arg
+        }
+    }));
+    *STRICT_STD.try_lock().unwrap() = opts.std == "c11";
     if let Some(path) = opts.input_file {
         match compile_src(path.as_str()) {
             Ok(asm) => asm,
@@ -3,6 +3,7 @@ use std::fmt::{Display, Formatter, Result};
 use std::rc::Rc;
 
 use crate::{
+    float80::Float80,
     token::{error_tok, TokenRef},
     typecell::TypeCell,
 };
@@ -11,41 +12,56 @@ pub type NodeRef = Rc<RefCell<Node>>;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Nodekind {
-    DefaultNd, // defalut
-    AddNd,     // '+'
-    SubNd,     // '-'
-    MulNd,     // '*'
-    DivNd,     // '/'
-    ModNd,     // '%'
-    LShiftNd,  // "<<"
-    RShiftNd,  // ">>"
-    BitAndNd,  // '&'
-    BitOrNd,   // '|'
-    BitXorNd,  // '^'
-    BitNotNd,  // '~'
-    LogAndNd,  // "&&"
-    LogOrNd,   // "||"
-    LogNotNd,  // '!'
-    AssignNd,  // '='
-    LvarNd,    // 左辺値
-    CastNd,    // キャスト
-    NumNd,     // 数値
-    AddrNd,    // アドレス参照(&)
-    DerefNd,   // アドレスの値を読む(*)
-    EqNd,      // "=="
-    NEqNd,     // "!="
-    LThanNd,   // '<' // '>' や ">=" はパース時に構文木の左右を入れ替えることで調整
-    LEqNd,     // "<="
-    IfNd,      // "if"
-    ForNd,     // "for"
-    WhileNd,   // "while"
-    ReturnNd,  // "return"
-    BlockNd,   // {}
-    CommaNd,   // ','
-    FunCallNd, // func()
-    GlobalNd,  // グローバル変数(関数含む)
-    ZeroClrNd, // スタックのゼロクリア(配列の初期化など)
-    NopNd,     // 何もしない
+    DefaultNd,      // defalut
+    AddNd,          // '+'
+    SubNd,          // '-'
+    MulNd,          // '*'
+    DivNd,          // '/'
+    ModNd,          // '%'
+    LShiftNd,       // "<<"
+    RShiftNd,       // ">>"
+    BitAndNd,       // '&'
+    BitOrNd,        // '|'
+    BitXorNd,       // '^'
+    BitNotNd,       // '~'
+    NegNd,          // 浮動小数点数の単項演算のマイナス
+    LogAndNd,       // "&&"
+    LogOrNd,        // "||"
+    LogNotNd,       // '!'
+    CondNd,         // "?:"
+    AssignNd,       // '='
+    LvarNd,         // 左辺値
+    CastNd,         // キャスト
+    NumNd,          // 数値
+    AddrNd,         // アドレス参照(&)
+    DerefNd,        // アドレスの値を読む(*)
+    EqNd,           // "=="
+    NEqNd,          // "!="
+    LThanNd,        // '<' // '>' や ">=" はパース時に構文木の左右を入れ替えることで調整
+    LEqNd,          // "<="
+    IfNd,           // "if"
+    ForNd,          // "for"
+    WhileNd,        // "while"
+    DoWhileNd,      // "do" ~ "while"
+    SwitchNd,       // "switch"
+    CaseNd,         // "case", "default"
+    BreakNd,        // "break"
+    ContinueNd,     // "continue"
+    LabelNd,        // ラベル付きの文
+    GotoNd,         // "goto"
+    LabelAddrNd,    // ラベルのアドレス(&&label)
+    ReturnNd,       // "return"
+    BlockNd,        // {}
+    CommaNd,        // ','
+    FunCallNd,      // func()
+    GlobalNd,       // グローバル変数(関数含む)
+    ZeroClrNd,      // スタックのゼロクリア(配列の初期化など)
+    AllocaNd,       // スタック上の領域の動的な確保(可変長配列, __builtin_alloca)
+    StackRestoreNd, // 可変長配列のスコープを抜ける際のスタックの復元
+    VaStartNd,      // __builtin_va_start
+    VaArgNd,        // __builtin_va_arg (次の可変長引数のアドレス)
+    VaCopyNd,       // __builtin_va_copy
+    NopNd,          // 何もしない
 }
 
 #[derive(Clone, Debug)]
@@ -55,19 +71,22 @@ pub struct Node {
     pub typ: Option<TypeCell>,
 
     // プロパティとなる数値
-    pub val: Option<i32>,
+    pub val: Option<i64>,
+    pub fval: Option<f64>,
     pub offset: Option<usize>, // ベースポインタからのオフセット(ローカル変数時のみ)
 
     // 通常ノード(計算式評価)用の左右ノード
     pub left: Option<NodeRef>,
     pub right: Option<NodeRef>,
 
-    // for (init; enter; routine) branch, if (enter) branch else els, while(enter) branch
+    // for (init; enter; routine) branch, if (enter) branch else els, while(enter) branch, do branch while(enter), enter ? branch : els
+    // break, continue, goto では init に可変長配列のスコープを抜ける際のスタックの復元を持つ
     pub init: Option<NodeRef>,
     pub enter: Option<NodeRef>,
     pub routine: Option<NodeRef>,
     pub branch: Option<NodeRef>,
     pub els: Option<NodeRef>,
+    pub label: Option<usize>, // 繰り返し文などの break, continue の飛び先を識別する番号
 
     // ブロック内のコード
     pub children: Vec<NodeRef>,
@@ -75,12 +94,16 @@ pub struct Node {
     // グローバル変数等で使用
     pub name: Option<String>,
     pub init_data: Vec<InitData>,
+    pub is_static: bool,    // 内部リンケージ(.globl を付けない)
+    pub is_extern: bool,    // 他の翻訳単位で定義されるため、領域を確保しない
+    pub is_tentative: bool, // 初期化子のない仮定義
 
     // 関数に使用
     pub func_typ: Option<TypeCell>,
     pub args: Vec<NodeRef>,
     pub stmts: Option<Vec<NodeRef>>,
     pub max_offset: Option<usize>,
+    pub va_area: Option<usize>, // 可変長引数をとる関数で、va_list の初期値とレジスタの値を保存する領域のオフセット
 
     // 変数時に使用
     pub is_local: bool,
@@ -93,6 +116,7 @@ pub struct InitData {
     pub size: usize,
     pub val: i64,
     pub label: Option<String>,
+    pub ldouble: Option<Float80>, // long double の値(80bit の表現をそのまま置く)
 }
 
 /// 並列で処理することがないものとして、グローバル変数の都合で Send/Sync を使う
@@ -108,6 +132,7 @@ impl Default for Node {
             token: None,
             typ: None,
             val: None,
+            fval: None,
             offset: None,
             left: None,
             right: None,
@@ -116,13 +141,18 @@ impl Default for Node {
             routine: None,
             branch: None,
             els: None,
+            label: None,
             children: vec![],
             name: None,
             init_data: vec![],
+            is_static: false,
+            is_extern: false,
+            is_tentative: false,
             func_typ: None,
             args: vec![],
             stmts: None,
             max_offset: None,
+            va_area: None,
             is_local: false,
             level: None,
         }
@@ -164,6 +194,9 @@ impl Display for Node {
         if let Some(e) = self.val.as_ref() {
             s = format!("{}val: {}\n", s, e);
         }
+        if let Some(e) = self.fval.as_ref() {
+            s = format!("{}fval: {}\n", s, e);
+        }
         if let Some(e) = self.name.as_ref() {
             s = format!("{}name: {}\n", s, e);
         }
@@ -192,6 +225,10 @@ impl Display for Node {
             s = format!("{}els: exist(kind:{:?})\n", s, e.borrow().kind);
         }
 
+        if let Some(e) = self.label.as_ref() {
+            s = format!("{}label: {}\n", s, e);
+        }
+
         if self.children.len() > 0 {
             s = format!("{}children: exist\n", s);
             for node in &self.children {
@@ -215,6 +252,9 @@ impl Display for Node {
         if let Some(e) = self.max_offset.as_ref() {
             s = format!("{}max_offset: {}\n", s, e);
         }
+        if let Some(e) = self.va_area.as_ref() {
+            s = format!("{}va_area: {}\n", s, e);
+        }
 
         if self.init_data.len() > 0 {
             s = format!("{}init_data: exist\n", s);
@@ -233,13 +273,21 @@ impl InitData {
             size: size,
             val: val.into(),
             label: label,
+            ldouble: None,
         }
     }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.val == 0 && self.label.is_none() && self.ldouble.is_none()
+    }
 }
 
 impl Display for InitData {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if let Some(l) = &self.label {
+        if let Some(ld) = &self.ldouble {
+            write!(f, "[size, ldouble] = [{}, {}]", self.size, ld)
+        } else if let Some(l) = &self.label {
             write!(
                 f,
                 "[size, val, label] = [{}, {}, {}]",
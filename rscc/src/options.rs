@@ -16,4 +16,8 @@ pub struct Opts {
     // 入力ファイル名
     #[clap(name = "FILE")]
     pub input_file: Option<String>,
+
+    // 言語規格: c11 では暗黙の関数宣言をエラーにする (gcc と同様に -std=c11 の形でも指定できる)
+    #[clap(long = "std", default_value = "gnu11", possible_values = &["c11", "gnu11"])]
+    pub std: String,
 }
@@ -8,28 +8,75 @@ use once_cell::sync::Lazy;
 
 use crate::{
     error_with_node, error_with_token, exit_eprintln,
-    initializer::Initializer,
+    float80::Float80,
+    globals::STRICT_STD,
+    initializer::{Initializer, InitializerRef},
     node::{InitData, Node, NodeRef, Nodekind},
-    token::{TokenRef, Tokenkind},
+    token::{token_ptr_exceed, TokenRef, Tokenkind},
     tokenizer::{
-        at_eof, consume, consume_ident, consume_kind, consume_literal, consume_type, expect,
-        expect_ident, expect_literal, expect_number, expect_type, is, is_kind, is_type,
+        at_eof, consume, consume_float, consume_ident, consume_kind, consume_literal,
+        consume_qualifiers, consume_type, expect, expect_decl_type, expect_ident, expect_literal,
+        expect_number, expect_type, is, is_kind, is_type,
     },
     typecell::{get_common_type, Type, TypeCell, TypeCellRef},
+    warning_with_token,
 };
 
 pub fn parse(mut head: TokenRef) -> Vec<NodeRef> {
     program(&mut head)
 }
 
-/// ローカル変数名 -> (BP からのオフセット,  型)
-static LOCALS: Lazy<Mutex<Vec<HashMap<String, (usize, TypeCell)>>>> =
-    Lazy::new(|| Mutex::new(vec![]));
+/// ローカル変数名 -> (BP からのオフセット,  型, static 変数・extern 宣言の場合は実体のラベル)
+type LocalScope = HashMap<String, (usize, TypeCell, Option<String>)>;
+
+/// ブロックごとのローカル変数のスコープ(内側のブロックほど後ろに積む)
+static LOCALS: Lazy<Mutex<Vec<LocalScope>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// 関数内で宣言された static 変数のノード(関数のパース後に program でグローバル変数として出力する)
+static STATIC_LVARS: Lazy<Mutex<Vec<Node>>> = Lazy::new(|| Mutex::new(vec![]));
+/// static 変数のラベルを一意にするためのカウンタ
+static STATIC_LVAR_COUNT: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
 
 /// グローバル変数名 -> 当該ノード
 static GLOBALS: Lazy<Mutex<HashMap<String, Node>>> = Lazy::new(|| Mutex::new(HashMap::new()));
 static LVAR_MAX_OFFSET: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
 
+/// 可変長配列の要素数を隠れたローカル変数に保存する代入式のうち、まだ文として出力されていないもの
+static VLA_DIMS: Lazy<Mutex<Vec<Node>>> = Lazy::new(|| Mutex::new(vec![]));
+/// 現在パースしている関数で alloca した領域の底を保持する隠れたローカル変数のオフセット
+static ALLOCA_BOTTOM: Lazy<Mutex<Option<usize>>> = Lazy::new(|| Mutex::new(None));
+/// 現在パースしているブロックごとに、宣言した可変長配列を確保する直前の領域の底を保存した隠れたローカル変数のオフセットを積む
+/// (ブロックを抜ける際や、 break などでスコープの外に飛ぶ際にはその値までスタックを復元する)
+static VLA_SCOPES: Lazy<Mutex<Vec<Vec<usize>>>> = Lazy::new(|| Mutex::new(vec![]));
+/// 宣言子の形だけを先読みしている間は true (可変長配列の要素数の式は評価しない)
+static PEEKING: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
+/// 関数の本体をパースしている間は true (複合リテラルをローカル変数として確保するかどうかの判定に用いる)
+static IN_FUNCTION: Lazy<Mutex<bool>> = Lazy::new(|| Mutex::new(false));
+
+/// 現在パースしている関数の戻り値の型(return 文の値の変換に用いる)
+static CUR_RET_TYP: Lazy<Mutex<Option<TypeCell>>> = Lazy::new(|| Mutex::new(None));
+
+/// break, continue の飛び先となる制御構文の (番号, continue できるかどうか, 外側にある可変長配列のスコープの数) のスタック
+static JUMP_TARGETS: Lazy<Mutex<Vec<(usize, bool, usize)>>> = Lazy::new(|| Mutex::new(vec![]));
+/// break, continue の飛び先となる制御構文の番号を一意にするためのカウンタ
+static JUMP_TARGET_COUNT: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
+/// switch 文の (制御式の型, case, default のノード, switch 文を囲む可変長配列のスコープ)
+type SwitchCases = (TypeCell, Vec<Node>, Vec<usize>);
+/// 現在パースしている switch 文の case, default のノード(ネストした switch 文ごとに積む)
+static SWITCH_CASES: Lazy<Mutex<Vec<SwitchCases>>> = Lazy::new(|| Mutex::new(vec![]));
+/// 関数内のラベル名 -> ラベルに割り当てた番号
+static LABELS: Lazy<Mutex<HashMap<String, usize>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+/// ラベルを定義・参照したノードと、それを囲む可変長配列のスコープ
+type LabelSite = (Node, Vec<usize>);
+/// 関数内で定義済みのラベル名 -> (ラベル付き文のノード, ラベルを囲む可変長配列のスコープ)
+static LABEL_DEFS: Lazy<Mutex<HashMap<String, LabelSite>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+/// 関数内でラベルを参照したノード(goto, &&label)とそれを囲む可変長配列のスコープ: 関数の末尾でラベルが定義されているかを確認する
+static LABEL_REFS: Lazy<Mutex<Vec<LabelSite>>> = Lazy::new(|| Mutex::new(vec![]));
+
+/// 定義中の関数が可変長引数をとる場合、 va_list の初期値とレジスタの値を保存する領域のオフセット
+static VA_AREA: Lazy<Mutex<Option<usize>>> = Lazy::new(|| Mutex::new(None));
+
 /// 文字列 -> 対応する内部変数名
 static LITERALS: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
 
@@ -38,6 +85,11 @@ pub static ORDERED_LITERALS: Lazy<Mutex<LinkedList<(String, String)>>> =
     Lazy::new(|| Mutex::new(LinkedList::new()));
 static LITERAL_COUNT: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
 
+/// 浮動小数点数のリテラルの (変数名, バイト数, 値) を正しい順番で保持するためのリスト
+/// 値はビット列で、 long double では 80bit の表現を下位に置いたもの
+pub static ORDERED_FLOATS: Lazy<Mutex<LinkedList<(String, usize, u128)>>> =
+    Lazy::new(|| Mutex::new(LinkedList::new()));
+
 fn store_literal(body: impl Into<String>) -> String {
     LITERALS
         .try_lock()
@@ -118,35 +170,85 @@ macro_rules! tmp_unary {
 
 // 数字に対応するノード
 #[inline]
-fn _num(val: i32, token: Option<TokenRef>) -> NodeRef {
+fn _num(val: i64, typ: TypeCell, token: Option<TokenRef>) -> NodeRef {
     Rc::new(RefCell::new(Node {
         kind: Nodekind::NumNd,
         token: token,
-        typ: Some(TypeCell::new(Type::Int)),
+        typ: Some(typ),
         val: Some(val),
         ..Default::default()
     }))
 }
 
 #[inline]
-fn new_num(val: i32, token: TokenRef) -> NodeRef {
-    _num(val, Some(token))
+fn new_num(val: i64, typ: TypeCell, token: TokenRef) -> NodeRef {
+    _num(val, typ, Some(token))
+}
+
+// 浮動小数点数のリテラルに対応するノード: 値はリテラルのセクションに置いて読み込む
+fn new_float(fval: f64, typ: TypeCell, token: TokenRef) -> NodeRef {
+    let name = format!(".LC{}", get_literal_id());
+    let value = if typ.typ == Type::LDouble {
+        // f64 を経由すると精度が落ちるので、リテラルの表記から直接変換する
+        Float80::parse(token.borrow().body.as_ref().unwrap()).to_bits()
+    } else {
+        float_bits(fval, &typ) as u128
+    };
+    ORDERED_FLOATS
+        .try_lock()
+        .unwrap()
+        .push_back((name.clone(), typ.bytes(), value));
+    Rc::new(RefCell::new(Node {
+        kind: Nodekind::NumNd,
+        token: Some(token),
+        typ: Some(typ),
+        fval: Some(fval),
+        name: Some(name),
+        ..Default::default()
+    }))
+}
+
+/// 浮動小数点数を型に応じたビット列に変換する
+fn float_bits(fval: f64, typ: &TypeCell) -> u64 {
+    if typ.typ == Type::Float {
+        (fval as f32).to_bits() as u64
+    } else {
+        fval.to_bits()
+    }
 }
 
 macro_rules! tmp_num {
     ($num: expr) => {
-        _num($num, None)
+        _num($num, TypeCell::new(Type::Int), None)
+    };
+    ($num: expr, $typ: expr) => {
+        _num($num, TypeCell::new($typ), None)
     };
 }
 
-fn get_alignment_base(typ: &TypeCell) -> usize {
-    match typ.bytes() {
-        1 => 1,
-        2 | 3 => 2,
-        4..=7 => 4,
-        8..=15 => 8,
-        16.. => 16,
-        _ => panic!("invalid size detected."),
+/// 整数リテラルの型を値の大きさとサフィックスから決める(C11 6.4.4.1: 現在は10進数のみ)
+fn literal_type(val: i64, body: &str) -> TypeCell {
+    let suffix = body.trim_start_matches(|c: char| c.is_ascii_digit());
+    let is_unsigned = suffix.contains(['u', 'U']);
+    let is_long = suffix.contains(['l', 'L']);
+    let val = val as u64;
+
+    let typ = if !is_long
+        && val
+            <= if is_unsigned {
+                u32::MAX as u64
+            } else {
+                i32::MAX as u64
+            } {
+        TypeCell::new(Type::Int)
+    } else {
+        TypeCell::new(Type::Long)
+    };
+    // long でも表現できない大きさの値は unsigned long として扱う
+    if is_unsigned || val > i64::MAX as u64 {
+        typ.make_unsigned()
+    } else {
+        typ
     }
 }
 
@@ -159,15 +261,16 @@ fn _lvar(
     level: usize,
 ) -> NodeRef {
     if is_local {
-        let (offset, _) = *LOCALS.try_lock().unwrap()[level]
+        let (offset, _, _) = *LOCALS.try_lock().unwrap()[level]
             .entry(name.into().clone())
             .or_insert_with_key(|_| {
                 // 見つからない場合にはオフセットの最大値を伸ばしてスタックを確保
                 let mut max_offset_access = LVAR_MAX_OFFSET.try_lock().unwrap();
 
-                // 各変数のサイズ(配列なら1要素のサイズ)に alignment する
-                let (diff, align_base) = if let Some(t) = &typ {
-                    (t.bytes(), get_alignment_base(t))
+                // 各変数の型のアラインメントに揃えて詰める
+                // 可変長配列は alloca した領域へのポインタを保持する
+                let (diff, align_base) = if let Some(t) = typ.as_ref().filter(|t| !t.is_vla()) {
+                    (t.bytes(), t.var_align())
                 } else {
                     (8, 8)
                 };
@@ -182,6 +285,7 @@ fn _lvar(
                     } else {
                         TypeCell::default()
                     },
+                    None,
                 )
             });
         Rc::new(RefCell::new(Node {
@@ -242,9 +346,17 @@ fn new_ctrl(
     branch: Option<NodeRef>,
     els: Option<NodeRef>,
 ) -> NodeRef {
-    if ![Nodekind::IfNd, Nodekind::ForNd, Nodekind::WhileNd].contains(&kind) {
+    if ![
+        Nodekind::IfNd,
+        Nodekind::ForNd,
+        Nodekind::WhileNd,
+        Nodekind::DoWhileNd,
+    ]
+    .contains(&kind)
+    {
         exit_eprintln!("new_ctrl: 制御構文ではありません。");
     }
+    let enter = enter.map(|enter| cond_cast(&enter));
     Rc::new(RefCell::new(Node {
         kind: kind,
         init: init,
@@ -256,6 +368,20 @@ fn new_ctrl(
     }))
 }
 
+/// break, continue のノード: label で飛び先の制御構文を指す
+#[inline]
+fn new_jump(kind: Nodekind, target: (usize, bool, usize), token: TokenRef) -> NodeRef {
+    let (label, _, depth) = target;
+    let chain = vla_chain();
+    Rc::new(RefCell::new(Node {
+        kind,
+        token: Some(token),
+        init: leave_vla_scopes(&chain, &chain[..depth]),
+        label: Some(label),
+        ..Default::default()
+    }))
+}
+
 #[inline]
 fn new_funcall(name: String, func_typ: TypeCell, args: Vec<NodeRef>, token: TokenRef) -> NodeRef {
     if func_typ.typ != Type::Func {
@@ -330,6 +456,17 @@ fn proto_func(name: String, func_typ: TypeCell, token: TokenRef) -> NodeRef {
     _global(name, None, Some(func_typ), vec![], None, None, token)
 }
 
+// void の関数における値のない return 文のノード
+#[inline]
+fn new_void_return(token: Option<TokenRef>) -> NodeRef {
+    Rc::new(RefCell::new(Node {
+        kind: Nodekind::ReturnNd,
+        token,
+        typ: Some(TypeCell::new(Type::Void)),
+        ..Default::default()
+    }))
+}
+
 #[inline]
 fn nop() -> NodeRef {
     Rc::new(RefCell::new(Node {
@@ -357,10 +494,69 @@ fn current_scope() -> usize {
     n_scopes - 1
 }
 
+/// break, continue の飛び先となる制御構文に入る: 割り当てた番号を返す
+fn enter_jump_target(can_continue: bool) -> usize {
+    let label = {
+        let mut count = JUMP_TARGET_COUNT.try_lock().unwrap();
+        *count += 1;
+        *count - 1
+    };
+    let depth = vla_chain().len();
+    JUMP_TARGETS
+        .try_lock()
+        .unwrap()
+        .push((label, can_continue, depth));
+    label
+}
+
+#[inline]
+fn leave_jump_target() {
+    let _ = JUMP_TARGETS.try_lock().unwrap().pop();
+}
+
+/// 現在の位置を囲む可変長配列のスコープ(底を保存した変数のオフセット)を外側から順に並べたもの
+fn vla_chain() -> Vec<usize> {
+    VLA_SCOPES.try_lock().unwrap().concat()
+}
+
+/// 可変長配列のスコープを抜ける際に、保存しておいた底の値までスタックを解放するノード
+fn new_stack_restore(saved: usize) -> NodeRef {
+    let offset = ALLOCA_BOTTOM.try_lock().unwrap().unwrap();
+    let ptr_typ = TypeCell::new(Type::Void).make_ptr_to();
+    let saved = direct_offset_lvar(saved, ptr_typ.clone());
+    let bottom = direct_offset_lvar(offset, ptr_typ);
+    let restore = tmp_unary!(
+        Nodekind::StackRestoreNd,
+        tmp_binary!(Nodekind::AssignNd, bottom, saved)
+    );
+    confirm_type(&restore);
+    restore
+}
+
+/// from のスコープからその外側の dest のスコープへ飛ぶ際に、抜ける可変長配列のうち最も外側のものを確保する前の状態に戻すノード
+fn leave_vla_scopes(from: &[usize], dest: &[usize]) -> Option<NodeRef> {
+    from.get(dest.len()).map(|saved| new_stack_restore(*saved))
+}
+
+/// 関数の本体をパースしているかどうか(関数の本体のトップレベルはファイルスコープと同じ LOCALS の階層にあるため、こちらで判定する)
+#[inline]
+fn in_function() -> bool {
+    *IN_FUNCTION.try_lock().unwrap()
+}
+
+/// void 型の式が値として使われていないかを確認する関数
+fn check_void(node: &NodeRef) {
+    if node.borrow().typ.as_ref().unwrap().is_void() {
+        error_with_node!("void 型の値は使用できません。", &node.borrow());
+    }
+}
+
 /// 計算時の暗黙のキャストを行う関数
 fn arith_cast(node: &mut Node) -> TypeCell {
     let left = Rc::clone(node.left.as_ref().unwrap());
     let right = Rc::clone(node.right.as_ref().unwrap());
+    check_void(&left);
+    check_void(&right);
     let left_typ = left.borrow().typ.clone().unwrap();
     let right_typ = right.borrow().typ.clone().unwrap();
     let typ = get_common_type(&left_typ, &right_typ);
@@ -369,8 +565,58 @@ fn arith_cast(node: &mut Node) -> TypeCell {
     typ
 }
 
+/// 浮動小数点数の式を、条件式として評価するために _Bool に変換する
+fn cond_cast(node: &NodeRef) -> NodeRef {
+    confirm_type(node);
+    if node.borrow().typ.as_ref().unwrap().is_floating() {
+        new_cast(node, TypeCell::new(Type::Bool))
+    } else {
+        Rc::clone(node)
+    }
+}
+
+/// 代入などの暗黙のポインタの変換で、指す先の型修飾子が失われないか、関数ポインタの型が一致するかを確認する関数
+fn check_ptr_conversion(expr: &NodeRef, typ: &TypeCell) {
+    confirm_type(expr);
+    let mut from = expr.borrow().typ.clone().unwrap();
+    if from.typ == Type::Func {
+        from = from.make_ptr_to();
+    }
+    if from.ptr_end.is_none() || typ.ptr_end.is_none() {
+        return;
+    }
+    let (from_deref, to_deref) = (from.make_deref().unwrap(), typ.make_deref().unwrap());
+    if from_deref.typ == Type::Func && to_deref.typ == Type::Func && from_deref != to_deref {
+        error_with_node!(
+            "型\"{}\"を互換性のない関数ポインタ型\"{}\"に変換することはできません。",
+            &expr.borrow(),
+            from,
+            typ
+        );
+    }
+    if !to_deref.has_qualifiers_of(&from_deref) {
+        error_with_node!(
+            "型\"{}\"から型\"{}\"への変換では修飾子が失われます。",
+            &expr.borrow(),
+            from,
+            typ
+        );
+    }
+}
+
 fn new_cast(expr: &NodeRef, typ: TypeCell) -> NodeRef {
     confirm_type(&expr);
+    let from = expr.borrow().typ.clone().unwrap();
+    if (from.is_floating() && typ.ptr_end.is_some())
+        || (from.ptr_end.is_some() && typ.is_floating())
+    {
+        error_with_node!(
+            "型\"{}\"から型\"{}\"への変換はできません。",
+            &expr.borrow(),
+            from,
+            typ
+        );
+    }
     let token = expr.borrow().token.clone();
     let left = Some(Rc::clone(expr));
     Rc::new(RefCell::new(Node {
@@ -430,8 +676,15 @@ fn confirm_type(node: &NodeRef) {
         }
         Nodekind::DerefNd => {
             let left_typ = node.left.as_ref().unwrap().borrow().typ.clone().unwrap();
-            if let Some(_) = &left_typ.ptr_end {
-                let _ = node.typ.insert(left_typ.make_deref().unwrap());
+            if left_typ.typ == Type::Func {
+                // 関数指示子は関数へのポインタに変換されるので、 *f も f と同じ関数を表す
+                let _ = node.typ.insert(left_typ);
+            } else if left_typ.ptr_end.is_some() {
+                let typ = left_typ.make_deref().unwrap();
+                if typ.is_void() {
+                    error_with_node!("void へのポインタの参照を外すことはできません。", &node);
+                }
+                let _ = node.typ.insert(typ);
             } else {
                 error_with_node!(
                     "\"*\"ではポインタの参照を外すことができますが、型\"{}\"が指定されています。",
@@ -445,6 +698,7 @@ fn confirm_type(node: &NodeRef) {
             let left = node.left.as_ref().unwrap();
             let right = node.right.as_ref().unwrap();
             let left_typ = left.borrow().typ.clone().unwrap();
+            check_void(right);
 
             if left_typ.is_array() {
                 error_with_node!(
@@ -453,6 +707,14 @@ fn confirm_type(node: &NodeRef) {
                     left_typ
                 );
             }
+            if left_typ.typ == Type::Func {
+                error_with_node!(
+                    "左辺値は代入可能な型である必要がありますが、関数型\"{}\"が指定されています。",
+                    &left.borrow(),
+                    left_typ
+                );
+            }
+            check_ptr_conversion(right, &left_typ);
             let right = new_cast(right, left_typ.clone());
             let _ = node.right.insert(right);
             let _ = node.typ.insert(left_typ);
@@ -462,29 +724,86 @@ fn confirm_type(node: &NodeRef) {
             let typ = arith_cast(&mut node);
             let _ = node.typ.insert(typ);
         }
+        Nodekind::NegNd => {
+            // 浮動小数点数の符号反転は、整数拡張も通常の算術型変換も行わない
+            let left = Rc::clone(node.left.as_ref().unwrap());
+            let typ = left.borrow().typ.clone().unwrap().unqualified();
+            let _ = node.typ.insert(typ);
+        }
         Nodekind::BitNotNd => {
             // ポインタの bitnot は不可
-            let left_typ = node.left.as_ref().unwrap().borrow().typ.clone().unwrap();
+            let left = Rc::clone(node.left.as_ref().unwrap());
+            check_void(&left);
+            let left_typ = left.borrow().typ.clone().unwrap();
             if left_typ.ptr_end.is_some() {
                 error_with_node!("ポインタのビット反転はできません。", &node);
             }
-            let _ = node.typ.insert(left_typ);
+            if left_typ.is_floating() {
+                error_with_node!("浮動小数点数のビット反転はできません。", &node);
+            }
+            // 整数拡張を行う
+            let typ = left_typ.int_promoted();
+            let _ = node.left.insert(new_cast(&left, typ.clone()));
+            let _ = node.typ.insert(typ);
+        }
+        Nodekind::LShiftNd | Nodekind::RShiftNd => {
+            // シフト演算では両辺が独立に整数拡張され、結果は左辺の型になる
+            let left = Rc::clone(node.left.as_ref().unwrap());
+            let right = Rc::clone(node.right.as_ref().unwrap());
+            check_void(&left);
+            check_void(&right);
+            let left_typ = left.borrow().typ.clone().unwrap();
+            let right_typ = right.borrow().typ.clone().unwrap();
+            if left_typ.ptr_end.is_some() || right_typ.ptr_end.is_some() {
+                error_with_node!("ポインタに対して行えない計算です。", &node);
+            }
+            if left_typ.is_floating() || right_typ.is_floating() {
+                error_with_node!("浮動小数点数に対して行えない計算です。", &node);
+            }
+            let typ = left_typ.int_promoted();
+            let _ = node.left.insert(new_cast(&left, typ.clone()));
+            let _ = node
+                .right
+                .insert(new_cast(&right, right_typ.int_promoted()));
+            let _ = node.typ.insert(typ);
         }
         Nodekind::MulNd
         | Nodekind::DivNd
         | Nodekind::ModNd
         | Nodekind::BitAndNd
         | Nodekind::BitOrNd
-        | Nodekind::BitXorNd
-        | Nodekind::LShiftNd
-        | Nodekind::RShiftNd => {
+        | Nodekind::BitXorNd => {
             let typ = arith_cast(&mut node);
             if typ.ptr_end.is_some() {
                 error_with_node!("ポインタに対して行えない計算です。", &node);
             }
+            if typ.is_floating() && ![Nodekind::MulNd, Nodekind::DivNd].contains(&kind) {
+                error_with_node!("浮動小数点数に対して行えない計算です。", &node);
+            }
+            let _ = node.typ.insert(typ);
+        }
+        Nodekind::CondNd => {
+            let enter = Rc::clone(node.enter.as_ref().unwrap());
+            check_void(&enter);
+            let _ = node.enter.insert(cond_cast(&enter));
+            let typ = cond_type(&node);
+            if !typ.is_void() {
+                let branch = new_cast(node.branch.as_ref().unwrap(), typ.clone());
+                let els = new_cast(node.els.as_ref().unwrap(), typ.clone());
+                let _ = node.branch.insert(branch);
+                let _ = node.els.insert(els);
+            }
             let _ = node.typ.insert(typ);
         }
         Nodekind::LogNotNd | Nodekind::LogAndNd | Nodekind::LogOrNd => {
+            // 浮動小数点数は _Bool に変換してから真偽を判定する
+            let left = Rc::clone(node.left.as_ref().unwrap());
+            check_void(&left);
+            let _ = node.left.insert(cond_cast(&left));
+            if let Some(right) = node.right.clone() {
+                check_void(&right);
+                let _ = node.right.insert(cond_cast(&right));
+            }
             let _ = node.typ.insert(TypeCell::new(Type::Int));
         }
         Nodekind::EqNd | Nodekind::NEqNd | Nodekind::LThanNd | Nodekind::LEqNd => {
@@ -510,8 +829,21 @@ fn confirm_type(node: &NodeRef) {
             let _ = node.typ.insert(ret_typ);
         }
         Nodekind::ReturnNd => {
-            let left_typ = node.left.as_ref().unwrap().borrow().typ.clone().unwrap();
-            let _ = node.typ.insert(left_typ);
+            // void の関数の return には値がない
+            let left = if let Some(left) = node.left.as_ref() {
+                Rc::clone(left)
+            } else {
+                let _ = node.typ.insert(TypeCell::new(Type::Void));
+                return;
+            };
+
+            // 戻り値は関数の戻り値の型に変換する
+            check_void(&left);
+            let left_typ = left.borrow().typ.clone().unwrap();
+            let typ = CUR_RET_TYP.try_lock().unwrap().clone().unwrap_or(left_typ);
+            check_ptr_conversion(&left, &typ);
+            let _ = node.left.insert(new_cast(&left, typ.clone()));
+            let _ = node.typ.insert(typ);
         }
         Nodekind::ZeroClrNd => {
             let left_typ = node.left.as_ref().unwrap().borrow().typ.clone().unwrap();
@@ -528,40 +860,71 @@ fn program(token_ptr: &mut TokenRef) -> Vec<NodeRef> {
     while !at_eof(token_ptr) {
         enter_scope();
         globals.push(global(token_ptr));
+        for node in STATIC_LVARS.try_lock().unwrap().drain(..) {
+            globals.push(Rc::new(RefCell::new(node)));
+        }
         leave_scope();
         assert_eq!(LOCALS.try_lock().unwrap().len(), 0);
 
         // 関数宣言が終わるごとにローカル変数の管理情報をクリア(offset や name としてノードが持っているのでこれ以上必要ない)
         *LVAR_MAX_OFFSET.try_lock().unwrap() = 0;
     }
+    for glob in &globals {
+        finish_tentative(glob);
+    }
     globals
 }
 
 /// 生成規則:
 /// global = type (function | global-variable)
 fn global(token_ptr: &mut TokenRef) -> NodeRef {
-    let typ = expect_type(token_ptr);
+    let token = Rc::clone(token_ptr);
+    let (typ, storage) = expect_decl_type(token_ptr);
     let glob = if is_func(token_ptr) {
-        function(token_ptr, typ)
+        if typ.alignas.is_some() {
+            error_with_token!("関数には _Alignas を指定できません。", &token.borrow());
+        }
+        function(token_ptr, typ, &storage)
     } else {
-        global_variable(token_ptr, typ)
+        global_variable(token_ptr, typ, &storage)
     };
     glob
 }
 
+/// グローバルなノードとその GLOBALS の登録内容にリンケージの情報を設定する
+fn set_linkage(glob: &NodeRef, is_static: bool, is_extern: bool) {
+    let mut node = glob.borrow_mut();
+    node.is_static = is_static;
+    node.is_extern = is_extern;
+    if let Some(entry) = GLOBALS
+        .try_lock()
+        .unwrap()
+        .get_mut(node.name.as_ref().unwrap())
+    {
+        entry.is_static = is_static;
+        entry.is_extern = is_extern;
+    }
+}
+
 #[inline]
 pub fn is_func(token_ptr: &TokenRef) -> bool {
     let ptr = &mut Rc::clone(token_ptr);
     let dummy = TypeCell::default();
-    declarator(ptr, dummy).1.typ == Type::Func
+    peek_declarator(ptr, dummy).1.typ == Type::Func
 }
 
 /// 生成規則:
 /// function = func-declarator ("{" stmt* "}")?
-fn function(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
+fn function(token_ptr: &mut TokenRef, typ: TypeCell, storage: &Option<String>) -> NodeRef {
     let token = Rc::clone(token_ptr);
-    let (name, typ, args) = func_declarator(token_ptr, typ);
-
+    *ALLOCA_BOTTOM.try_lock().unwrap() = None;
+    let mark = vla_mark();
+    let (name, mut typ, args) = func_declarator(token_ptr, typ);
+    // 引数の可変長配列の要素数は関数の先頭で計算する
+    let vla_dims = take_vla_dims(mark);
+
+    // 一度 static で宣言された関数は、以降の宣言・定義でも内部リンケージを持つ
+    let mut is_static = storage.as_deref() == Some("static");
     let (defined, line_num, line_offset) =
         if let Some(node) = GLOBALS.try_lock().unwrap().get(&name) {
             let decl = node.token.as_ref().unwrap().borrow();
@@ -575,6 +938,16 @@ fn function(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
                     _offset
                 );
             }
+            if is_static && !node.is_static {
+                error_with_token!(
+                    "\"{}\"は位置[{}, {}]で既に外部リンケージを持つ関数として宣言されています。",
+                    &*token.borrow(),
+                    name,
+                    _num,
+                    _offset
+                );
+            }
+            is_static |= node.is_static;
             (node.stmts.is_some(), _num, _offset)
         } else {
             (false, 0, 0)
@@ -584,6 +957,19 @@ fn function(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
         if typ.is_abstract {
             error_with_token!("関数の定義時には引数名を省略できません。", &*token.borrow());
         }
+        let _ = CUR_RET_TYP
+            .try_lock()
+            .unwrap()
+            .insert(typ.ret_typ.as_ref().unwrap().borrow().clone());
+        *IN_FUNCTION.try_lock().unwrap() = true;
+        // 可変長引数をとる関数では、 va_list の初期値(24 バイト)と、引数を渡しうる汎用レジスタ 6 つ・ xmm レジスタ 8 つの値を保存する領域を確保する
+        *VA_AREA.try_lock().unwrap() = if typ.is_variadic {
+            hidden_lvar(TypeCell::new(Type::Long).make_array_of(25))
+                .borrow()
+                .offset
+        } else {
+            None
+        };
         // 既に宣言されている場合をケア
         let node = GLOBALS
             .try_lock()
@@ -611,6 +997,11 @@ fn function(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
                         line_offset
                     );
                 }
+                // "()" による定義はプロトタイプを与えないので、先にプロトタイプ宣言があればその型で呼び出しをチェックする
+                let proto_typ = node.func_typ.as_ref().unwrap();
+                if typ.is_unprototyped && !proto_typ.is_unprototyped {
+                    typ = proto_typ.clone();
+                }
             }
             Nodekind::DefaultNd => {
                 // プロトタイプ宣言がない場合は、再帰のことを考えて定義のパース前に GLOBALS に一旦プロトタイプ宣言の体で保存する
@@ -621,44 +1012,84 @@ fn function(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
             }
         }
 
-        let mut stmts: Vec<NodeRef> = Vec::new();
-        let mut has_return: bool = false;
+        let mut stmts: Vec<NodeRef> = vla_dims.into_iter().collect();
+        let mut end = Rc::clone(token_ptr);
+        // 関数の本体のトップレベルで宣言された可変長配列は、関数から戻る際に解放される
+        VLA_SCOPES.try_lock().unwrap().push(vec![]);
         while !consume(token_ptr, "}") {
-            has_return |= token_ptr.borrow().kind == Tokenkind::ReturnTk; // return がローカルの最大のスコープに出現するかどうかを確認 (ブロックでネストされていると対応できないのが難点…)
             let stmt_ = stmt(token_ptr);
             confirm_type(&stmt_);
             stmts.push(stmt_);
+            end = Rc::clone(token_ptr);
         }
-
-        if !has_return {
-            stmts.push(tmp_unary!(Nodekind::ReturnNd, tmp_num!(0)));
+        VLA_SCOPES.try_lock().unwrap().clear();
+        *IN_FUNCTION.try_lock().unwrap() = false;
+        check_labels();
+
+        // 関数の末尾に制御が到達しうる場合は return を補う(値を補うのは main のみ)
+        if flows(&new_block(stmts.clone()), true, &mut vec![]) {
+            let is_void = typ.ret_typ.as_ref().unwrap().borrow().is_void();
+            stmts.push(if !is_void && name == "main" {
+                tmp_unary!(Nodekind::ReturnNd, tmp_num!(0))
+            } else {
+                if !is_void {
+                    warning_with_token!(
+                        "void 型でない関数\"{}\"の末尾に制御が到達します。[-Wreturn-type]",
+                        &end.borrow(),
+                        name
+                    );
+                }
+                new_void_return(None)
+            });
         }
 
         let mut max_offset_access = LVAR_MAX_OFFSET.try_lock().unwrap();
-        align!(*max_offset_access, 8usize);
+        align!(*max_offset_access, 16usize);
         let max_offset = *max_offset_access;
 
-        new_funcdec(name, typ, args, stmts, max_offset, token)
+        let func = new_funcdec(name, typ, args, stmts, max_offset, token);
+        set_linkage(&func, is_static, false);
+        // alloca を使う関数では、プロローグで領域の底を初期化する必要がある
+        func.borrow_mut().offset = *ALLOCA_BOTTOM.try_lock().unwrap();
+        func.borrow_mut().va_area = VA_AREA.try_lock().unwrap().take();
+        func
     } else {
         expect(token_ptr, ";");
-        let _ = proto_func(name, typ, token);
+        let proto = proto_func(name, typ, token);
+        set_linkage(&proto, is_static, false);
         nop()
     }
 }
 
-/// func-declarator = pointers func-name "(" func-args ")"
+/// 生成規則:
+/// func-declarator = pointers ( "(" func-declarator ")" type-suffix | func-name "(" func-args ")" )
 fn func_declarator(
     token_ptr: &mut TokenRef,
     mut typ: TypeCell,
 ) -> (String, TypeCell, Vec<NodeRef>) {
     typ = pointers(token_ptr, typ);
-    let name = func_name(token_ptr);
 
+    // int (*f(int x))(char) のように括弧の内側で引数が宣言されている場合は、外側の type-suffix が戻り値の型の一部になる
+    let mut ptr = Rc::clone(token_ptr);
+    if consume(&mut ptr, "(") && peek_declarator(&mut ptr, TypeCell::default()).1.typ == Type::Func
+    {
+        expect(&mut ptr, ")");
+        typ = type_suffix(&mut ptr, typ);
+        expect(token_ptr, "(");
+        let ret = func_declarator(token_ptr, typ);
+        *token_ptr = ptr;
+        return ret;
+    }
+
+    let name = func_name(token_ptr);
     expect(token_ptr, "(");
-    let (args, arg_typs) = func_args(token_ptr);
+    let is_unprototyped = is(token_ptr, ")");
+    let (args, arg_typs, is_variadic) = func_args(token_ptr, true);
     let is_abstract = args.len() != arg_typs.len();
     typ = typ.make_func(arg_typs);
     typ.is_abstract = is_abstract;
+    typ.is_variadic = is_variadic;
+    typ.is_unprototyped = is_unprototyped;
     expect(token_ptr, ")");
 
     (name, typ, args)
@@ -676,46 +1107,72 @@ fn func_name(token_ptr: &mut TokenRef) -> String {
 }
 
 /// 生成規則:
-/// func-args = arg ("," arg)* | null
+/// func-args = arg ("," arg)* ("," "...")? | null
 /// arg = type declarator
-fn func_args(token_ptr: &mut TokenRef) -> (Vec<NodeRef>, Vec<TypeCellRef>) {
+/// 関数定義の引数である場合のみ(is_def が true)、引数をローカル変数として登録する
+/// 返り値の最後の要素は、可変長引数をとるかどうか
+fn func_args(token_ptr: &mut TokenRef, is_def: bool) -> (Vec<NodeRef>, Vec<TypeCellRef>, bool) {
     let mut args: Vec<NodeRef> = vec![];
     let mut arg_typs: Vec<TypeCellRef> = vec![];
-    let mut argc: usize = 0;
 
+    // (void) は引数がないことを表す
+    let ptr = &mut Rc::clone(token_ptr);
+    if consume(ptr, "void") && is(ptr, ")") {
+        *token_ptr = Rc::clone(ptr);
+        return (args, arg_typs, false);
+    }
+
+    let mut is_variadic = false;
     while is_type(token_ptr) {
-        if argc >= 6 {
-            error_with_token!(
-                "現在7つ以上の引数はサポートされていません。",
-                &token_ptr.borrow()
-            );
-        }
-        arg(token_ptr, &mut args, &mut arg_typs);
-        argc += 1;
+        arg(token_ptr, &mut args, &mut arg_typs, is_def);
         if !consume(token_ptr, ",") {
             break;
         }
+        if consume(token_ptr, "...") {
+            is_variadic = true;
+            break;
+        }
+    }
+    if is(token_ptr, "...") {
+        error_with_token!(
+            "\"...\" の前には少なくとも1つの引数が必要です。",
+            &token_ptr.borrow()
+        );
     }
     // args.len() != arg_types.len() ならば引数名が省略されており、プロトタイプ宣言であるとみなせる
-    (args, arg_typs)
+    (args, arg_typs, is_variadic)
 }
 
 /// 生成規則:
 /// arg = type declarator
-fn arg(token_ptr: &mut TokenRef, args: &mut Vec<NodeRef>, arg_typs: &mut Vec<TypeCellRef>) {
+fn arg(
+    token_ptr: &mut TokenRef,
+    args: &mut Vec<NodeRef>,
+    arg_typs: &mut Vec<TypeCellRef>,
+    is_def: bool,
+) {
     let typ = expect_type(token_ptr);
     let token = Rc::clone(token_ptr);
-    let (name, typ) = declarator(token_ptr, typ);
+    let (name, mut typ) = declarator(token_ptr, typ);
+    if typ.is_void() {
+        error_with_token!("void 型の引数は宣言できません。", &token.borrow());
+    }
+    // 関数型の引数は関数ポインタとして、配列型の引数は要素へのポインタとして扱う
+    if typ.typ == Type::Func {
+        typ = typ.make_ptr_to();
+    } else if typ.is_array() {
+        typ = typ.make_deref().unwrap().make_ptr_to();
+    }
     arg_typs.push(Rc::new(RefCell::new(typ.clone())));
-    if name != "" {
+    if is_def && !name.is_empty() {
         args.push(new_lvar(name, token, typ, true, 0));
     }
 }
 
 /// 生成規則:
 /// global-variable = gvar-decl ("," gvar-decl)* ";"
-fn global_variable(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
-    let mut node_ptr = gvar_decl(token_ptr, typ.clone());
+fn global_variable(token_ptr: &mut TokenRef, typ: TypeCell, storage: &Option<String>) -> NodeRef {
+    let mut node_ptr = gvar_decl(token_ptr, typ.clone(), storage);
     loop {
         let comma_token = Rc::clone(token_ptr);
         if !consume(token_ptr, ",") {
@@ -724,7 +1181,7 @@ fn global_variable(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
         node_ptr = new_binary(
             Nodekind::CommaNd,
             node_ptr,
-            gvar_decl(token_ptr, typ.clone()),
+            gvar_decl(token_ptr, typ.clone(), storage),
             comma_token,
         )
     }
@@ -735,31 +1192,80 @@ fn global_variable(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
 
 /// 生成規則:
 /// gvar-decl = declarator ("=" initializer)?
-fn gvar_decl(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
+/// 初期化子のない extern 宣言は領域を確保せず、他の翻訳単位(もしくは後の定義)を参照する
+/// 初期化子も extern もない宣言は仮定義(C11 6.9.2)で、同じ変数について何度でも宣言できる
+fn gvar_decl(token_ptr: &mut TokenRef, typ: TypeCell, storage: &Option<String>) -> NodeRef {
     let token = Rc::clone(token_ptr);
-    let (name, typ) = declarator(token_ptr, typ);
+    let mark = vla_mark();
+    let (name, mut typ) = object_declarator(token_ptr, typ);
+    if take_vla_dims(mark).is_some() && typ.typ != Type::Func {
+        error_with_token!(
+            "ファイルスコープでは可変長配列型の変数は宣言できません。",
+            &token.borrow()
+        );
+    }
     if name == "" {
         error_with_token!("変数名を指定する必要があります。", &token.borrow());
     }
-    if let Some(node) = GLOBALS.try_lock().unwrap().get(&name) {
+    if typ.is_void() {
+        error_with_token!("void 型の変数は宣言できません。", &token.borrow());
+    }
+    let has_init = is(token_ptr, "=");
+    let is_extern = storage.as_deref() == Some("extern") && !has_init;
+    let mut is_static = storage.as_deref() == Some("static");
+    let prev = GLOBALS.try_lock().unwrap().get(&name).cloned();
+    if let Some(node) = prev {
         let decl = node.token.as_ref().unwrap().borrow();
-        if node.typ.is_some() {
+        if node.typ.is_none() {
             error_with_token!(
-                "\"{}\"は位置[{}, {}]で既にグローバル変数として宣言されています。",
+                "\"{}\"は位置[{}, {}]で既に関数として宣言されています。",
                 &*token.borrow(),
                 name,
                 decl.line_num,
                 decl.line_offset
             );
-        } else {
+        }
+        // 以前の宣言と型が一致していれば、重ねて宣言・定義できる(配列のサイズはどちらかが省略されていても良い)
+        let alignas = typ.alignas.max(node.typ.as_ref().unwrap().alignas);
+        typ = composite_type(node.typ.as_ref().unwrap(), &typ).unwrap_or_else(|| {
             error_with_token!(
-                "\"{}\"は位置[{}, {}]で既に関数として宣言されています。",
+                "\"{}\"の型が位置[{}, {}]での宣言と一致しません。",
+                &*token.borrow(),
+                name,
+                decl.line_num,
+                decl.line_offset
+            );
+        });
+        typ.alignas = alignas;
+        // extern 宣言は以前の宣言のリンケージを引き継ぐが、それ以外はリンケージが一致している必要がある
+        if is_extern || (node.is_extern && !node.is_static && !is_static) {
+            is_static = node.is_static;
+        } else if is_static != node.is_static {
+            error_with_token!(
+                "\"{}\"は位置[{}, {}]で異なるリンケージを持つ変数として宣言されています。",
+                &*token.borrow(),
+                name,
+                decl.line_num,
+                decl.line_offset
+            );
+        }
+        let is_defined = !node.is_extern && !node.is_tentative;
+        if has_init && is_defined {
+            error_with_token!(
+                "\"{}\"は位置[{}, {}]で既に定義されています。",
                 &*token.borrow(),
                 name,
                 decl.line_num,
                 decl.line_offset
             );
         }
+        if is_extern || (!has_init && !node.is_extern) {
+            // 既に領域が確保されることになっている場合は、型の情報のみ更新する
+            if let Some(entry) = GLOBALS.try_lock().unwrap().get_mut(&name) {
+                let _ = entry.typ.insert(typ);
+            }
+            return nop();
+        }
     }
 
     let is_flex = typ.is_flex_array();
@@ -768,9 +1274,73 @@ fn gvar_decl(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
     } else {
         new_gvar(name, typ, token)
     };
+    set_linkage(&gvar, is_static, is_extern);
+    if is_extern {
+        return nop();
+    }
+    if !has_init {
+        gvar.borrow_mut().is_tentative = true;
+        let name = gvar.borrow().name.clone().unwrap();
+        GLOBALS
+            .try_lock()
+            .unwrap()
+            .get_mut(&name)
+            .unwrap()
+            .is_tentative = true;
+    }
     gvar
 }
 
+/// 同じ変数の2つの宣言の型から合成型を作る: 互換性がなければ None
+fn composite_type(prev: &TypeCell, typ: &TypeCell) -> Option<TypeCell> {
+    // 修飾子が異なる型は互換でない(const int と int など)
+    if prev.typ != typ.typ || !prev.has_qualifiers_of(typ) || !typ.has_qualifiers_of(prev) {
+        return None;
+    }
+    match prev.typ {
+        Type::Array => {
+            composite_type(&prev.make_deref().unwrap(), &typ.make_deref().unwrap())?;
+            match (prev.array_size, typ.array_size) {
+                (Some(x), Some(y)) if x != y => None,
+                (Some(_), _) => Some(prev.clone()),
+                _ => Some(typ.clone()),
+            }
+        }
+        Type::Ptr => {
+            composite_type(&prev.make_deref().unwrap(), &typ.make_deref().unwrap())?;
+            Some(typ.clone())
+        }
+        _ => (prev == typ).then(|| typ.clone()),
+    }
+}
+
+/// 仮定義のみの変数を確定させる: 後で初期化子付きで定義された場合は出力せず、そうでなければ 0 で初期化された定義とする
+fn finish_tentative(node: &NodeRef) {
+    let kind = node.borrow().kind;
+    match kind {
+        Nodekind::CommaNd => {
+            finish_tentative(node.borrow().left.as_ref().unwrap());
+            finish_tentative(node.borrow().right.as_ref().unwrap());
+        }
+        Nodekind::GlobalNd if node.borrow().is_tentative => {
+            let name = node.borrow().name.clone().unwrap();
+            let entry = GLOBALS.try_lock().unwrap().get(&name).cloned().unwrap();
+            let mut node = node.borrow_mut();
+            if entry.is_tentative {
+                // サイズが確定しなかった配列は要素数1の配列とみなす
+                let mut typ = entry.typ.unwrap();
+                if typ.is_flex_array() {
+                    let _ = typ.array_size.insert(1);
+                }
+                let _ = node.typ.insert(typ);
+            } else {
+                node.kind = Nodekind::NopNd;
+            }
+        }
+        _ => {}
+    }
+}
+
 /// 生成規則としては lvar_initializer と同じ
 fn gvar_initializer(
     token_ptr: &mut TokenRef,
@@ -817,11 +1387,8 @@ fn make_gvar_init(init: &Initializer, typ: TypeCell, gvar: &mut NodeRef) {
                 // flatten して読む
                 for _ in 0..elem_flatten_size {
                     let _expr = init.elements[ix].borrow().node.clone().unwrap();
-                    let mut label: Option<String> = None;
-                    let val = eval_const(&_expr, &mut label);
-                    gvar.borrow_mut()
-                        .init_data
-                        .push(InitData::new(base_bytes, val, label));
+                    let data = const_init_data(&_expr, &base_typ);
+                    gvar.borrow_mut().init_data.push(data);
                     ix += 1;
                     finished_bytes += base_bytes;
                     if finished_bytes >= total_bytes || ix >= init.elements.len() {
@@ -837,14 +1404,14 @@ fn make_gvar_init(init: &Initializer, typ: TypeCell, gvar: &mut NodeRef) {
             let mut gvar_bor = gvar.borrow_mut();
             let init_data = &mut gvar_bor.init_data;
             let last_data = init_data.last().unwrap();
-            let mut zero_end = last_data.val == 0 && last_data.label.is_none();
+            let mut zero_end = last_data.is_zero();
             if zero_end {
                 let mut zeros = InitData::new(0, 0, None);
                 while zero_end && init_data.len() > 0 {
                     let merge_data = init_data.pop().unwrap();
                     zeros.size += merge_data.size;
                     if let Some(last_data) = init_data.last() {
-                        zero_end = last_data.val == 0 && last_data.label.is_none();
+                        zero_end = last_data.is_zero();
                     }
                 }
                 init_data.push(zeros);
@@ -857,12 +1424,29 @@ fn make_gvar_init(init: &Initializer, typ: TypeCell, gvar: &mut NodeRef) {
                 .push(InitData::new(total_bytes - finished_bytes, 0, None));
         }
     } else {
-        let mut label: Option<String> = None;
-        let val = eval_const(init.node.as_ref().unwrap(), &mut label);
-        gvar.borrow_mut()
-            .init_data
-            .push(InitData::new(typ.bytes(), val, label));
+        let data = const_init_data(init.node.as_ref().unwrap(), &typ);
+        gvar.borrow_mut().init_data.push(data);
+    }
+}
+
+/// 初期化式を typ に変換したコンパイル時定数を、グローバル変数の初期値として返す
+fn const_init_data(expr: &NodeRef, typ: &TypeCell) -> InitData {
+    check_ptr_conversion(expr, typ);
+    let expr = new_cast(expr, typ.clone());
+    if typ.typ == Type::LDouble {
+        let ldouble = eval_ldouble(&expr, &mut None);
+        if ldouble.is_zero() && !ldouble.sign {
+            return InitData::new(typ.bytes(), 0, None);
+        }
+        return InitData {
+            size: typ.bytes(),
+            ldouble: Some(ldouble),
+            ..Default::default()
+        };
     }
+    let mut label: Option<String> = None;
+    let val = eval_const(&expr, &mut label);
+    InitData::new(typ.bytes(), val, label)
 }
 
 macro_rules! eval_const_left {
@@ -882,9 +1466,15 @@ macro_rules! eval_const_right {
 fn eval_const(node: &NodeRef, label: &mut Option<String>) -> i64 {
     confirm_type(node);
     let typ = node.borrow().typ.clone().unwrap();
+    // 浮動小数点数の値はビット列として返す
+    if typ.is_floating() {
+        return float_bits(eval_float(node, label), &typ) as i64;
+    }
     let kind = node.borrow().kind;
-    match kind {
-        Nodekind::AddNd => eval_const_left!(node, label) + eval_const_right!(node, label),
+    let val = match kind {
+        Nodekind::AddNd => {
+            eval_const_left!(node, label).wrapping_add(eval_const_right!(node, label))
+        }
         Nodekind::SubNd => {
             let left_val = eval_const_left!(node, label);
             let left_label = label.clone();
@@ -902,73 +1492,322 @@ fn eval_const(node: &NodeRef, label: &mut Option<String>) -> i64 {
                 }
                 _ => {}
             }
-            left_val - right_val
+            left_val.wrapping_sub(right_val)
         }
-        Nodekind::MulNd => eval_const_left!(node, label) * eval_const_right!(node, label),
-        Nodekind::DivNd => eval_const_left!(node, label) / eval_const_right!(node, label),
-        Nodekind::ModNd => eval_const_left!(node, label) % eval_const_right!(node, label),
-        Nodekind::LShiftNd => eval_const_left!(node, label) << eval_const_right!(node, label),
-        Nodekind::RShiftNd => eval_const_left!(node, label) >> eval_const_right!(node, label),
-        Nodekind::BitAndNd => eval_const_left!(node, label) & eval_const_right!(node, label),
-        Nodekind::BitOrNd => eval_const_left!(node, label) | eval_const_right!(node, label),
-        Nodekind::BitXorNd => eval_const_left!(node, label) ^ eval_const_right!(node, label),
-        Nodekind::BitNotNd => !eval_const_left!(node, label),
-        Nodekind::LogAndNd => {
-            if eval_const_left!(node, label) == 0 || eval_const_right!(node, label) == 0 {
-                0
-            } else {
-                1
-            }
+        Nodekind::MulNd => {
+            eval_const_left!(node, label).wrapping_mul(eval_const_right!(node, label))
         }
-        Nodekind::LogOrNd => {
-            if eval_const_left!(node, label) == 0 && eval_const_right!(node, label) == 0 {
-                0
-            } else {
-                1
+        Nodekind::DivNd | Nodekind::ModNd => {
+            let left_val = eval_const_left!(node, label);
+            let right_val = eval_const_right!(node, label);
+            if right_val == 0 {
+                error_with_node!("定数式の中でゼロ除算が発生しました。", &node.borrow());
+            }
+            // 符号なしの計算では、値を u64 とみなして計算する
+            match (kind, typ.is_unsigned) {
+                (Nodekind::DivNd, true) => ((left_val as u64) / (right_val as u64)) as i64,
+                (Nodekind::DivNd, false) => left_val.wrapping_div(right_val),
+                (_, true) => ((left_val as u64) % (right_val as u64)) as i64,
+                (_, false) => left_val.wrapping_rem(right_val),
             }
         }
-        Nodekind::LogNotNd => {
-            if eval_const_left!(node, label) == 0 {
-                1
+        Nodekind::LShiftNd => {
+            eval_const_left!(node, label).wrapping_shl(eval_const_right!(node, label) as u32)
+        }
+        Nodekind::RShiftNd => {
+            let left_val = eval_const_left!(node, label);
+            let right_val = eval_const_right!(node, label) as u32;
+            if typ.is_unsigned {
+                // 左辺はノードの型でゼロ拡張済みなので、 u64 として論理シフトすれば良い
+                ((left_val as u64) >> right_val) as i64
             } else {
-                0
+                left_val.wrapping_shr(right_val)
             }
         }
-        Nodekind::CastNd => {
-            let val = eval_const_left!(node, label);
-            match typ.typ.bytes() {
-                1 => {
-                    if typ.is_unsigned {
-                        val as u8 as i64
-                    } else {
-                        val as i8 as i64
-                    }
-                }
-                2 => {
-                    if typ.is_unsigned {
-                        val as u16 as i64
+        Nodekind::BitAndNd => eval_const_left!(node, label) & eval_const_right!(node, label),
+        Nodekind::BitOrNd => eval_const_left!(node, label) | eval_const_right!(node, label),
+        Nodekind::BitXorNd => eval_const_left!(node, label) ^ eval_const_right!(node, label),
+        Nodekind::BitNotNd => !eval_const_left!(node, label),
+        Nodekind::EqNd | Nodekind::NEqNd | Nodekind::LThanNd | Nodekind::LEqNd => {
+            // 両辺は共通の型にキャスト済みなので、左辺の型の符号の有無に従って比較する
+            let is_unsigned = node
+                .borrow()
+                .left
+                .as_ref()
+                .unwrap()
+                .borrow()
+                .typ
+                .as_ref()
+                .unwrap()
+                .is_unsigned;
+            let left = Rc::clone(node.borrow().left.as_ref().unwrap());
+            if left.borrow().typ.as_ref().unwrap().is_floating() {
+                let left_val = eval_float(&left, label);
+                let right_val = eval_float(node.borrow().right.as_ref().unwrap(), label);
+                let res = match kind {
+                    Nodekind::EqNd => left_val == right_val,
+                    Nodekind::NEqNd => left_val != right_val,
+                    Nodekind::LThanNd => left_val < right_val,
+                    _ => left_val <= right_val,
+                };
+                return res as i64;
+            }
+            let left_val = eval_const_left!(node, label);
+            let right_val = eval_const_right!(node, label);
+            let res = match (kind, is_unsigned) {
+                (Nodekind::EqNd, _) => left_val == right_val,
+                (Nodekind::NEqNd, _) => left_val != right_val,
+                (Nodekind::LThanNd, true) => (left_val as u64) < (right_val as u64),
+                (Nodekind::LThanNd, false) => left_val < right_val,
+                (_, true) => (left_val as u64) <= (right_val as u64),
+                (_, false) => left_val <= right_val,
+            };
+            res as i64
+        }
+        Nodekind::CondNd => {
+            let (cond, branch) = cond_operands(node);
+            let cond_val = eval_const(&cond, label);
+            if label.is_some() {
+                error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+            }
+            // 選ばれなかった方は評価しない(定数でなくても良い)
+            if cond_val != 0 {
+                eval_const(&branch, label)
+            } else {
+                eval_const(node.borrow().els.as_ref().unwrap(), label)
+            }
+        }
+        Nodekind::LogAndNd => {
+            if eval_const_left!(node, label) == 0 || eval_const_right!(node, label) == 0 {
+                0
+            } else {
+                1
+            }
+        }
+        Nodekind::LogOrNd => {
+            if eval_const_left!(node, label) == 0 && eval_const_right!(node, label) == 0 {
+                0
+            } else {
+                1
+            }
+        }
+        Nodekind::LogNotNd => {
+            if eval_const_left!(node, label) == 0 {
+                1
+            } else {
+                0
+            }
+        }
+        Nodekind::CommaNd => {
+            let _ = eval_const_left!(node, label);
+            eval_const_right!(node, label)
+        }
+        Nodekind::CastNd => {
+            let left = Rc::clone(node.borrow().left.as_ref().unwrap());
+            if left.borrow().typ.as_ref().unwrap().is_floating() {
+                // 浮動小数点数から整数への変換は 0 方向への切り捨て
+                if left.borrow().typ.as_ref().unwrap().typ == Type::LDouble {
+                    let ldouble = eval_ldouble(&left, label);
+                    if typ.typ == Type::Bool {
+                        !ldouble.is_zero() as i64
                     } else {
-                        val as i16 as i64
+                        ldouble.to_i128() as i64
                     }
-                }
-                4 => {
-                    if typ.is_unsigned {
-                        val as u32 as i64
+                } else {
+                    let fval = eval_float(&left, label);
+                    if typ.typ == Type::Bool {
+                        (fval != 0.0) as i64
+                    } else if typ.is_unsigned && typ.bytes() == 8 {
+                        fval as u64 as i64
                     } else {
-                        val as i32 as i64
+                        fval as i64
                     }
                 }
-                _ => val,
+            } else {
+                let val = eval_const(&left, label);
+                if typ.typ == Type::Bool {
+                    (val != 0) as i64
+                } else {
+                    val
+                }
             }
         }
         Nodekind::AddrNd => eval_label(node.borrow().left.as_ref().unwrap(), label),
-        Nodekind::NumNd => node.borrow().val.unwrap() as i64,
+        Nodekind::LabelAddrNd => {
+            let _ = label.insert(format!(".LLabel{}", node.borrow().label.unwrap()));
+            0
+        }
+        // 配列や関数はそれ自体へのポインタとして扱われるので、アドレスを表すラベルになる
+        Nodekind::LvarNd if typ.is_one_of(&[Type::Array, Type::Func]) => eval_label(node, label),
+        Nodekind::NumNd => node.borrow().val.unwrap(),
+        _ => {
+            error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+        }
+    };
+
+    // 実行時と同様に、値をノードの型で表現できる範囲に収める
+    wrap_const(val, &typ)
+}
+
+/// 定数式として評価する条件演算子の条件と、真の場合の値を返す
+/// a ?: b は (tmp = a) ? tmp : b と読み替えているため、 init に保持している元の a を両方に使う
+fn cond_operands(node: &NodeRef) -> (NodeRef, NodeRef) {
+    let node = node.borrow();
+    if let Some(gnu) = node.init.as_ref() {
+        (cond_cast(gnu), new_cast(gnu, node.typ.clone().unwrap()))
+    } else {
+        (
+            Rc::clone(node.enter.as_ref().unwrap()),
+            Rc::clone(node.branch.as_ref().unwrap()),
+        )
+    }
+}
+
+/// 浮動小数点数のコンパイル時定数の処理を行う関数
+fn eval_float(node: &NodeRef, label: &mut Option<String>) -> f64 {
+    confirm_type(node);
+    let typ = node.borrow().typ.clone().unwrap();
+    if typ.typ == Type::LDouble {
+        return eval_ldouble(node, label).to_f64();
+    }
+    let kind = node.borrow().kind;
+    let val = match kind {
+        Nodekind::NegNd => -eval_float(node.borrow().left.as_ref().unwrap(), label),
+        Nodekind::AddNd | Nodekind::SubNd | Nodekind::MulNd | Nodekind::DivNd => {
+            let left_val = eval_float(node.borrow().left.as_ref().unwrap(), label);
+            let right_val = eval_float(node.borrow().right.as_ref().unwrap(), label);
+            match kind {
+                Nodekind::AddNd => left_val + right_val,
+                Nodekind::SubNd => left_val - right_val,
+                Nodekind::MulNd => left_val * right_val,
+                _ => left_val / right_val,
+            }
+        }
+        Nodekind::CommaNd => {
+            let _ = eval_const_left!(node, label);
+            eval_float(node.borrow().right.as_ref().unwrap(), label)
+        }
+        Nodekind::CondNd => {
+            let (cond, branch) = cond_operands(node);
+            let cond_val = eval_const(&cond, label);
+            if label.is_some() {
+                error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+            }
+            if cond_val != 0 {
+                eval_float(&branch, label)
+            } else {
+                eval_float(node.borrow().els.as_ref().unwrap(), label)
+            }
+        }
+        Nodekind::CastNd => {
+            let left = Rc::clone(node.borrow().left.as_ref().unwrap());
+            let left_typ = left.borrow().typ.clone().unwrap();
+            if left_typ.is_floating() {
+                eval_float(&left, label)
+            } else {
+                let val = eval_const(&left, label);
+                if label.is_some() {
+                    error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+                }
+                if left_typ.is_unsigned && left_typ.bytes() == 8 {
+                    val as u64 as f64
+                } else {
+                    val as f64
+                }
+            }
+        }
+        Nodekind::NumNd => node.borrow().fval.unwrap(),
+        _ => {
+            error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+        }
+    };
+
+    // float の計算結果は float の精度に丸める
+    if typ.typ == Type::Float {
+        val as f32 as f64
+    } else {
+        val
+    }
+}
+
+/// long double のコンパイル時定数の処理を行う関数: f64 を経由せず 80bit の精度で計算する
+fn eval_ldouble(node: &NodeRef, label: &mut Option<String>) -> Float80 {
+    confirm_type(node);
+    let kind = node.borrow().kind;
+    match kind {
+        Nodekind::NegNd => eval_ldouble(node.borrow().left.as_ref().unwrap(), label).negate(),
+        Nodekind::AddNd | Nodekind::SubNd | Nodekind::MulNd | Nodekind::DivNd => {
+            let left_val = eval_ldouble(node.borrow().left.as_ref().unwrap(), label);
+            let right_val = eval_ldouble(node.borrow().right.as_ref().unwrap(), label);
+            match kind {
+                Nodekind::AddNd => left_val.add(right_val),
+                Nodekind::SubNd => left_val.sub(right_val),
+                Nodekind::MulNd => left_val.mul(right_val),
+                _ => left_val.div(right_val),
+            }
+        }
+        Nodekind::CommaNd => {
+            let _ = eval_const_left!(node, label);
+            eval_ldouble(node.borrow().right.as_ref().unwrap(), label)
+        }
+        Nodekind::CondNd => {
+            let (cond, branch) = cond_operands(node);
+            let cond_val = eval_const(&cond, label);
+            if label.is_some() {
+                error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+            }
+            if cond_val != 0 {
+                eval_ldouble(&branch, label)
+            } else {
+                eval_ldouble(node.borrow().els.as_ref().unwrap(), label)
+            }
+        }
+        Nodekind::CastNd => {
+            let left = Rc::clone(node.borrow().left.as_ref().unwrap());
+            let left_typ = left.borrow().typ.clone().unwrap();
+            if left_typ.typ == Type::LDouble {
+                eval_ldouble(&left, label)
+            } else if left_typ.is_floating() {
+                Float80::from_f64(eval_float(&left, label))
+            } else {
+                let val = eval_const(&left, label);
+                if label.is_some() {
+                    error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
+                }
+                if left_typ.is_unsigned && left_typ.bytes() == 8 {
+                    Float80::from_u64(val as u64)
+                } else {
+                    Float80::from_i64(val)
+                }
+            }
+        }
+        // リテラルは f64 に丸める前の表記から値を求める
+        Nodekind::NumNd => match node.borrow().token.as_ref() {
+            Some(token) => Float80::parse(token.borrow().body.as_ref().unwrap()),
+            None => Float80::from_f64(node.borrow().fval.unwrap()),
+        },
         _ => {
             error_with_node!("コンパイル時定数のみが使用可能です。", &node.borrow());
         }
     }
 }
 
+/// 定数を型に合わせて切り詰め、符号拡張もしくはゼロ拡張する
+fn wrap_const(val: i64, typ: &TypeCell) -> i64 {
+    if !typ.is_integer() {
+        return val;
+    }
+    match (typ.bytes(), typ.is_unsigned) {
+        (1, true) => val as u8 as i64,
+        (1, false) => val as i8 as i64,
+        (2, true) => val as u16 as i64,
+        (2, false) => val as i16 as i64,
+        (4, true) => val as u32 as i64,
+        (4, false) => val as i32 as i64,
+        _ => val,
+    }
+}
+
 /// グローバル変数のアドレス評価時などに使用する関数
 fn eval_label(node: &NodeRef, label: &mut Option<String>) -> i64 {
     let kind = node.borrow().kind;
@@ -992,13 +1831,16 @@ fn eval_label(node: &NodeRef, label: &mut Option<String>) -> i64 {
 }
 
 /// 生成規則:
-/// declarator = pointers ("(" declarator ")" | ident ) type-suffix
+/// declarator = pointers ("(" declarator ")" type-suffix | ident? type-suffix)
+/// 識別子を省略した場合は抽象宣言子(型名で用いる int (*)[10] など)となる
+/// 括弧の内側の宣言子は外側の型を修飾するため、先に外側の type-suffix を読んでから内側を読み直す
 fn declarator(token_ptr: &mut TokenRef, mut typ: TypeCell) -> (String, TypeCell) {
     typ = pointers(token_ptr, typ);
-    if consume(token_ptr, "(") {
+    if is(token_ptr, "(") && !is_func_suffix(token_ptr) {
+        expect(token_ptr, "(");
         let ref mut token = Rc::clone(token_ptr);
         let dummy = TypeCell::default();
-        let _ = declarator(token, dummy);
+        let _ = peek_declarator(token, dummy);
         expect(token, ")");
         typ = type_suffix(token, typ);
         let nt = declarator(token_ptr, typ);
@@ -1017,11 +1859,88 @@ fn declarator(token_ptr: &mut TokenRef, mut typ: TypeCell) -> (String, TypeCell)
     }
 }
 
+/// 変数の宣言子: _Alignas は宣言されるオブジェクト自身に適用されるため、型指定子の側から宣言子の結果の型へ移す
+fn object_declarator(token_ptr: &mut TokenRef, mut typ: TypeCell) -> (String, TypeCell) {
+    let token = Rc::clone(token_ptr);
+    let alignas = typ.alignas.take();
+    let (name, mut typ) = declarator(token_ptr, typ);
+    if let Some(align) = alignas.filter(|&align| align > 0) {
+        if typ.typ == Type::Func {
+            error_with_token!("関数には _Alignas を指定できません。", &token.borrow());
+        }
+        if align < typ.align() {
+            error_with_token!(
+                "型\"{}\"のアラインメント({})より小さいアラインメントは指定できません。",
+                &token.borrow(),
+                typ,
+                typ.align()
+            );
+        }
+        let _ = typ.alignas.insert(align);
+    }
+    (name, typ)
+}
+
+/// 生成規則:
+/// alignas-specifier = "_Alignas" "(" (type-name | const-expr) ")"
+/// 指定されたアラインメントを返す(0 の場合は指定がないものとして扱われる)
+pub fn alignas_specifier(token_ptr: &mut TokenRef) -> usize {
+    expect(token_ptr, "_Alignas");
+    expect(token_ptr, "(");
+    let token = Rc::clone(token_ptr);
+    let align = if is_type(token_ptr) {
+        let typ = type_name(token_ptr);
+        if typ.is_void() || typ.typ == Type::Func {
+            error_with_token!(
+                "型\"{}\"のアラインメントは取得できません。",
+                &token.borrow(),
+                typ
+            );
+        }
+        typ.align()
+    } else {
+        let val = const_expr(token_ptr);
+        if val < 0 || (val as u64).count_ones() > 1 {
+            error_with_token!(
+                "アラインメントには 2 のべき乗を指定する必要があります。",
+                &token.borrow()
+            );
+        }
+        val as usize
+    };
+    expect(token_ptr, ")");
+    align
+}
+
+/// "(" の後に型か ")" が続く場合は、括弧で囲まれた宣言子ではなく関数の引数リストである
+fn is_func_suffix(token_ptr: &TokenRef) -> bool {
+    let mut ptr = Rc::clone(token_ptr);
+    consume(&mut ptr, "(") && (is_type(&mut ptr) || is(&mut ptr, ")"))
+}
+
+/// 生成規則:
+/// type-name = type declarator (ただし識別子は持たない)
+fn type_name(token_ptr: &mut TokenRef) -> TypeCell {
+    let typ = expect_type(token_ptr);
+    let token = Rc::clone(token_ptr);
+    let (name, typ) = declarator(token_ptr, typ);
+    if !name.is_empty() {
+        error_with_token!(
+            "型名には識別子\"{}\"を含めることができません。",
+            &token.borrow(),
+            name
+        );
+    }
+    typ
+}
+
 /// 生成規則:
-/// pointers = ("*")*
+/// pointers = ("*" qualifier*)*
+/// qualifier = "const" | "volatile" | "restrict"
 fn pointers(token_ptr: &mut TokenRef, mut typ: TypeCell) -> TypeCell {
     while consume(token_ptr, "*") {
         typ = typ.make_ptr_to();
+        consume_qualifiers(token_ptr, &mut typ);
     }
     typ
 }
@@ -1030,9 +1949,20 @@ fn pointers(token_ptr: &mut TokenRef, mut typ: TypeCell) -> TypeCell {
 /// type-suffix = "(" func-args ")" | "[" array-suffix | null
 fn type_suffix(token_ptr: &mut TokenRef, typ: TypeCell) -> TypeCell {
     if consume(token_ptr, "(") {
-        let (_, arg_typs) = func_args(token_ptr);
+        let is_unprototyped = is(token_ptr, ")");
+        let (_, arg_typs, is_variadic) = func_args(token_ptr, false);
         expect(token_ptr, ")");
-        typ.make_func(arg_typs)
+        if typ.is_array() || typ.typ == Type::Func {
+            error_with_token!(
+                "関数は型\"{}\"の値を返すことができません。",
+                &token_ptr.borrow(),
+                typ
+            );
+        }
+        let mut typ = typ.make_func(arg_typs);
+        typ.is_variadic = is_variadic;
+        typ.is_unprototyped = is_unprototyped;
+        typ
     } else if consume(token_ptr, "[") {
         array_suffix(token_ptr, typ)
     } else {
@@ -1043,8 +1973,8 @@ fn type_suffix(token_ptr: &mut TokenRef, typ: TypeCell) -> TypeCell {
 /// 生成規則:
 /// declaration = type lvar-decl ("," lvar-decl )* ";"
 fn declaration(token_ptr: &mut TokenRef) -> NodeRef {
-    let typ = expect_type(token_ptr);
-    let mut node_ptr = lvar_decl(token_ptr, typ.clone());
+    let (typ, storage) = expect_decl_type(token_ptr);
+    let mut node_ptr = lvar_decl(token_ptr, typ.clone(), &storage);
     loop {
         let token = Rc::clone(token_ptr);
         if !consume(token_ptr, ",") {
@@ -1053,7 +1983,7 @@ fn declaration(token_ptr: &mut TokenRef) -> NodeRef {
         node_ptr = new_binary(
             Nodekind::CommaNd,
             node_ptr,
-            lvar_decl(token_ptr, typ.clone()),
+            lvar_decl(token_ptr, typ.clone(), &storage),
             token,
         )
     }
@@ -1064,12 +1994,17 @@ fn declaration(token_ptr: &mut TokenRef) -> NodeRef {
 
 /// 生成規則:
 /// lvar-decl = declarator ("=" initializer)?
-fn lvar_decl(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
+fn lvar_decl(token_ptr: &mut TokenRef, typ: TypeCell, storage: &Option<String>) -> NodeRef {
     let token = Rc::clone(token_ptr);
-    let (name, typ) = declarator(token_ptr, typ);
+    let mark = vla_mark();
+    let (name, typ) = object_declarator(token_ptr, typ);
+    let vla_dims = take_vla_dims(mark);
     if name == "" {
         error_with_token!("変数名を指定する必要があります。", &token.borrow());
     }
+    if typ.is_void() {
+        error_with_token!("void 型の変数は宣言できません。", &token.borrow());
+    }
     if LOCALS
         .try_lock()
         .unwrap()
@@ -1080,8 +2015,34 @@ fn lvar_decl(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
         error_with_token!("既に宣言された変数です。", &token.borrow());
     }
 
+    if typ.typ == Type::Func {
+        return block_func_decl(name, typ, storage, token);
+    }
+    if vla_dims.is_some() && storage.is_some() {
+        error_with_token!(
+            "可変長配列型の変数は static や extern では宣言できません。",
+            &token.borrow()
+        );
+    }
+    match storage.as_deref() {
+        Some("static") => return static_lvar_decl(token_ptr, name, typ, token),
+        Some("extern") => return extern_lvar_decl(token_ptr, name, typ, token),
+        _ => {}
+    }
+    // rbp は 16 bytes 境界にしか揃っていないため、それより大きいアラインメントはスタック上では保証できない
+    if typ.var_align() > 16 {
+        error_with_token!(
+            "ローカル変数には16バイトを超えるアラインメントは指定できません。",
+            &token.borrow()
+        );
+    }
+
+    if typ.is_vla() {
+        return vla_decl(token_ptr, name, typ, vla_dims.unwrap(), token);
+    }
+
     let is_flex = typ.is_flex_array();
-    if consume(token_ptr, "=") {
+    let decl = if consume(token_ptr, "=") {
         lvar_initializer(token_ptr, name, typ, is_flex, token)
     } else {
         // 初期化しない場合は何もアセンブリを吐かない
@@ -1093,94 +2054,475 @@ fn lvar_decl(token_ptr: &mut TokenRef, typ: TypeCell) -> NodeRef {
         }
         let _ = new_lvar(name, token, typ, true, current_scope());
         nop()
-    }
-}
-
-/// 生成規則:
-/// array-suffix = const-expr? "]" ("[" array-suffix)?
-fn array_suffix(token_ptr: &mut TokenRef, mut typ: TypeCell) -> TypeCell {
-    let ptr_err = Rc::clone(token_ptr);
-
-    let array_size = if consume(token_ptr, "]") {
-        None
-    } else {
-        let size = const_expr(token_ptr);
-        if size < 0 {
-            error_with_token!(
-                "配列のサイズは0以上である必要があります。",
-                &ptr_err.borrow()
-            );
-        }
-        expect(token_ptr, "]");
-        Some(size)
     };
-
-    // 配列の次元は後ろから処理する
-    if consume(token_ptr, "[") {
-        let ptr_err = Rc::clone(token_ptr);
-        if consume(token_ptr, "]") {
-            error_with_token!(
-                "2次元目以降の要素サイズは必ず指定する必要があります。",
-                &ptr_err.borrow()
-            );
-        }
-        typ = array_suffix(token_ptr, typ);
-    }
-
-    if let Some(size) = array_size {
-        typ.make_array_of(size as usize)
+    // 可変長配列へのポインタなどは、宣言の前に要素数を計算しておく
+    if let Some(dims) = vla_dims {
+        tmp_binary!(Nodekind::CommaNd, dims, decl)
     } else {
-        typ.make_flex_array_of()
+        decl
     }
 }
 
-fn const_expr(token_ptr: &mut TokenRef) -> i64 {
-    let ref node_ptr = expr(token_ptr);
-    let ref mut label: Option<String> = Default::default();
-    let val = eval_const(node_ptr, label);
-    if label.is_some() {
-        error_with_node!("定数ではありません", &node_ptr.borrow());
+/// 可変長配列の宣言: 要素数を計算した後、必要なサイズをスタック上に確保してそのアドレスを変数に保存する
+fn vla_decl(
+    token_ptr: &mut TokenRef,
+    name: String,
+    typ: TypeCell,
+    dims: NodeRef,
+    token: TokenRef,
+) -> NodeRef {
+    if is(token_ptr, "=") {
+        error_with_token!("可変長配列は初期化できません。", &token_ptr.borrow());
     }
-    val
+    let var = new_lvar(name, Rc::clone(&token), typ.clone(), true, current_scope());
+    let offset = var.borrow().offset.unwrap();
+    let slot = direct_offset_lvar(offset, TypeCell::new(Type::Void).make_ptr_to());
+    let alloc = new_alloca(size_node(&typ), token);
+    // 確保する前の領域の底を保存しておき、スコープを抜ける際にはそこまでスタックを解放する
+    let ptr_typ = TypeCell::new(Type::Void).make_ptr_to();
+    let saved = hidden_lvar(ptr_typ.clone());
+    let bottom = direct_offset_lvar(alloc.borrow().offset.unwrap(), ptr_typ);
+    if let Some(scope) = VLA_SCOPES.try_lock().unwrap().last_mut() {
+        scope.push(saved.borrow().offset.unwrap());
+    }
+    tmp_binary!(
+        Nodekind::CommaNd,
+        dims,
+        tmp_binary!(
+            Nodekind::CommaNd,
+            tmp_binary!(Nodekind::AssignNd, saved, bottom),
+            tmp_binary!(Nodekind::AssignNd, slot, alloc)
+        )
+    )
 }
 
-/// 規則 initializer により Initializer を生成し、AssignNd による代入へと変換する
-fn lvar_initializer(
-    token_ptr: &mut TokenRef,
+/// ブロック内の関数宣言: 関数はブロック内では定義できないため、プロトタイプ宣言として扱う
+fn block_func_decl(
     name: String,
-    mut typ: TypeCell,
-    is_flex: bool,
+    typ: TypeCell,
+    storage: &Option<String>,
     token: TokenRef,
 ) -> NodeRef {
-    if typ.is_array() && !is_kind(token_ptr, Tokenkind::StringTk) && !is(token_ptr, "{") {
-        error_with_token!("配列の初期化の形式が異なります。", &token_ptr.borrow());
-    }
-    if typ.array_dim().0.len() > 1 && is_kind(token_ptr, Tokenkind::StringTk) {
+    if storage.as_deref() == Some("static") {
         error_with_token!(
-            "2次元以上の配列\"{}\"は単一の文字リテラルでは初期化できません。",
-            &*token_ptr.borrow(),
-            typ
+            "ブロック内の関数宣言には static を指定できません。",
+            &token.borrow()
         );
     }
-
-    let init = initializer(token_ptr, typ.clone());
-    if is_flex {
-        let _ = typ.array_size.insert(init.flex_elem_count());
-    }
-
-    let lvar = new_lvar(name, Rc::clone(&token), typ.clone(), true, current_scope());
+    let prev = GLOBALS.try_lock().unwrap().get(&name).cloned();
+    match prev {
+        Some(node) if node.typ.is_some() => {
+            let decl = node.token.as_ref().unwrap().borrow();
+            error_with_token!(
+                "\"{}\"は位置[{}, {}]で既にグローバル変数として宣言されています。",
+                &*token.borrow(),
+                name,
+                decl.line_num,
+                decl.line_offset
+            );
+        }
+        Some(node) => {
+            if typ != *node.func_typ.as_ref().unwrap() {
+                let decl = node.token.as_ref().unwrap().borrow();
+                error_with_token!(
+                    "プロトタイプ宣言との互換性がありません。(宣言位置: [{}, {}])",
+                    &*token.borrow(),
+                    decl.line_num,
+                    decl.line_offset
+                );
+            }
+        }
+        None => {
+            let _ = proto_func(name, typ, token);
+        }
+    }
+    nop()
+}
+
+/// static なローカル変数: 一意なラベルを持つグローバル変数として領域を確保し、関数の呼び出しをまたいで値を保持する
+fn static_lvar_decl(
+    token_ptr: &mut TokenRef,
+    name: String,
+    typ: TypeCell,
+    token: TokenRef,
+) -> NodeRef {
+    let label = {
+        let mut count = STATIC_LVAR_COUNT.try_lock().unwrap();
+        *count += 1;
+        format!("{}.{}", name, *count - 1)
+    };
+    let is_flex = typ.is_flex_array();
+    let gvar = if consume(token_ptr, "=") {
+        gvar_initializer(token_ptr, label.clone(), typ, is_flex, token)
+    } else {
+        if is_flex {
+            error_with_token!(
+                "初期化しない場合は完全な配列サイズが必要です。",
+                &token.borrow()
+            );
+        }
+        new_gvar(label.clone(), typ, token)
+    };
+    set_linkage(&gvar, true, false);
+
+    let typ = gvar.borrow().typ.clone().unwrap();
+    let _ = LOCALS
+        .try_lock()
+        .unwrap()
+        .last_mut()
+        .unwrap()
+        .insert(name, (0, typ, Some(label)));
+    STATIC_LVARS.try_lock().unwrap().push(gvar.borrow().clone());
+    nop()
+}
+
+/// ブロック内の extern 宣言: 同名のグローバル変数を参照する
+fn extern_lvar_decl(
+    token_ptr: &mut TokenRef,
+    name: String,
+    typ: TypeCell,
+    token: TokenRef,
+) -> NodeRef {
+    if is(token_ptr, "=") {
+        error_with_token!(
+            "ブロック内の extern 宣言は初期化できません。",
+            &token_ptr.borrow()
+        );
+    }
+    let prev = GLOBALS.try_lock().unwrap().get(&name).cloned();
+    if let Some(node) = prev {
+        let decl = node.token.as_ref().unwrap().borrow();
+        if node.typ.is_none() {
+            error_with_token!(
+                "\"{}\"は位置[{}, {}]で既に関数として宣言されています。",
+                &*token.borrow(),
+                name,
+                decl.line_num,
+                decl.line_offset
+            );
+        }
+        if composite_type(node.typ.as_ref().unwrap(), &typ).is_none() {
+            error_with_token!(
+                "\"{}\"の型が位置[{}, {}]での宣言と一致しません。",
+                &*token.borrow(),
+                name,
+                decl.line_num,
+                decl.line_offset
+            );
+        }
+    } else {
+        let gvar = new_gvar(name.clone(), typ.clone(), token);
+        set_linkage(&gvar, false, true);
+    }
+    let _ = LOCALS
+        .try_lock()
+        .unwrap()
+        .last_mut()
+        .unwrap()
+        .insert(name.clone(), (0, typ, Some(name)));
+    nop()
+}
+
+/// "*" の直後に "]" が続く場合は、 [*p] のような式ではなく要素数が未指定の [*] である
+fn is_unspecified_size(token_ptr: &TokenRef) -> bool {
+    let mut ptr = Rc::clone(token_ptr);
+    consume(&mut ptr, "*") && is(&mut ptr, "]")
+}
+
+/// 生成規則:
+/// array-suffix = const-expr? "]" ("[" array-suffix)?
+fn array_suffix(token_ptr: &mut TokenRef, mut typ: TypeCell) -> TypeCell {
+    let ptr_err = Rc::clone(token_ptr);
+
+    // 先読み中は要素数の式を評価せずに読み飛ばす
+    if *PEEKING.try_lock().unwrap() {
+        skip_array_size(token_ptr);
+        if consume(token_ptr, "[") {
+            typ = array_suffix(token_ptr, typ);
+        }
+        return typ.make_flex_array_of();
+    }
+
+    let mut vla_len = None;
+    let array_size = if consume(token_ptr, "]") {
+        None
+    } else if is_unspecified_size(token_ptr) {
+        // [*] は要素数が未指定の可変長配列(プロトタイプ宣言で用いる)
+        expect(token_ptr, "*");
+        expect(token_ptr, "]");
+        let _ = vla_len.insert(hidden_lvar(TypeCell::new(Type::Long)));
+        Some(0)
+    } else {
+        let size = expr(token_ptr);
+        if !is_const_expr(&size) {
+            let _ = vla_len.insert(vla_size(Rc::clone(&size), &ptr_err));
+        }
+        let size = if vla_len.is_some() {
+            0
+        } else {
+            eval_const_expr(&size)
+        };
+        if size < 0 {
+            error_with_token!(
+                "配列のサイズは0以上である必要があります。",
+                &ptr_err.borrow()
+            );
+        }
+        expect(token_ptr, "]");
+        Some(size)
+    };
+
+    // 配列の次元は後ろから処理する
+    if consume(token_ptr, "[") {
+        let ptr_err = Rc::clone(token_ptr);
+        if consume(token_ptr, "]") {
+            error_with_token!(
+                "2次元目以降の要素サイズは必ず指定する必要があります。",
+                &ptr_err.borrow()
+            );
+        }
+        typ = array_suffix(token_ptr, typ);
+    }
+
+    if let Some(size) = array_size {
+        let mut typ = typ.make_array_of(size as usize);
+        typ.vla_len = vla_len.map(|len| len.borrow().offset.unwrap());
+        typ
+    } else {
+        typ.make_flex_array_of()
+    }
+}
+
+/// 可変長配列の要素数の値を保存する隠れたローカル変数を確保し、代入式を VLA_DIMS に積む
+fn vla_size(size: NodeRef, token: &TokenRef) -> NodeRef {
+    confirm_type(&size);
+    if !size.borrow().typ.as_ref().unwrap().is_integer() {
+        error_with_token!(
+            "配列のサイズは整数型である必要があります。",
+            &token.borrow()
+        );
+    }
+    let len = hidden_lvar(TypeCell::new(Type::Long));
+    let assign = tmp_binary!(
+        Nodekind::AssignNd,
+        Rc::clone(&len),
+        new_cast(&size, TypeCell::new(Type::Long))
+    );
+    confirm_type(&assign);
+    VLA_DIMS.try_lock().unwrap().push(assign.borrow().clone());
+    len
+}
+
+/// 定数式かどうか(変数の参照や関数呼び出しなどを含まないか)
+fn is_const_expr(node: &NodeRef) -> bool {
+    let node = node.borrow();
+    match node.kind {
+        Nodekind::NumNd => true,
+        Nodekind::LvarNd
+        | Nodekind::FunCallNd
+        | Nodekind::AssignNd
+        | Nodekind::DerefNd
+        | Nodekind::AddrNd
+        | Nodekind::AllocaNd => false,
+        _ => [
+            &node.left,
+            &node.right,
+            &node.enter,
+            &node.branch,
+            &node.els,
+        ]
+        .iter()
+        .all(|child| child.as_ref().is_none_or(is_const_expr)),
+    }
+}
+
+/// 先読み中に配列の要素数の部分を "]" まで読み飛ばす
+fn skip_array_size(token_ptr: &mut TokenRef) {
+    let mut depth = 0;
+    while depth > 0 || !is(token_ptr, "]") {
+        if at_eof(token_ptr) {
+            error_with_token!("\"]\"が必要です。", &token_ptr.borrow());
+        }
+        if is(token_ptr, "[") {
+            depth += 1;
+        } else if is(token_ptr, "]") {
+            depth -= 1;
+        }
+        token_ptr_exceed(token_ptr);
+    }
+    expect(token_ptr, "]");
+}
+
+/// 宣言子の形(型の種類や名前)だけを先読みする
+fn peek_declarator(token_ptr: &mut TokenRef, typ: TypeCell) -> (String, TypeCell) {
+    let outer = std::mem::replace(&mut *PEEKING.try_lock().unwrap(), true);
+    let ret = declarator(token_ptr, typ);
+    *PEEKING.try_lock().unwrap() = outer;
+    ret
+}
+
+/// 名前を持たない(ソースからは参照できない)ローカル変数の領域を確保する
+fn hidden_lvar(typ: TypeCell) -> NodeRef {
+    let mut max_offset_access = LVAR_MAX_OFFSET.try_lock().unwrap();
+    *max_offset_access += typ.bytes();
+    align!(*max_offset_access, typ.var_align());
+    direct_offset_lvar(*max_offset_access, typ)
+}
+
+#[inline]
+fn vla_mark() -> usize {
+    VLA_DIMS.try_lock().unwrap().len()
+}
+
+/// mark 以降に積まれた可変長配列の要素数の計算を、1つのコンマ式にまとめて取り出す
+fn take_vla_dims(mark: usize) -> Option<NodeRef> {
+    let dims: Vec<Node> = VLA_DIMS.try_lock().unwrap().drain(mark..).collect();
+    dims.into_iter()
+        .map(|dim| Rc::new(RefCell::new(dim)))
+        .reduce(|acc, dim| {
+            let comma = tmp_binary!(Nodekind::CommaNd, acc, dim);
+            confirm_type(&comma);
+            comma
+        })
+}
+
+/// 型のサイズを計算するノード: 可変長配列であれば、保存しておいた要素数から実行時に計算する
+fn size_node(typ: &TypeCell) -> NodeRef {
+    if !typ.is_vla() {
+        return tmp_num!(typ.bytes() as i64, Type::Long);
+    }
+    let len = if let Some(offset) = typ.vla_len {
+        direct_offset_lvar(offset, TypeCell::new(Type::Long))
+    } else {
+        tmp_num!(typ.array_size.unwrap() as i64, Type::Long)
+    };
+    let size = tmp_binary!(Nodekind::MulNd, len, size_node(&typ.make_deref().unwrap()));
+    confirm_type(&size);
+    size
+}
+
+/// スタック上に size バイトの領域を確保するノード(関数から戻るまで、もしくは可変長配列のスコープを抜けるまで有効)
+fn new_alloca(size: NodeRef, token: TokenRef) -> NodeRef {
+    confirm_type(&size);
+    let bottom = *ALLOCA_BOTTOM.try_lock().unwrap().get_or_insert_with(|| {
+        hidden_lvar(TypeCell::new(Type::Void).make_ptr_to())
+            .borrow()
+            .offset
+            .unwrap()
+    });
+    Rc::new(RefCell::new(Node {
+        kind: Nodekind::AllocaNd,
+        token: Some(token),
+        typ: Some(TypeCell::new(Type::Void).make_ptr_to()),
+        left: Some(new_cast(&size, TypeCell::new(Type::Long).make_unsigned())),
+        offset: Some(bottom),
+        ..Default::default()
+    }))
+}
+
+fn const_expr(token_ptr: &mut TokenRef) -> i64 {
+    eval_const_expr(&expr(token_ptr))
+}
+
+fn eval_const_expr(node_ptr: &NodeRef) -> i64 {
+    let ref mut label: Option<String> = Default::default();
+    let val = eval_const(node_ptr, label);
+    if label.is_some() {
+        error_with_node!("定数ではありません", &node_ptr.borrow());
+    }
+    val
+}
+
+/// 規則 initializer により Initializer を生成し、AssignNd による代入へと変換する
+fn lvar_initializer(
+    token_ptr: &mut TokenRef,
+    name: String,
+    mut typ: TypeCell,
+    is_flex: bool,
+    token: TokenRef,
+) -> NodeRef {
+    if typ.is_array() && !is_kind(token_ptr, Tokenkind::StringTk) && !is(token_ptr, "{") {
+        error_with_token!("配列の初期化の形式が異なります。", &token_ptr.borrow());
+    }
+    if typ.array_dim().0.len() > 1 && is_kind(token_ptr, Tokenkind::StringTk) {
+        error_with_token!(
+            "2次元以上の配列\"{}\"は単一の文字リテラルでは初期化できません。",
+            &*token_ptr.borrow(),
+            typ
+        );
+    }
+
+    let init = initializer(token_ptr, typ.clone());
+    if is_flex {
+        let _ = typ.array_size.insert(init.flex_elem_count());
+    }
+    let saves = save_shared_exprs(&init);
+
+    let lvar = new_lvar(name, Rc::clone(&token), typ.clone(), true, current_scope());
     let offset = lvar.borrow().offset.unwrap();
-    match typ.typ {
+    let init_node = match typ.typ {
         Type::Array => {
             let zero_clear = new_unary(Nodekind::ZeroClrNd, lvar, Rc::clone(&token));
             new_binary(
                 Nodekind::CommaNd,
                 zero_clear,
                 make_lvar_init(init, typ, offset, false, Rc::clone(&token)),
-                token,
+                Rc::clone(&token),
             )
         }
-        _ => make_lvar_init(init, typ, offset, true, token),
+        _ => make_lvar_init(init, typ, offset, true, Rc::clone(&token)),
+    };
+    saves.into_iter().rev().fold(init_node, |node, save| {
+        new_binary(Nodekind::CommaNd, save, node, Rc::clone(&token))
+    })
+}
+
+/// GNU 拡張の範囲指定では複数の要素が同じ式のノードを共有するため、式を一度だけ評価して隠れたローカル変数に保存し、各要素ではその値を読むように置き換える
+/// 保存のための代入式を返す
+fn save_shared_exprs(init: &Initializer) -> Vec<NodeRef> {
+    let mut leaves: Vec<InitializerRef> = vec![];
+    collect_leaves(init, &mut leaves);
+    let mut saved: Vec<(NodeRef, NodeRef)> = vec![];
+    let mut saves: Vec<NodeRef> = vec![];
+    for (ix, leaf) in leaves.iter().enumerate() {
+        let expr = leaf.borrow().node.clone().unwrap();
+        if expr.borrow().kind == Nodekind::NumNd {
+            continue;
+        }
+        let tmp = if let Some((_, tmp)) = saved.iter().find(|(e, _)| Rc::ptr_eq(e, &expr)) {
+            Rc::clone(tmp)
+        } else if leaves[ix + 1..]
+            .iter()
+            .any(|other| Rc::ptr_eq(other.borrow().node.as_ref().unwrap(), &expr))
+        {
+            let tmp = hidden_lvar(leaf.borrow().typ.as_ref().unwrap().unqualified());
+            let token = expr.borrow().token.clone().unwrap();
+            saves.push(assign_op(
+                Nodekind::AssignNd,
+                Rc::clone(&tmp),
+                Rc::clone(&expr),
+                token,
+            ));
+            saved.push((expr, Rc::clone(&tmp)));
+            tmp
+        } else {
+            continue;
+        };
+        let _ = leaf.borrow_mut().node.insert(tmp);
+    }
+    saves
+}
+
+/// 初期化子の最下位の要素を重複なく集める
+fn collect_leaves(init: &Initializer, leaves: &mut Vec<InitializerRef>) {
+    for elem in &init.elements {
+        if elem.borrow().is_element() {
+            if !leaves.iter().any(|leaf| Rc::ptr_eq(leaf, elem)) {
+                leaves.push(Rc::clone(elem));
+            }
+        } else {
+            collect_leaves(&elem.borrow(), leaves);
+        }
     }
 }
 
@@ -1244,7 +2586,7 @@ fn initializer(token_ptr: &mut TokenRef, typ: TypeCell) -> Initializer {
 /// char-array-initializer = string-literal
 fn char_array_initializer(body: String, array_size: Option<usize>, token: TokenRef) -> Initializer {
     let mut init = Initializer::default();
-    let elems = body.as_bytes().iter().map(|c| *c as i32);
+    let elems = body.as_bytes().iter().map(|c| *c as i8 as i64);
     let elem_typ = TypeCell::new(Type::Char);
     let size = if let Some(_size) = array_size {
         // 配列は、どんな型であれ初期値の指定がない箇所は0で初期化されるため、固定長の場合は終端'\0'としての (int)0 を生成するノードは不要
@@ -1256,7 +2598,7 @@ fn char_array_initializer(body: String, array_size: Option<usize>, token: TokenR
             ix += 1;
             init.push_element(Initializer::new(
                 elem_typ.clone(),
-                new_num(e, Rc::clone(&token)),
+                new_num(e, TypeCell::new(Type::Int), Rc::clone(&token)),
             ));
         }
         while ix < _size {
@@ -1264,7 +2606,7 @@ fn char_array_initializer(body: String, array_size: Option<usize>, token: TokenR
             // 0 パディング
             init.push_element(Initializer::new(
                 elem_typ.clone(),
-                new_num(0, Rc::clone(&token)),
+                new_num(0, TypeCell::new(Type::Int), Rc::clone(&token)),
             ));
         }
         _size
@@ -1272,17 +2614,20 @@ fn char_array_initializer(body: String, array_size: Option<usize>, token: TokenR
         for e in elems {
             init.push_element(Initializer::new(
                 elem_typ.clone(),
-                new_num(e, Rc::clone(&token)),
+                new_num(e, TypeCell::new(Type::Int), Rc::clone(&token)),
             ));
         }
         init.push_element(Initializer::new(
             elem_typ.clone(),
-            new_num(0, Rc::clone(&token)),
+            new_num(0, TypeCell::new(Type::Int), Rc::clone(&token)),
         ));
         init.elements.len()
     };
     // この関数が呼ばれている時点でネストが深すぎるということはないため、ここで持たせる node はなんでも良い
-    init.insert(elem_typ.make_array_of(size), new_num(0, token));
+    init.insert(
+        elem_typ.make_array_of(size),
+        new_num(0, TypeCell::new(Type::Int), token),
+    );
     init
 }
 
@@ -1325,88 +2670,297 @@ fn char_array_initializer(body: String, array_size: Option<usize>, token: TokenR
 // clang では3行上の例は valid な文法としてコンパイル可能。
 //
 /// 生成規則:
-/// array-initializer = (initializer ("," initializer)* ","? "}"
-///	C99 以降の designator は現段階ではサポートしない
+/// array-initializer = designation? initializer ("," designation? initializer)* ","? "}"
+/// designator は配列の添字指定 (GNU 拡張の範囲指定を含む) のみサポートする
 fn array_initializer(token_ptr: &mut TokenRef, typ: TypeCell) -> Initializer {
-    let mut init = Initializer::default();
+    let token = Rc::clone(token_ptr);
     let elem_typ = if let Ok(_typ) = typ.make_deref() {
         _typ
     } else {
         typ.clone()
     };
+    // 各要素の初期化子を添字の位置に置いていく(designator で飛ばされた位置は None のまま残り、最後に0で埋める)
+    let mut slots: Vec<Option<Initializer>> = vec![];
+    let mut pos = 0;
     loop {
-        if is(token_ptr, "{") || elem_typ.is_non_array() {
-            init.push_element(initializer(token_ptr, elem_typ.clone()));
+        let (begin, end, elem, rest) = if is(token_ptr, "[") || is(token_ptr, ".") {
+            let (begin, end) = designator(token_ptr, &typ);
+            let (elem, rest) = designation(token_ptr, elem_typ.clone());
+            (begin, end, elem, rest)
         } else {
-            // この深さではまだ配列が来るべきであるにも関わらず、初期化文のネストが浅かった場合の処理
-            let (base_typ, elem_flatten_size) = if is_kind(token_ptr, Tokenkind::StringTk)
-                && elem_typ.get_base_cell().typ != Type::Ptr
-            {
-                // 文字列リテラルかつ最小要素の型がポインタでない場合は、ベースの型を1次元配列とみなして読む(型チェックは initializer() で行うためここではスルー)
-                let _typ = elem_typ.get_last_level_array().unwrap();
-                let _flatten_size = elem_typ.flatten_size() / _typ.array_size.unwrap();
-                (_typ, _flatten_size)
-            } else {
-                (elem_typ.get_base_cell(), elem_typ.flatten_size())
-            };
-            for _ in 0..elem_flatten_size {
-                let elem = initializer(token_ptr, base_typ.clone());
-                // base_typ が Array (つまり上記で文字リテラルを読んでいてかつポインタ型配列でない)の場合には、要素数カウントを正しく行うため、elem.elements を init.elements に append する
-                if base_typ.is_array() {
-                    init.append_elements(&elem);
-                } else {
-                    init.push_element(elem);
-                }
-                let _ = consume(token_ptr, ",");
-                if is(token_ptr, "}") {
-                    break;
-                }
-            }
+            let elem = Designated::Whole(element_initializer(token_ptr, elem_typ.clone()));
+            (pos, pos, elem, vec![])
+        };
+        if slots.len() <= end {
+            slots.resize(end + 1, None);
+        }
+        for slot in slots.iter_mut().take(end + 1).skip(begin) {
+            merge_designated(slot, &elem, &elem_typ, &token);
         }
+        // ネストした指定子に続く初期化子は、範囲指定の場合でも最後の要素にのみ適用する
+        if !rest.is_empty() {
+            merge_designated(&mut slots[end], &Designated::Part(rest), &elem_typ, &token);
+        }
+        pos = end + 1;
         let _ = consume(token_ptr, ",");
         if consume(token_ptr, "}") {
             break;
         }
     }
-
-    // 配列の Initializer の node は最初の要素を指すことにする
-    let first_elem = init.elements[0].borrow().clone();
-    init.insert(typ, Rc::clone(first_elem.node.as_ref().unwrap()));
-    init
+    join_slots(slots, typ, token)
 }
 
-/// オフセットで直接代入したい場合の LvarNd
-#[inline]
-fn direct_offset_lvar(offset: usize, typ: TypeCell) -> NodeRef {
-    Rc::new(RefCell::new(Node {
-        kind: Nodekind::LvarNd,
-        typ: Some(typ),
-        offset: Some(offset),
-        is_local: true,
-        ..Default::default()
-    }))
+/// 配列の要素1つ分の初期化子: 要素が配列であるにも関わらず中括弧で始まらない場合は、波括弧を省略したものとして読む
+fn element_initializer(token_ptr: &mut TokenRef, elem_typ: TypeCell) -> Initializer {
+    if is(token_ptr, "{") || elem_typ.is_non_array() {
+        initializer(token_ptr, elem_typ)
+    } else {
+        // この深さではまだ配列が来るべきであるにも関わらず、初期化文のネストが浅かった場合の処理
+        shallow_initializer(token_ptr, elem_typ)
+    }
 }
 
-/// initializer が存在する要素に対応する部分のみノードを作る(この時、flex であっても先に要素数は確定しており typ.array_size を利用して処理できる)
-/// int x[2] = {1, 2}; のようなパターンは int x[2]; x[0] = 1, x[1] = 2; のように展開する
-/// ただし、それぞれの要素アクセスのためにわざわざポインタ計算を生成せず、単に各要素が格納されるべき位置に対応するベースポインタからオフセットを持つローカル変数であるとみなす
-fn make_lvar_init(
-    init: Initializer,
-    typ: TypeCell,
-    offset: usize,
-    is_scalar: bool,
-    token: TokenRef,
-) -> NodeRef {
-    if typ.is_array() {
-        let total_bytes = typ.bytes();
-        let elem_typ = typ.make_deref().unwrap();
-        let elem_bytes = elem_typ.bytes();
-        let elem_flatten_size = elem_typ.flatten_size();
-        let base_typ = typ.get_base_cell();
-        let base_bytes = base_typ.bytes();
-        let mut node_ptr = nop();
+/// designator で指定された要素の初期化子: 要素全体を初期化するか、ネストした指定子で要素の一部を (添字, 初期化子) の順に初期化する
+#[derive(Clone)]
+enum Designated {
+    Whole(Initializer),
+    Part(Vec<(usize, Designated)>),
+}
 
-        let mut ix = 0;
+/// 生成規則:
+/// designation = designator* "="
+/// 最初の designator を読んだ後の、その要素 (型は typ) に対する残りの指定と初期化子を読む
+/// 指定された要素の初期化子と、それに続いて同じ配列の残りの要素を初期化する初期化子を返す
+fn designation(token_ptr: &mut TokenRef, typ: TypeCell) -> (Designated, Vec<(usize, Designated)>) {
+    if consume(token_ptr, "=") {
+        return (
+            Designated::Whole(element_initializer(token_ptr, typ)),
+            vec![],
+        );
+    }
+    if !is(token_ptr, "[") && !is(token_ptr, ".") {
+        expect(token_ptr, "=");
+    }
+    let (begin, end) = designator(token_ptr, &typ);
+    let elem_typ = typ.make_deref().unwrap();
+    let (elem, rest) = designation(token_ptr, elem_typ.clone());
+    let designated = (begin..=end).map(|ix| (ix, elem.clone())).collect();
+    let mut rest = if rest.is_empty() {
+        vec![]
+    } else {
+        vec![(end, Designated::Part(rest))]
+    };
+    // 指定した要素に続く初期化子は、波括弧を省略した場合と同様に同じ配列の残りの要素を順に初期化する
+    for pos in end + 1..typ.array_size.unwrap() {
+        let mut ptr = Rc::clone(token_ptr);
+        let _ = consume(&mut ptr, ",");
+        if is(&mut ptr, "}") || is(&mut ptr, "[") || is(&mut ptr, ".") || at_eof(&ptr) {
+            break;
+        }
+        let _ = consume(token_ptr, ",");
+        rest.push((
+            pos,
+            Designated::Whole(element_initializer(token_ptr, elem_typ.clone())),
+        ));
+    }
+    (Designated::Part(designated), rest)
+}
+
+/// 生成規則:
+/// designator = "[" const-expr ("..." const-expr)? "]"
+/// 指定された添字の範囲を (先頭, 末尾) の形で返す
+fn designator(token_ptr: &mut TokenRef, typ: &TypeCell) -> (usize, usize) {
+    let token = Rc::clone(token_ptr);
+    if consume(token_ptr, ".") {
+        error_with_token!(
+            "構造体は未実装のため、メンバ指定子は使用できません。",
+            &token.borrow()
+        );
+    }
+    if !typ.is_array() {
+        error_with_token!(
+            "配列でない\"{}\"型の初期化には添字指定子を使用できません。",
+            &token.borrow(),
+            typ
+        );
+    }
+    expect(token_ptr, "[");
+    let begin = const_expr(token_ptr);
+    let end = if consume(token_ptr, "...") {
+        const_expr(token_ptr)
+    } else {
+        begin
+    };
+    expect(token_ptr, "]");
+    if begin < 0 || end < begin {
+        error_with_token!(
+            "添字指定子の範囲 [{}, {}] が不正です。",
+            &token.borrow(),
+            begin,
+            end
+        );
+    }
+    if let Some(size) = typ.array_size {
+        if end as usize >= size {
+            error_with_token!(
+                "添字指定子 [{}] が\"{}\"型の範囲外です。",
+                &token.borrow(),
+                end,
+                typ
+            );
+        }
+    }
+    (begin as usize, end as usize)
+}
+
+/// 要素 (型は typ) の初期化子を designator による指定で上書きする: 要素の一部を指定する場合は、指定されていない部分の初期化子を残す
+fn merge_designated(
+    slot: &mut Option<Initializer>,
+    designated: &Designated,
+    typ: &TypeCell,
+    token: &TokenRef,
+) {
+    match designated {
+        Designated::Whole(init) => {
+            let _ = slot.insert(init.clone());
+        }
+        Designated::Part(parts) => {
+            let elem_typ = typ.make_deref().unwrap();
+            let mut slots = split_slots(slot.take(), typ);
+            for (ix, part) in parts {
+                merge_designated(&mut slots[*ix], part, &elem_typ, token);
+            }
+            let _ = slot.insert(join_slots(slots, typ.clone(), Rc::clone(token)));
+        }
+    }
+}
+
+/// 配列の初期化子を要素ごとの初期化子に分ける(join_slots の逆)
+fn split_slots(init: Option<Initializer>, typ: &TypeCell) -> Vec<Option<Initializer>> {
+    let mut slots = vec![None; typ.array_size.unwrap()];
+    let Some(init) = init else {
+        return slots;
+    };
+    let elem_typ = typ.make_deref().unwrap();
+    // 波括弧を省略した初期化子では最下位の要素が平坦に並んでいるため、要素1つ分ずつまとめ直す
+    let is_flat = elem_typ.is_array()
+        && init
+            .elements
+            .first()
+            .is_some_and(|e| e.borrow().is_element());
+    let chunk_size = if is_flat { elem_typ.flatten_size() } else { 1 };
+    for (slot, elems) in slots.iter_mut().zip(init.elements.chunks(chunk_size)) {
+        let _ = slot.insert(if is_flat {
+            let mut elem = Initializer {
+                elements: elems.to_vec(),
+                ..Default::default()
+            };
+            let first = elems[0].borrow().node.clone().unwrap();
+            elem.insert(elem_typ.clone(), first);
+            elem
+        } else {
+            elems[0].borrow().clone()
+        });
+    }
+    slots
+}
+
+/// 要素ごとの初期化子を配列の初期化子にまとめる: 初期化子のない要素は0で初期化する
+fn join_slots(slots: Vec<Option<Initializer>>, typ: TypeCell, token: TokenRef) -> Initializer {
+    let elem_typ = if let Ok(_typ) = typ.make_deref() {
+        _typ
+    } else {
+        typ.clone()
+    };
+    let mut init = Initializer::default();
+    for slot in slots {
+        init.push_element(
+            slot.unwrap_or_else(|| zero_initializer(elem_typ.clone(), Rc::clone(&token))),
+        );
+    }
+
+    // 配列の Initializer の node は最初の要素を指すことにする
+    let first_elem = init.elements[0].borrow().clone();
+    init.insert(typ, Rc::clone(first_elem.node.as_ref().unwrap()));
+    init
+}
+
+/// 配列であるべき要素の初期化子が中括弧で始まらない場合に、1要素分の初期化子を読んでまとめる
+fn shallow_initializer(token_ptr: &mut TokenRef, elem_typ: TypeCell) -> Initializer {
+    let mut init = Initializer::default();
+    let (base_typ, elem_flatten_size) =
+        if is_kind(token_ptr, Tokenkind::StringTk) && elem_typ.get_base_cell().typ != Type::Ptr {
+            // 文字列リテラルかつ最小要素の型がポインタでない場合は、ベースの型を1次元配列とみなして読む(型チェックは initializer() で行うためここではスルー)
+            let _typ = elem_typ.get_last_level_array().unwrap();
+            let _flatten_size = elem_typ.flatten_size() / _typ.array_size.unwrap();
+            (_typ, _flatten_size)
+        } else {
+            (elem_typ.get_base_cell(), elem_typ.flatten_size())
+        };
+    for _ in 0..elem_flatten_size {
+        let elem = initializer(token_ptr, base_typ.clone());
+        // base_typ が Array (つまり上記で文字リテラルを読んでいてかつポインタ型配列でない)の場合には、要素数カウントを正しく行うため、elem.elements を init.elements に append する
+        if base_typ.is_array() {
+            init.append_elements(&elem);
+        } else {
+            init.push_element(elem);
+        }
+        let _ = consume(token_ptr, ",");
+        // designator が来た場合も、その位置は外側の配列の添字を指すためここで打ち切る
+        if is(token_ptr, "}") || is(token_ptr, "[") || is(token_ptr, ".") {
+            break;
+        }
+    }
+    let first_elem = init.elements[0].borrow().clone();
+    init.insert(elem_typ, Rc::clone(first_elem.node.as_ref().unwrap()));
+    init
+}
+
+/// designator により初期化子が与えられなかった要素を0で初期化する Initializer
+fn zero_initializer(typ: TypeCell, token: TokenRef) -> Initializer {
+    let zero = new_num(0, TypeCell::new(Type::Int), token);
+    if typ.is_array() {
+        let mut init = Initializer::default();
+        init.push_element(Initializer::new(typ.get_base_cell(), Rc::clone(&zero)));
+        init.insert(typ, zero);
+        init
+    } else {
+        Initializer::new(typ, zero)
+    }
+}
+
+/// オフセットで直接代入したい場合の LvarNd
+#[inline]
+fn direct_offset_lvar(offset: usize, typ: TypeCell) -> NodeRef {
+    Rc::new(RefCell::new(Node {
+        kind: Nodekind::LvarNd,
+        typ: Some(typ),
+        offset: Some(offset),
+        is_local: true,
+        ..Default::default()
+    }))
+}
+
+/// initializer が存在する要素に対応する部分のみノードを作る(この時、flex であっても先に要素数は確定しており typ.array_size を利用して処理できる)
+/// int x[2] = {1, 2}; のようなパターンは int x[2]; x[0] = 1, x[1] = 2; のように展開する
+/// ただし、それぞれの要素アクセスのためにわざわざポインタ計算を生成せず、単に各要素が格納されるべき位置に対応するベースポインタからオフセットを持つローカル変数であるとみなす
+fn make_lvar_init(
+    init: Initializer,
+    typ: TypeCell,
+    offset: usize,
+    is_scalar: bool,
+    token: TokenRef,
+) -> NodeRef {
+    if typ.is_array() {
+        let total_bytes = typ.bytes();
+        let elem_typ = typ.make_deref().unwrap();
+        let elem_bytes = elem_typ.bytes();
+        let elem_flatten_size = elem_typ.flatten_size();
+        let base_typ = typ.get_base_cell();
+        let base_bytes = base_typ.bytes();
+        let mut node_ptr = nop();
+
+        let mut ix = 0;
         let mut finished_bytes = 0;
         while finished_bytes < total_bytes && ix < init.elements.len() {
             let elem = Rc::clone(&init.elements[ix]);
@@ -1470,12 +3024,20 @@ fn make_lvar_init(
 
 /// 生成規則:
 /// stmt = expr? ";"
-///		| declaration
-///		| "{" stmt* "}"
-///		| "if" "(" expr ")" stmt ("else" stmt)?
-///		| "while" "(" expr ")" stmt
-///		| "for" "(" expr? ";" expr? ";" expr? ")" stmt
-///		| "return" expr? ";"
+///      | declaration
+///      | "{" stmt* "}"
+///      | "if" "(" expr ")" stmt ("else" stmt)?
+///      | "while" "(" expr ")" stmt
+///      | "do" stmt "while" "(" expr ")" ";"
+///      | "for" "(" expr? ";" expr? ";" expr? ")" stmt
+///      | "switch" "(" expr ")" stmt
+///      | "case" const-expr ":" stmt
+///      | "default" ":" stmt
+///      | ident ":" stmt
+///      | "goto" (ident | "*" expr) ";"
+///      | "break" ";"
+///      | "continue" ";"
+///      | "return" expr? ";"
 fn stmt(token_ptr: &mut TokenRef) -> NodeRef {
     let token = Rc::clone(token_ptr);
     if consume(token_ptr, ";") {
@@ -1484,6 +3046,7 @@ fn stmt(token_ptr: &mut TokenRef) -> NodeRef {
         declaration(token_ptr)
     } else if consume(token_ptr, "{") {
         enter_scope();
+        VLA_SCOPES.try_lock().unwrap().push(vec![]);
         let mut children: Vec<NodeRef> = vec![];
         loop {
             if !consume(token_ptr, "}") {
@@ -1499,6 +3062,10 @@ fn stmt(token_ptr: &mut TokenRef) -> NodeRef {
         }
         leave_scope();
 
+        // 可変長配列を宣言したブロックでは、抜ける際に alloca した領域を解放する
+        if let Some(saved) = VLA_SCOPES.try_lock().unwrap().pop().unwrap().first() {
+            children.push(new_stack_restore(*saved));
+        }
         new_block(children)
     } else if consume(token_ptr, "if") {
         expect(token_ptr, "(");
@@ -1516,9 +3083,35 @@ fn stmt(token_ptr: &mut TokenRef) -> NodeRef {
         expect(token_ptr, "(");
         let enter = Some(expr(token_ptr));
         expect(token_ptr, ")");
+        let label = enter_jump_target(true);
         let branch = Some(stmt(token_ptr));
+        leave_jump_target();
+
+        let ctrl = new_ctrl(Nodekind::WhileNd, None, enter, None, branch, None);
+        let _ = ctrl.borrow_mut().label.insert(label);
+        ctrl
+    } else if consume(token_ptr, "do") {
+        let label = enter_jump_target(true);
+        let branch = Some(stmt(token_ptr));
+        leave_jump_target();
+        if !consume(token_ptr, "while") {
+            error_with_token!("do 文の後には \"while\" が必要です。", &token_ptr.borrow());
+        }
+        expect(token_ptr, "(");
+        let enter = Some(expr(token_ptr));
+        // ";" が抜けている場合は、次の行の文ではなく閉じ括弧の位置を指してエラーにする
+        let close = Rc::clone(token_ptr);
+        expect(token_ptr, ")");
+        if !consume(token_ptr, ";") {
+            error_with_token!(
+                "do-while 文の \")\" の後には \";\" が必要です。",
+                &close.borrow()
+            );
+        }
 
-        new_ctrl(Nodekind::WhileNd, None, enter, None, branch, None)
+        let ctrl = new_ctrl(Nodekind::DoWhileNd, None, enter, None, branch, None);
+        let _ = ctrl.borrow_mut().label.insert(label);
+        ctrl
     } else if consume(token_ptr, "for") {
         expect(token_ptr, "(");
         enter_scope();
@@ -1549,15 +3142,91 @@ fn stmt(token_ptr: &mut TokenRef) -> NodeRef {
             _routine
         };
 
+        let label = enter_jump_target(true);
         let branch: Option<NodeRef> = Some(stmt(token_ptr));
+        leave_jump_target();
         leave_scope();
 
-        new_ctrl(Nodekind::ForNd, init, enter, routine, branch, None)
+        let ctrl = new_ctrl(Nodekind::ForNd, init, enter, routine, branch, None);
+        let _ = ctrl.borrow_mut().label.insert(label);
+        ctrl
+    } else if consume(token_ptr, "switch") {
+        switch_stmt(token_ptr, token)
+    } else if consume(token_ptr, "case") {
+        case_stmt(token_ptr, false, token)
+    } else if consume(token_ptr, "default") {
+        case_stmt(token_ptr, true, token)
+    } else if is_label(token_ptr) {
+        label_stmt(token_ptr, token)
+    } else if consume(token_ptr, "goto") {
+        let node = if consume(token_ptr, "*") {
+            // GNU 拡張の計算型 goto: 式の値のアドレスに飛ぶ
+            let dest = expr(token_ptr);
+            confirm_type(&dest);
+            if !dest.borrow().typ.as_ref().unwrap().is_pointer() {
+                error_with_token!(
+                    "goto * の飛び先はポインタである必要があります。",
+                    &token.borrow()
+                );
+            }
+            Rc::new(RefCell::new(Node {
+                kind: Nodekind::GotoNd,
+                token: Some(token),
+                left: Some(dest),
+                ..Default::default()
+            }))
+        } else {
+            let name = expect_ident(token_ptr);
+            label_ref(Nodekind::GotoNd, name, token)
+        };
+        expect(token_ptr, ";");
+        node
+    } else if consume(token_ptr, "break") {
+        expect(token_ptr, ";");
+        let target = JUMP_TARGETS.try_lock().unwrap().last().copied();
+        if target.is_none() {
+            error_with_token!(
+                "break は繰り返し文か switch 文の中でのみ使用できます。",
+                &token.borrow()
+            );
+        }
+        new_jump(Nodekind::BreakNd, target.unwrap(), token)
+    } else if consume(token_ptr, "continue") {
+        expect(token_ptr, ";");
+        let target = JUMP_TARGETS
+            .try_lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .find(|target| target.1)
+            .copied();
+        if target.is_none() {
+            error_with_token!(
+                "continue は繰り返し文の中でのみ使用できます。",
+                &token.borrow()
+            );
+        }
+        new_jump(Nodekind::ContinueNd, target.unwrap(), token)
     } else if consume_kind(token_ptr, Tokenkind::ReturnTk) {
-        // exprなしのパターン: 実質NumNd 0があるのと同じと捉えれば良い
+        let is_void = CUR_RET_TYP
+            .try_lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|typ| typ.is_void());
+
+        // exprなしのパターン: void でない関数では実質NumNd 0があるのと同じと捉えれば良い
         let left: NodeRef = if consume(token_ptr, ";") {
+            if is_void {
+                return new_void_return(Some(token));
+            }
             tmp_num!(0)
         } else {
+            if is_void {
+                error_with_token!(
+                    "void 型の関数では値を返すことができません。",
+                    &token.borrow()
+                );
+            }
             let _left: NodeRef = expr(token_ptr);
             expect(token_ptr, ";");
             _left
@@ -1571,95 +3240,579 @@ fn stmt(token_ptr: &mut TokenRef) -> NodeRef {
     }
 }
 
-/// 生成規則:
-/// expr = assign ("," expr)?
-pub fn expr(token_ptr: &mut TokenRef) -> NodeRef {
-    let node_ptr: NodeRef = assign(token_ptr);
-    let token = Rc::clone(token_ptr);
-    if consume(token_ptr, ",") {
-        new_binary(Nodekind::CommaNd, node_ptr, expr(token_ptr), token)
-    } else {
-        node_ptr
-    }
+/// ident ":" の形であればラベル付きの文である
+fn is_label(token_ptr: &TokenRef) -> bool {
+    let mut ptr = Rc::clone(token_ptr);
+    consume_ident(&mut ptr).is_some() && is(&mut ptr, ":")
 }
 
-/// 生成規則:
-/// assign = logor (assign-op assign)?
-/// assign-op = "="
-///			| "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "^=" | "|="
-///			| "<<=" | ">>="
-/// 禁止代入(例えば x + y = 10; や x & y = 10; など)は generator 側で弾く
-fn assign(token_ptr: &mut TokenRef) -> NodeRef {
-    let node_ptr: NodeRef = logor(token_ptr);
-    let token = Rc::clone(token_ptr);
-    if consume(token_ptr, "=") {
-        assign_op(Nodekind::AssignNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "+=") {
-        assign_op(Nodekind::AddNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "-=") {
-        assign_op(Nodekind::SubNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "*=") {
-        assign_op(Nodekind::MulNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "/=") {
-        assign_op(Nodekind::DivNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "%=") {
-        assign_op(Nodekind::ModNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "&=") {
-        assign_op(Nodekind::BitAndNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "^=") {
-        assign_op(Nodekind::BitXorNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "|=") {
-        assign_op(Nodekind::BitOrNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, "<<=") {
-        assign_op(Nodekind::LShiftNd, node_ptr, assign(token_ptr), token)
-    } else if consume(token_ptr, ">>=") {
-        assign_op(Nodekind::RShiftNd, node_ptr, assign(token_ptr), token)
-    } else {
-        node_ptr
-    }
+/// ラベルに割り当てた番号を返す(goto などでラベルの定義より先に参照された場合もここで番号を割り当てる)
+fn label_number(name: &str) -> usize {
+    *LABELS
+        .try_lock()
+        .unwrap()
+        .entry(name.to_string())
+        .or_insert_with(|| {
+            let mut count = JUMP_TARGET_COUNT.try_lock().unwrap();
+            *count += 1;
+            *count - 1
+        })
 }
 
-/// a += b; -->  tmp = &a, *tmp = *tmp + b; と読み替える
-fn assign_op(kind: Nodekind, left: NodeRef, right: NodeRef, token: TokenRef) -> NodeRef {
-    // 左右の型を確定させておく
-    confirm_type(&left);
-    confirm_type(&right);
-
-    // この式全体の評価値は left (a += b の a) の型とする
-    let assign_ = if kind == Nodekind::AssignNd {
-        new_binary(Nodekind::AssignNd, left, right, token)
-    } else {
-        // tmp として通常は認められない無名の変数を使うことで重複を避ける
-        let typ = left.borrow().typ.clone().unwrap();
-        let tmp_lvar = tmp_lvar!();
-        let _ = tmp_lvar.borrow_mut().typ.insert(typ.make_ptr_to());
-        let tmp_deref = tmp_unary!(Nodekind::DerefNd, tmp_lvar);
-
-        let expr_left = tmp_binary!(
-            Nodekind::AssignNd,
-            tmp_lvar,
-            tmp_unary!(Nodekind::AddrNd, left)
+/// ラベル付きの文: ラベルは関数全体で1つの名前空間を持つ
+fn label_stmt(token_ptr: &mut TokenRef, token: TokenRef) -> NodeRef {
+    let name = expect_ident(token_ptr);
+    expect(token_ptr, ":");
+    let prev = LABEL_DEFS.try_lock().unwrap().get(&name).map(|(prev, _)| {
+        let prev = prev.token.as_ref().unwrap().borrow();
+        (prev.line_num, prev.line_offset)
+    });
+    if let Some((line_num, line_offset)) = prev {
+        error_with_token!(
+            "ラベル\"{}\"は位置[{}, {}]で既に定義されています。",
+            &*token.borrow(),
+            name,
+            line_num,
+            line_offset
         );
+    }
+    let label = Rc::new(RefCell::new(Node {
+        kind: Nodekind::LabelNd,
+        token: Some(token),
+        name: Some(name.clone()),
+        label: Some(label_number(&name)),
+        ..Default::default()
+    }));
+    let _ = LABEL_DEFS
+        .try_lock()
+        .unwrap()
+        .insert(name, (label.borrow().clone(), vla_chain()));
 
-        let token2 = Rc::clone(&token);
-        let tmp_deref2 = Rc::clone(&tmp_deref);
-        let op = match kind {
-            Nodekind::AddNd => new_add(tmp_deref2, right, token2),
-            Nodekind::SubNd => new_sub(tmp_deref2, right, token2),
-            _ => new_binary(kind, tmp_deref2, right, token2),
-        };
+    let branch = stmt(token_ptr);
+    let _ = label.borrow_mut().branch.insert(branch);
+    label
+}
 
-        let expr_right = tmp_binary!(Nodekind::AssignNd, tmp_deref, op);
+/// goto label や &&label のようにラベルを参照するノード: 関数の終わりで定義されているかをチェックするために記録しておく
+fn label_ref(kind: Nodekind, name: String, token: TokenRef) -> NodeRef {
+    let node = Rc::new(RefCell::new(Node {
+        kind,
+        token: Some(token),
+        name: Some(name.clone()),
+        // goto では、飛ぶ前にスタックを復元する必要があるかは飛び先のラベルが定義されるまで分からないので、後で置き換える
+        init: if kind == Nodekind::GotoNd {
+            Some(nop())
+        } else {
+            None
+        },
+        label: Some(label_number(&name)),
+        ..Default::default()
+    }));
+    LABEL_REFS
+        .try_lock()
+        .unwrap()
+        .push((node.borrow().clone(), vla_chain()));
+    node
+}
 
-        confirm_type(&expr_left);
-        confirm_type(&expr_right);
-        new_binary(Nodekind::CommaNd, expr_left, expr_right, token)
-    };
-    confirm_type(&assign_);
-    assign_
+/// 関数の終わりで、参照されたラベルが全て定義されているかをチェックする
+/// goto が可変長配列のスコープから抜ける場合は、飛ぶ前にスタックを復元させる
+fn check_labels() {
+    let refs: Vec<LabelSite> = LABEL_REFS.try_lock().unwrap().drain(..).collect();
+    let defs: HashMap<String, LabelSite> = LABEL_DEFS.try_lock().unwrap().drain().collect();
+    LABELS.try_lock().unwrap().clear();
+    for (node, chain) in refs {
+        let name = node.name.as_ref().unwrap();
+        let Some((_, dest)) = defs.get(name) else {
+            error_with_node!("ラベル\"{}\"は定義されていません。", &node, name);
+        };
+        let Some(init) = &node.init else {
+            continue;
+        };
+        if !chain.starts_with(dest) {
+            error_with_node!(
+                "goto で可変長配列のスコープの中へ飛び込むことはできません。",
+                &node
+            );
+        }
+        if let Some(restore) = leave_vla_scopes(&chain, dest) {
+            *init.borrow_mut() = restore.borrow().clone();
+        }
+    }
 }
 
-/// 生成規則:
+/// 文の末尾に制御が到達しうるかを解析する: reachable は文の先頭に到達しうるかを表し、到達しうる break, continue を jumps に記録する
+fn flows(node: &NodeRef, reachable: bool, jumps: &mut Vec<(Nodekind, usize)>) -> bool {
+    let node = node.borrow();
+    match node.kind {
+        Nodekind::ReturnNd | Nodekind::GotoNd => false,
+        Nodekind::BreakNd | Nodekind::ContinueNd => {
+            if reachable {
+                jumps.push((node.kind, node.label.unwrap()));
+            }
+            false
+        }
+        // ラベルには goto や switch から飛んでくるので、直前の文に関わらず到達しうる
+        Nodekind::LabelNd | Nodekind::CaseNd => flows(node.branch.as_ref().unwrap(), true, jumps),
+        Nodekind::BlockNd => {
+            let mut reachable = reachable;
+            let mut after_jump = false;
+            for child in &node.children {
+                let kind = child.borrow().kind;
+                if after_jump && !has_label(child) {
+                    if let Some(token) = first_token(child) {
+                        warning_with_token!(
+                            "return 文や goto 文より後のこのコードには到達しません。",
+                            &token.borrow()
+                        );
+                    }
+                    after_jump = false;
+                }
+                // 宣言のみの文は実行されるコードを持たないので、警告の対象にしない
+                if kind != Nodekind::NopNd {
+                    after_jump =
+                        reachable && [Nodekind::ReturnNd, Nodekind::GotoNd].contains(&kind);
+                }
+                reachable = flows(child, reachable, jumps);
+            }
+            reachable
+        }
+        Nodekind::IfNd => {
+            let branch = flows(node.branch.as_ref().unwrap(), reachable, jumps);
+            let els = node
+                .els
+                .as_ref()
+                .map_or(reachable, |els| flows(els, reachable, jumps));
+            branch || els
+        }
+        Nodekind::WhileNd | Nodekind::ForNd | Nodekind::DoWhileNd => {
+            let body = flows(node.branch.as_ref().unwrap(), reachable, jumps);
+            let (broken, continued) = take_jumps(jumps, node.label.unwrap());
+            // 条件式が省略されているか、0 でない整数の定数であれば無限ループとなる
+            let infinite = node.enter.as_ref().is_none_or(|enter| {
+                let enter = enter.borrow();
+                enter.kind == Nodekind::NumNd && enter.val != Some(0)
+            });
+            let tested = if node.kind == Nodekind::DoWhileNd {
+                body || continued
+            } else {
+                reachable || has_label(node.branch.as_ref().unwrap())
+            };
+            (tested && !infinite) || broken
+        }
+        Nodekind::SwitchNd => {
+            let body = flows(node.branch.as_ref().unwrap(), false, jumps);
+            let (broken, _) = take_jumps(jumps, node.label.unwrap());
+            let has_default = node.children.iter().any(|case| case.borrow().val.is_none());
+            (reachable && !has_default) || body || broken
+        }
+        _ => reachable,
+    }
+}
+
+/// label を飛び先とする break, continue を jumps から取り除き、それぞれが存在したかを返す
+fn take_jumps(jumps: &mut Vec<(Nodekind, usize)>, label: usize) -> (bool, bool) {
+    let broken = jumps.contains(&(Nodekind::BreakNd, label));
+    let continued = jumps.contains(&(Nodekind::ContinueNd, label));
+    jumps.retain(|&(_, target)| target != label);
+    (broken, continued)
+}
+
+/// 文の中にラベル(case, default を含む)があるか
+fn has_label(node: &NodeRef) -> bool {
+    let node = node.borrow();
+    [Nodekind::LabelNd, Nodekind::CaseNd].contains(&node.kind)
+        || [&node.init, &node.branch, &node.els]
+            .iter()
+            .any(|child| child.as_ref().is_some_and(has_label))
+        || node.children.iter().any(has_label)
+}
+
+/// 警告の位置を示すために、文の中でソース上の最も前にあるトークンを返す
+fn first_token(node: &NodeRef) -> Option<TokenRef> {
+    let node = node.borrow();
+    [
+        &node.init,
+        &node.left,
+        &node.enter,
+        &node.branch,
+        &node.right,
+    ]
+    .iter()
+    .filter_map(|child| child.as_ref().and_then(first_token))
+    .chain(node.children.iter().filter_map(first_token))
+    .chain(node.token.clone())
+    .min_by_key(|token| {
+        let token = token.borrow();
+        (token.line_num, token.line_offset)
+    })
+}
+
+/// switch 文: 条件式は整数拡張した型で評価し、本体の中の case, default を children として持たせる
+fn switch_stmt(token_ptr: &mut TokenRef, token: TokenRef) -> NodeRef {
+    expect(token_ptr, "(");
+    let enter = expr(token_ptr);
+    expect(token_ptr, ")");
+    confirm_type(&enter);
+    let typ = enter.borrow().typ.clone().unwrap();
+    if !typ.is_integer() {
+        error_with_token!(
+            "switch 文の条件式は整数型である必要があります。(\"{}\"型が与えられました)",
+            &token.borrow(),
+            typ
+        );
+    }
+    let typ = typ.int_promoted().unqualified();
+    let enter = new_cast(&enter, typ.clone());
+
+    let label = enter_jump_target(false);
+    SWITCH_CASES
+        .try_lock()
+        .unwrap()
+        .push((typ, vec![], vla_chain()));
+    let branch = stmt(token_ptr);
+    let (_, cases, _) = SWITCH_CASES.try_lock().unwrap().pop().unwrap();
+    leave_jump_target();
+
+    Rc::new(RefCell::new(Node {
+        kind: Nodekind::SwitchNd,
+        token: Some(token),
+        enter: Some(enter),
+        branch: Some(branch),
+        children: cases
+            .into_iter()
+            .map(|case| Rc::new(RefCell::new(case)))
+            .collect(),
+        label: Some(label),
+        ..Default::default()
+    }))
+}
+
+/// case, default のラベル: case の値は switch 文の条件式の型に変換してから重複をチェックする
+fn case_stmt(token_ptr: &mut TokenRef, is_default: bool, token: TokenRef) -> NodeRef {
+    let keyword = if is_default { "default" } else { "case" };
+    let switch_typ = SWITCH_CASES
+        .try_lock()
+        .unwrap()
+        .last()
+        .map(|(typ, _, chain)| (typ.clone(), chain.clone()));
+    let Some((switch_typ, switch_chain)) = switch_typ else {
+        error_with_token!(
+            "{} は switch 文の中でのみ使用できます。",
+            &*token.borrow(),
+            keyword
+        );
+    };
+    // switch 文の外側にない可変長配列のスコープの中へは飛び込めない
+    if vla_chain() != switch_chain {
+        error_with_token!(
+            "switch 文から可変長配列のスコープの中の {} へ飛び込むことはできません。",
+            &*token.borrow(),
+            keyword
+        );
+    }
+    let val = if is_default {
+        None
+    } else {
+        let node = expr(token_ptr);
+        confirm_type(&node);
+        if !node.borrow().typ.as_ref().unwrap().is_integer() {
+            error_with_token!(
+                "case の値は整数の定数式である必要があります。",
+                &token.borrow()
+            );
+        }
+        let val = eval_const_expr(&node);
+        Some(match (switch_typ.bytes(), switch_typ.is_unsigned) {
+            (4, false) => val as i32 as i64,
+            (4, true) => val as u32 as i64,
+            _ => val,
+        })
+    };
+    expect(token_ptr, ":");
+
+    let label = {
+        let mut count = JUMP_TARGET_COUNT.try_lock().unwrap();
+        *count += 1;
+        *count - 1
+    };
+    let case = Rc::new(RefCell::new(Node {
+        kind: Nodekind::CaseNd,
+        token: Some(Rc::clone(&token)),
+        val,
+        label: Some(label),
+        ..Default::default()
+    }));
+    let prev = SWITCH_CASES
+        .try_lock()
+        .unwrap()
+        .last()
+        .unwrap()
+        .1
+        .iter()
+        .find(|prev| prev.val == val)
+        .map(|prev| {
+            let prev = prev.token.as_ref().unwrap().borrow();
+            (prev.line_num, prev.line_offset)
+        });
+    if let Some((line_num, line_offset)) = prev {
+        if let Some(val) = val {
+            error_with_token!(
+                "case の値 {} は位置[{}, {}]で既に使用されています。",
+                &*token.borrow(),
+                val,
+                line_num,
+                line_offset
+            );
+        } else {
+            error_with_token!(
+                "default は位置[{}, {}]で既に使用されています。",
+                &*token.borrow(),
+                line_num,
+                line_offset
+            );
+        }
+    }
+    SWITCH_CASES
+        .try_lock()
+        .unwrap()
+        .last_mut()
+        .unwrap()
+        .1
+        .push(case.borrow().clone());
+
+    let branch = stmt(token_ptr);
+    let _ = case.borrow_mut().branch.insert(branch);
+    case
+}
+
+/// 生成規則:
+/// expr = assign ("," expr)?
+pub fn expr(token_ptr: &mut TokenRef) -> NodeRef {
+    let node_ptr: NodeRef = assign(token_ptr);
+    let token = Rc::clone(token_ptr);
+    if consume(token_ptr, ",") {
+        new_binary(Nodekind::CommaNd, node_ptr, expr(token_ptr), token)
+    } else {
+        node_ptr
+    }
+}
+
+/// 生成規則:
+/// assign = conditional (assign-op assign)?
+/// assign-op = "="
+///			| "+=" | "-=" | "*=" | "/=" | "%=" | "&=" | "^=" | "|="
+///			| "<<=" | ">>="
+/// 禁止代入(例えば x + y = 10; や x & y = 10; など)は generator 側で弾く
+fn assign(token_ptr: &mut TokenRef) -> NodeRef {
+    let node_ptr: NodeRef = conditional(token_ptr);
+    let token = Rc::clone(token_ptr);
+    if [
+        "=", "+=", "-=", "*=", "/=", "%=", "&=", "^=", "|=", "<<=", ">>=",
+    ]
+    .iter()
+    .any(|op| is(token_ptr, op))
+    {
+        check_const(&node_ptr);
+    }
+    if consume(token_ptr, "=") {
+        assign_op(Nodekind::AssignNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "+=") {
+        assign_op(Nodekind::AddNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "-=") {
+        assign_op(Nodekind::SubNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "*=") {
+        assign_op(Nodekind::MulNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "/=") {
+        assign_op(Nodekind::DivNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "%=") {
+        assign_op(Nodekind::ModNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "&=") {
+        assign_op(Nodekind::BitAndNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "^=") {
+        assign_op(Nodekind::BitXorNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "|=") {
+        assign_op(Nodekind::BitOrNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, "<<=") {
+        assign_op(Nodekind::LShiftNd, node_ptr, assign(token_ptr), token)
+    } else if consume(token_ptr, ">>=") {
+        assign_op(Nodekind::RShiftNd, node_ptr, assign(token_ptr), token)
+    } else {
+        node_ptr
+    }
+}
+
+/// const で修飾されたオブジェクトには、初期化以外で代入できない
+fn check_const(node: &NodeRef) {
+    confirm_type(node);
+    let typ = node.borrow().typ.clone().unwrap();
+    if typ.is_const {
+        error_with_node!(
+            "const で修飾された\"{}\"型の値は変更できません。",
+            &node.borrow(),
+            typ
+        );
+    }
+}
+
+/// a += b; -->  tmp = &a, *tmp = *tmp + b; と読み替える
+fn assign_op(kind: Nodekind, left: NodeRef, right: NodeRef, token: TokenRef) -> NodeRef {
+    // 左右の型を確定させておく
+    confirm_type(&left);
+    confirm_type(&right);
+
+    // この式全体の評価値は left (a += b の a) の型とする
+    let assign_ = if kind == Nodekind::AssignNd {
+        new_binary(Nodekind::AssignNd, left, right, token)
+    } else {
+        // tmp として通常は認められない無名の変数を使うことで重複を避ける
+        let typ = left.borrow().typ.clone().unwrap();
+        let tmp_lvar = tmp_lvar!();
+        let _ = tmp_lvar.borrow_mut().typ.insert(typ.make_ptr_to());
+        let tmp_deref = tmp_unary!(Nodekind::DerefNd, tmp_lvar);
+
+        let expr_left = tmp_binary!(
+            Nodekind::AssignNd,
+            tmp_lvar,
+            tmp_unary!(Nodekind::AddrNd, left)
+        );
+
+        let token2 = Rc::clone(&token);
+        let tmp_deref2 = Rc::clone(&tmp_deref);
+        let op = match kind {
+            Nodekind::AddNd => new_add(tmp_deref2, right, token2),
+            Nodekind::SubNd => new_sub(tmp_deref2, right, token2),
+            _ => new_binary(kind, tmp_deref2, right, token2),
+        };
+
+        let expr_right = tmp_binary!(Nodekind::AssignNd, tmp_deref, op);
+
+        confirm_type(&expr_left);
+        confirm_type(&expr_right);
+        new_binary(Nodekind::CommaNd, expr_left, expr_right, token)
+    };
+    confirm_type(&assign_);
+    assign_
+}
+
+/// 生成規則:
+/// conditional = logor ("?" expr? ":" conditional)?
+/// GNU 拡張の a ?: b は a を1度だけ評価するよう、 (tmp = a) ? tmp : b と読み替える
+fn conditional(token_ptr: &mut TokenRef) -> NodeRef {
+    let node_ptr: NodeRef = logor(token_ptr);
+    let token = Rc::clone(token_ptr);
+    if !consume(token_ptr, "?") {
+        return node_ptr;
+    }
+    if consume(token_ptr, ":") {
+        let els = conditional(token_ptr);
+        confirm_type(&node_ptr);
+        check_void(&node_ptr);
+        let typ = decayed(node_ptr.borrow().typ.as_ref().unwrap()).unqualified();
+        let tmp = hidden_lvar(typ);
+        let enter = tmp_binary!(Nodekind::AssignNd, Rc::clone(&tmp), Rc::clone(&node_ptr));
+        confirm_type(&enter);
+        let cond = new_cond(enter, tmp, els, token);
+        // 定数式として評価する場合のために、元の a を init に持たせておく
+        let _ = cond.borrow_mut().init.insert(node_ptr);
+        cond
+    } else {
+        let branch = expr(token_ptr);
+        expect(token_ptr, ":");
+        let els = conditional(token_ptr);
+        new_cond(node_ptr, branch, els, token)
+    }
+}
+
+#[inline]
+fn new_cond(enter: NodeRef, branch: NodeRef, els: NodeRef, token: TokenRef) -> NodeRef {
+    let node = Rc::new(RefCell::new(Node {
+        kind: Nodekind::CondNd,
+        token: Some(token),
+        enter: Some(enter),
+        branch: Some(branch),
+        els: Some(els),
+        ..Default::default()
+    }));
+    confirm_type(&node);
+    node
+}
+
+/// 配列や関数は、値として使われるとポインタに変換される
+fn decayed(typ: &TypeCell) -> TypeCell {
+    if typ.is_array() {
+        typ.make_deref().unwrap().make_ptr_to()
+    } else if typ.typ == Type::Func {
+        typ.make_ptr_to()
+    } else {
+        typ.clone()
+    }
+}
+
+/// 整数の定数 0 (もしくはそれを void * にキャストしたもの)は空ポインタ定数である
+fn is_null_ptr_const(node: &NodeRef) -> bool {
+    let node = node.borrow();
+    let typ = node.typ.as_ref().unwrap();
+    match node.kind {
+        Nodekind::NumNd => typ.is_integer() && node.val == Some(0),
+        Nodekind::CastNd if typ.is_pointer() && typ.make_deref().unwrap().is_void() => {
+            is_null_ptr_const(node.left.as_ref().unwrap())
+        }
+        _ => false,
+    }
+}
+
+/// 条件演算子の結果の型: 算術型同士なら通常の算術型変換、ポインタ同士なら修飾子を合わせたポインタ型となる
+fn cond_type(node: &Node) -> TypeCell {
+    let branch = node.branch.as_ref().unwrap();
+    let els = node.els.as_ref().unwrap();
+    let branch_typ = decayed(branch.borrow().typ.as_ref().unwrap());
+    let els_typ = decayed(els.borrow().typ.as_ref().unwrap());
+    if branch_typ.is_void() || els_typ.is_void() {
+        return TypeCell::new(Type::Void);
+    }
+    match (branch_typ.is_pointer(), els_typ.is_pointer()) {
+        (false, false) => get_common_type(&branch_typ, &els_typ),
+        (true, true) if is_null_ptr_const(els) => branch_typ.unqualified(),
+        (true, true) if is_null_ptr_const(branch) => els_typ.unqualified(),
+        (true, true) => {
+            let branch_deref = branch_typ.make_deref().unwrap();
+            let els_deref = els_typ.make_deref().unwrap();
+            let deref = if branch_deref.unqualified() == els_deref.unqualified() {
+                branch_deref.clone()
+            } else if branch_deref.is_void() || els_deref.is_void() {
+                TypeCell::new(Type::Void)
+            } else {
+                error_with_node!(
+                    "条件演算子の2つの値の型\"{}\"と\"{}\"には互換性がありません。",
+                    node,
+                    branch_typ,
+                    els_typ
+                );
+            };
+            // 指す先の修飾子は両方のものを合わせる
+            TypeCell {
+                is_const: branch_deref.is_const || els_deref.is_const,
+                is_volatile: branch_deref.is_volatile || els_deref.is_volatile,
+                is_restrict: branch_deref.is_restrict || els_deref.is_restrict,
+                ..deref
+            }
+            .make_ptr_to()
+        }
+        (true, false) if is_null_ptr_const(els) => branch_typ.unqualified(),
+        (false, true) if is_null_ptr_const(branch) => els_typ.unqualified(),
+        _ => {
+            error_with_node!(
+                "条件演算子の2つの値の型\"{}\"と\"{}\"には互換性がありません。",
+                node,
+                branch_typ,
+                els_typ
+            );
+        }
+    }
+}
+
+/// 生成規則:
 /// logor = logand ("||" logand)*
 fn logor(token_ptr: &mut TokenRef) -> NodeRef {
     let mut node_ptr: NodeRef = logand(token_ptr);
@@ -1788,6 +3941,14 @@ fn new_add(mut left: NodeRef, mut right: NodeRef, token: TokenRef) -> NodeRef {
     // それぞれ配列の場合でも true になるが、それで良い
     let left_is_ptr = left.borrow().typ.as_ref().unwrap().ptr_end.is_some();
     let right_is_ptr = right.borrow().typ.as_ref().unwrap().ptr_end.is_some();
+    let has_floating = left.borrow().typ.as_ref().unwrap().is_floating()
+        || right.borrow().typ.as_ref().unwrap().is_floating();
+    if (left_is_ptr || right_is_ptr) && has_floating {
+        error_with_token!(
+            "ポインタと浮動小数点数の演算はできません。",
+            &token.borrow()
+        );
+    }
     if left_is_ptr && right_is_ptr {
         error_with_token!(
             "ポインタ演算は整数型との加算か、ポインタ同士の引き算のみ可能です。",
@@ -1807,8 +3968,14 @@ fn new_add(mut left: NodeRef, mut right: NodeRef, token: TokenRef) -> NodeRef {
 
         // 配列の場合、サイズを考慮する必要があることに注意
         let ptr_cell = left.borrow().typ.clone().unwrap();
-        let bytes = ptr_cell.ptr_to.as_ref().unwrap().borrow().bytes() as i32;
-        let pointer_offset = tmp_binary!(Nodekind::MulNd, tmp_num!(bytes), right);
+        if ptr_cell.ptr_to.as_ref().unwrap().borrow().is_void() {
+            error_with_token!(
+                "void へのポインタに対する演算はできません。",
+                &token.borrow()
+            );
+        }
+        let size = size_node(&ptr_cell.ptr_to.as_ref().unwrap().borrow());
+        let pointer_offset = tmp_binary!(Nodekind::MulNd, size, right);
         let add_ = new_binary(Nodekind::AddNd, left, pointer_offset, token);
         confirm_type(&add_);
         let _ = add_.borrow_mut().typ.insert(ptr_cell);
@@ -1826,6 +3993,19 @@ fn new_sub(left: NodeRef, right: NodeRef, token: TokenRef) -> NodeRef {
     let left_is_ptr = left_typ.ptr_end.is_some();
     let right_is_ptr = right_typ.ptr_end.is_some();
 
+    if (left_is_ptr || right_is_ptr) && (left_typ.is_floating() || right_typ.is_floating()) {
+        error_with_token!(
+            "ポインタと浮動小数点数の演算はできません。",
+            &token.borrow()
+        );
+    }
+    if left_is_ptr && left_typ.ptr_to.as_ref().unwrap().borrow().is_void() {
+        error_with_token!(
+            "void へのポインタに対する演算はできません。",
+            &token.borrow()
+        );
+    }
+
     let (sub_, type_cell) = if !left_is_ptr && !right_is_ptr {
         return new_binary(Nodekind::SubNd, left, right, token);
     } else if left_is_ptr && right_is_ptr {
@@ -1839,12 +4019,12 @@ fn new_sub(left: NodeRef, right: NodeRef, token: TokenRef) -> NodeRef {
             );
         }
 
-        let bytes = left_typ.ptr_to.as_ref().unwrap().borrow().bytes() as i32;
+        let size = size_node(&left_typ.ptr_to.as_ref().unwrap().borrow());
         let pointer_offset = tmp_binary!(Nodekind::SubNd, left, right);
         confirm_type(&pointer_offset);
         (
-            new_binary(Nodekind::DivNd, pointer_offset, tmp_num!(bytes), token),
-            TypeCell::new(Type::Int),
+            new_binary(Nodekind::DivNd, pointer_offset, size, token),
+            TypeCell::new(Type::Long),
         )
     } else {
         // num - ptr は invalid
@@ -1855,8 +4035,8 @@ fn new_sub(left: NodeRef, right: NodeRef, token: TokenRef) -> NodeRef {
             );
         }
 
-        let bytes = left_typ.ptr_to.as_ref().unwrap().borrow().bytes() as i32;
-        let pointer_offset = tmp_binary!(Nodekind::MulNd, tmp_num!(bytes), right);
+        let size = size_node(&left_typ.ptr_to.as_ref().unwrap().borrow());
+        let pointer_offset = tmp_binary!(Nodekind::MulNd, size, right);
         confirm_type(&pointer_offset);
         (
             new_binary(Nodekind::SubNd, left, pointer_offset, token),
@@ -1905,11 +4085,13 @@ fn mul(token_ptr: &mut TokenRef) -> NodeRef {
 
 /// !+x; や ~-y; は valid
 /// unary = tailed
-///		| ("sizeof") ( "(" (type | expr) ")" | unary)
-///		| ("~" | "!") unary
-///		| ("*" | "&") unary
-///		| ("+" | "-") unary
-///		| ("++" | "--") unary
+///       | ("sizeof") ( "(" (type | expr) ")" | unary)
+///       | "_Alignof" "(" type-name ")"
+///       | ("~" | "!") unary
+///       | ("*" | "&") unary
+///       | ("+" | "-") unary
+///       | ("++" | "--") unary
+///       | "(" type-name ")" unary
 // TODO: *+x; *-y; みたいな構文を禁止したい
 fn unary(token_ptr: &mut TokenRef) -> NodeRef {
     let token = Rc::clone(token_ptr);
@@ -1919,9 +4101,26 @@ fn unary(token_ptr: &mut TokenRef) -> NodeRef {
         if let Some(typ) = consume_type(token_ptr) {
             error_with_token!("型名を使用した sizeof 演算子の使用では、 \"(\" と \")\" で囲う必要があります。 -> \"({})\"", &ptr_.borrow(), typ);
         }
+        let mark = vla_mark();
         let typ: TypeCell = if consume(token_ptr, "(") {
-            let typ_: TypeCell = if let Some(t) = consume_type(token_ptr) {
-                t
+            let typ_: TypeCell = if is_type(token_ptr) {
+                let typ = type_name(token_ptr);
+                let mut _token_ptr = Rc::clone(token_ptr);
+                if consume(&mut _token_ptr, ")") && is(&mut _token_ptr, "{") {
+                    *token_ptr = _token_ptr;
+                    // sizeof (type-name){...} は複合リテラルのサイズ
+                    let literal = compound_literal(token_ptr, typ, None, Rc::clone(&token));
+                    let literal = postfix(token_ptr, literal, Rc::clone(&token));
+                    confirm_type(&literal);
+                    let _ = take_vla_dims(mark);
+                    let _typ = literal.borrow().typ.clone().unwrap();
+                    return new_num(
+                        _typ.bytes() as i64,
+                        TypeCell::new(Type::Long).make_unsigned(),
+                        token,
+                    );
+                }
+                typ
             } else {
                 let exp = expr(token_ptr);
                 confirm_type(&exp);
@@ -1936,7 +4135,64 @@ fn unary(token_ptr: &mut TokenRef) -> NodeRef {
             let _typ = una.borrow().typ.clone().unwrap();
             _typ
         };
-        new_num(typ.bytes() as i32, token)
+        if typ.is_void() {
+            error_with_token!("void 型に sizeof 演算子は使用できません。", &token.borrow());
+        }
+        if typ.typ == Type::Func {
+            error_with_token!("関数型に sizeof 演算子は使用できません。", &token.borrow());
+        }
+        // sizeof の結果は size_t (unsigned long) 型
+        let size_t = TypeCell::new(Type::Long).make_unsigned();
+        if !typ.is_vla() {
+            let _ = take_vla_dims(mark);
+            return new_num(typ.bytes() as i64, size_t, token);
+        }
+        // 可変長配列のサイズは実行時に計算する(型名の中で要素数が指定されていれば、それも先に計算する)
+        let size = new_cast(&size_node(&typ), size_t);
+        if let Some(dims) = take_vla_dims(mark) {
+            let comma = tmp_binary!(Nodekind::CommaNd, dims, size);
+            confirm_type(&comma);
+            comma
+        } else {
+            size
+        }
+    } else if consume(token_ptr, "_Alignof") {
+        expect(token_ptr, "(");
+        let typ = type_name(token_ptr);
+        expect(token_ptr, ")");
+        if typ.is_void() {
+            error_with_token!(
+                "void 型に _Alignof 演算子は使用できません。",
+                &token.borrow()
+            );
+        }
+        if typ.typ == Type::Func {
+            error_with_token!(
+                "関数型に _Alignof 演算子は使用できません。",
+                &token.borrow()
+            );
+        }
+        // _Alignof の結果も size_t (unsigned long) 型
+        new_num(
+            typ.align() as i64,
+            TypeCell::new(Type::Long).make_unsigned(),
+            token,
+        )
+    } else if consume(token_ptr, "&&") {
+        // GNU 拡張のラベルのアドレス: 計算型 goto の飛び先として使う
+        if !in_function() {
+            error_with_token!(
+                "ラベルのアドレスは関数の中でのみ使用できます。",
+                &token.borrow()
+            );
+        }
+        let name = expect_ident(token_ptr);
+        let node = label_ref(Nodekind::LabelAddrNd, name, token);
+        let _ = node
+            .borrow_mut()
+            .typ
+            .insert(TypeCell::new(Type::Void).make_ptr_to());
+        node
     } else if consume(token_ptr, "~") {
         new_unary(Nodekind::BitNotNd, unary(token_ptr), token)
     } else if consume(token_ptr, "!") {
@@ -1951,24 +4207,163 @@ fn unary(token_ptr: &mut TokenRef) -> NodeRef {
         new_unary(Nodekind::AddrNd, node_ptr, token)
     } else if consume(token_ptr, "+") {
         // 単項演算子のプラスは0に足す形にする。こうすることで &+var のような表現を generator 側で弾ける
-        new_binary(Nodekind::AddNd, tmp_num!(0), primary(token_ptr), token)
+        // 浮動小数点数は 0 を足すと -0.0 の符号が変わってしまうので、キャストで左辺値でなくする
+        let node_ptr = unary(token_ptr);
+        confirm_type(&node_ptr);
+        let typ = node_ptr.borrow().typ.clone().unwrap();
+        if typ.is_floating() {
+            new_cast(&node_ptr, typ.unqualified())
+        } else {
+            new_binary(Nodekind::AddNd, tmp_num!(0), node_ptr, token)
+        }
     } else if consume(token_ptr, "-") {
         // 単項演算のマイナスは0から引く形にする。
-        new_binary(Nodekind::SubNd, tmp_num!(0), primary(token_ptr), token)
+        // 浮動小数点数は 0 から引くと -0.0 にならないので、符号反転のノードにする
+        let node_ptr = unary(token_ptr);
+        confirm_type(&node_ptr);
+        if node_ptr.borrow().typ.as_ref().unwrap().is_floating() {
+            new_unary(Nodekind::NegNd, node_ptr, token)
+        } else {
+            new_binary(Nodekind::SubNd, tmp_num!(0), node_ptr, token)
+        }
     } else if consume(token_ptr, "++") {
-        assign_op(Nodekind::AddNd, unary(token_ptr), tmp_num!(1), token)
+        let node_ptr = unary(token_ptr);
+        check_const(&node_ptr);
+        assign_op(Nodekind::AddNd, node_ptr, tmp_num!(1), token)
     } else if consume(token_ptr, "--") {
-        assign_op(Nodekind::SubNd, unary(token_ptr), tmp_num!(1), token)
+        let node_ptr = unary(token_ptr);
+        check_const(&node_ptr);
+        assign_op(Nodekind::SubNd, node_ptr, tmp_num!(1), token)
+    } else if is_cast(token_ptr) {
+        expect(token_ptr, "(");
+        let mark = vla_mark();
+        let typ = type_name(token_ptr);
+        let vla_dims = take_vla_dims(mark);
+        expect(token_ptr, ")");
+        if is(token_ptr, "{") {
+            let literal = compound_literal(token_ptr, typ, vla_dims, Rc::clone(&token));
+            return postfix(token_ptr, literal, token);
+        }
+        let node_ptr = unary(token_ptr);
+        let cast = new_explicit_cast(&node_ptr, typ, token);
+        if let Some(dims) = vla_dims {
+            tmp_binary!(Nodekind::CommaNd, dims, cast)
+        } else {
+            cast
+        }
     } else {
         tailed(token_ptr)
     }
 }
 
+/// 複合リテラル (type-name){...}: 無名のオブジェクトを確保して初期化し、それを左辺値として返す
+/// 関数内では自動変数として、ファイルスコープでは静的なオブジェクトとして確保する
+fn compound_literal(
+    token_ptr: &mut TokenRef,
+    typ: TypeCell,
+    vla_dims: Option<NodeRef>,
+    token: TokenRef,
+) -> NodeRef {
+    if vla_dims.is_some() || typ.is_vla() {
+        error_with_token!(
+            "可変長配列型の複合リテラルは使用できません。",
+            &token.borrow()
+        );
+    }
+    if typ.is_void() || typ.typ == Type::Func {
+        error_with_token!(
+            "\"{}\"型の複合リテラルは使用できません。",
+            &token.borrow(),
+            typ
+        );
+    }
+    let label = {
+        let mut count = STATIC_LVAR_COUNT.try_lock().unwrap();
+        *count += 1;
+        format!("__compound_literal.{}", *count - 1)
+    };
+    let is_flex = typ.is_flex_array();
+    if !in_function() {
+        let gvar = gvar_initializer(token_ptr, label.clone(), typ, is_flex, Rc::clone(&token));
+        set_linkage(&gvar, true, false);
+        let typ = gvar.borrow().typ.clone().unwrap();
+        STATIC_LVARS.try_lock().unwrap().push(gvar.borrow().clone());
+        return new_lvar(label, token, typ, false, 0);
+    }
+
+    // 初期化した後に変数のアドレスを返し、それを参照外しすることで左辺値として扱えるようにする
+    let init = lvar_initializer(token_ptr, label.clone(), typ, is_flex, Rc::clone(&token));
+    let (offset, typ, _) = LOCALS
+        .try_lock()
+        .unwrap()
+        .last()
+        .unwrap()
+        .get(&label)
+        .cloned()
+        .unwrap();
+    let addr = new_unary(
+        Nodekind::AddrNd,
+        direct_offset_lvar(offset, typ),
+        Rc::clone(&token),
+    );
+    new_unary(
+        Nodekind::DerefNd,
+        new_binary(Nodekind::CommaNd, init, addr, Rc::clone(&token)),
+        token,
+    )
+}
+
+/// "(" の後に型名が続く場合はキャストである
+fn is_cast(token_ptr: &TokenRef) -> bool {
+    let mut ptr = Rc::clone(token_ptr);
+    consume(&mut ptr, "(") && is_type(&mut ptr)
+}
+
+/// ソースコード上に書かれたキャスト (type)expr のノードを生成する
+fn new_explicit_cast(expr: &NodeRef, typ: TypeCell, token: TokenRef) -> NodeRef {
+    confirm_type(expr);
+    // (void)expr は値を捨てることを明示する
+    if typ.is_void() {
+        return new_cast(expr, typ);
+    }
+    if typ.is_array() || typ.typ == Type::Func {
+        error_with_token!("型\"{}\"へのキャストはできません。", &token.borrow(), typ);
+    }
+    check_void(expr);
+    // キャストの結果は左辺値ではないので、修飾子は意味を持たない
+    new_cast(expr, typ.unqualified())
+}
+
 /// 生成規則:
-/// tailed = primary (primary-tail)?
+/// tailed = primary ("[" expr "]" | "(" params ")")* (primary-tail)?
 /// primary-tail = "++" | "--"
+/// x[i] は *(x + i) と読み替える((*p)[i] のように括弧で囲まれた式にも添字を付けられる)
+/// また、 tbl[i](x) や (*fp)(x) のような関数ポインタを介した呼び出しもここで扱う
 fn tailed(token_ptr: &mut TokenRef) -> NodeRef {
+    let token = Rc::clone(token_ptr);
     let node_ptr: NodeRef = primary(token_ptr);
+    postfix(token_ptr, node_ptr, token)
+}
+
+/// tailed のうち primary (または複合リテラル) に続く部分を読む
+fn postfix(token_ptr: &mut TokenRef, mut node_ptr: NodeRef, token: TokenRef) -> NodeRef {
+    loop {
+        let tail_token = Rc::clone(token_ptr);
+        if consume(token_ptr, "[") {
+            let index = expr(token_ptr);
+            node_ptr = new_unary(
+                Nodekind::DerefNd,
+                new_add(node_ptr, index, tail_token),
+                Rc::clone(&token),
+            );
+            expect(token_ptr, "]");
+        } else if consume(token_ptr, "(") {
+            let args = params(token_ptr);
+            node_ptr = new_indirect_call(node_ptr, args, tail_token);
+        } else {
+            break;
+        }
+    }
     let token = Rc::clone(token_ptr);
     if consume(token_ptr, "++") {
         inc_dec(node_ptr, true, false, token)
@@ -1986,7 +4381,7 @@ fn inc_dec(node: NodeRef, is_inc: bool, is_prefix: bool, token: TokenRef) -> Nod
     } else {
         Nodekind::SubNd
     };
-    confirm_type(&node);
+    check_const(&node);
     let _assign = assign_op(kind, node, tmp_num!(1), Rc::clone(&token));
     if is_prefix {
         // ++i は (i+=1) として読み替えると良い
@@ -2001,6 +4396,142 @@ fn inc_dec(node: NodeRef, is_inc: bool, is_prefix: bool, token: TokenRef) -> Nod
     }
 }
 
+/// 名前が関数ではなく変数(関数ポインタを含む)として宣言されているかどうか
+fn is_variable(name: &String) -> bool {
+    LOCALS
+        .try_lock()
+        .unwrap()
+        .iter()
+        .any(|scope| scope.contains_key(name))
+        || GLOBALS
+            .try_lock()
+            .unwrap()
+            .get(name)
+            .is_some_and(|glob| glob.typ.is_some())
+}
+
+/// 暗黙の関数宣言: C89 と同様に int を返す引数の情報を持たない関数として GLOBALS に登録する(-std=c11 ではエラー)
+fn implicit_declaration(name: &str, token: &TokenRef) -> TypeCell {
+    if *STRICT_STD.try_lock().unwrap() {
+        error_with_token!(
+            "関数\"{}\"は宣言されていません。(C11 では暗黙の関数宣言は認められていません)",
+            &token.borrow(),
+            name
+        );
+    }
+    warning_with_token!(
+        "関数\"{}\"の暗黙の宣言です。[-Wimplicit-function-declaration]",
+        &token.borrow(),
+        name
+    );
+    let mut func_typ = TypeCell::new(Type::Int).make_func(vec![]);
+    func_typ.is_unprototyped = true;
+    let _ = proto_func(name.to_string(), func_typ.clone(), Rc::clone(token));
+    func_typ
+}
+
+/// 関数呼び出しの引数を関数の型と照合し、プロトタイプの型に変換する
+fn cast_args(args: &mut [NodeRef], func_typ: &TypeCell, name: &str, token: &TokenRef) {
+    // 現在利用できる型は一応全て エラーレベルで compatible (ただしまともなコンパイラは warning を出す) なので、引数の数があっていれば良いものとする
+    // 引数の情報を持たない関数の呼び出しでは、全ての引数に既定の実引数拡張を行う
+    if func_typ.is_unprototyped {
+        for arg in args.iter_mut() {
+            *arg = default_promoted(arg);
+        }
+        return;
+    }
+    let argc = func_typ.arg_typs.as_ref().unwrap().len();
+    if args.len() < argc || (args.len() > argc && !func_typ.is_variadic) {
+        error_with_token!(
+            "\"{}\" の引数は{}個{}で宣言されていますが、{}個が渡されました。",
+            &*token.borrow(),
+            name,
+            argc,
+            if func_typ.is_variadic { "以上" } else { "" },
+            args.len()
+        );
+    }
+
+    // 引数はプロトタイプの型に変換してから渡す
+    for (arg, arg_typ) in args.iter_mut().zip(func_typ.arg_typs.as_ref().unwrap()) {
+        let arg_typ = arg_typ.borrow().clone();
+        check_ptr_conversion(arg, &arg_typ);
+        check_arg_conversion(arg, &arg_typ, name);
+        if !arg_typ.is_array() {
+            *arg = new_cast(arg, arg_typ);
+        }
+    }
+    // "..." に対応する引数には既定の実引数拡張を行う
+    for arg in args.iter_mut().skip(argc) {
+        *arg = default_promoted(arg);
+    }
+}
+
+/// 引数がポインタと整数の間の変換や、互換性のないポインタ型への変換になる場合はエラーにする
+/// ただし空ポインタ定数はポインタに、ポインタは _Bool に、 void * は他のポインタとの間で変換できる
+fn check_arg_conversion(arg: &NodeRef, typ: &TypeCell, name: &str) {
+    let from = decayed(arg.borrow().typ.as_ref().unwrap());
+    let to = decayed(typ);
+    let compatible = match (from.is_pointer(), to.is_pointer()) {
+        (true, true) => {
+            let (from_deref, to_deref) = (from.make_deref().unwrap(), to.make_deref().unwrap());
+            from_deref.is_void()
+                || to_deref.is_void()
+                || from_deref.unqualified() == to_deref.unqualified()
+        }
+        (false, true) => is_null_ptr_const(arg),
+        (true, false) => !to.is_integer() || to.typ == Type::Bool,
+        (false, false) => true,
+    };
+    if !compatible {
+        error_with_node!(
+            "\"{}\" の引数に型\"{}\"の値を渡していますが、型\"{}\"とは互換性がありません。",
+            &arg.borrow(),
+            name,
+            from,
+            to
+        );
+    }
+}
+
+/// 既定の実引数拡張: 整数拡張を行い、 float は double に変換する
+fn default_promoted(arg: &NodeRef) -> NodeRef {
+    confirm_type(arg);
+    let typ = arg.borrow().typ.clone().unwrap();
+    if typ.typ == Type::Float {
+        new_cast(arg, TypeCell::new(Type::Double))
+    } else if typ.is_integer() && typ.int_promoted() != typ {
+        new_cast(arg, typ.int_promoted())
+    } else {
+        Rc::clone(arg)
+    }
+}
+
+/// 関数ポインタなどの式を介した呼び出し(間接呼び出し)のノードを生成する
+fn new_indirect_call(callee: NodeRef, mut args: Vec<NodeRef>, token: TokenRef) -> NodeRef {
+    confirm_type(&callee);
+    for arg in &args {
+        check_void(arg);
+    }
+    let typ = callee.borrow().typ.clone().unwrap();
+    let func_typ = if typ.typ == Type::Func {
+        typ
+    } else if typ.is_pointer() && typ.make_deref().unwrap().typ == Type::Func {
+        typ.make_deref().unwrap()
+    } else {
+        error_with_token!("型\"{}\"は関数として扱えません。", &*token.borrow(), typ);
+    };
+    cast_args(&mut args, &func_typ, &format!("{}", func_typ), &token);
+    Rc::new(RefCell::new(Node {
+        kind: Nodekind::FunCallNd,
+        token: Some(token),
+        func_typ: Some(func_typ),
+        left: Some(callee),
+        args,
+        ..Default::default()
+    }))
+}
+
 /// 生成規則:
 /// params = assign ("," assign)* | null
 fn params(token_ptr: &mut TokenRef) -> Vec<NodeRef> {
@@ -2023,11 +4554,110 @@ fn params(token_ptr: &mut TokenRef) -> Vec<NodeRef> {
     args
 }
 
+/// stdarg.h の va_start, va_arg, va_end, va_copy に対応する組み込み関数
+/// va_list はその先頭へのポインタとして扱う(関数の引数として受け取った va_list もそのまま使える)
+fn va_builtin(token_ptr: &mut TokenRef, name: &str, token: TokenRef) -> NodeRef {
+    let ap = va_list_arg(token_ptr, name, &token);
+    let void = TypeCell::new(Type::Void);
+    match name {
+        "__builtin_va_start" => {
+            // 第二引数(最後の名前付き引数)は gcc と同様に読み捨てる
+            expect(token_ptr, ",");
+            let _ = assign(token_ptr);
+            expect(token_ptr, ")");
+            let area = *VA_AREA.try_lock().unwrap();
+            if area.is_none() {
+                error_with_token!(
+                    "va_start は可変長引数をとる関数の中でのみ使用できます。",
+                    &token.borrow()
+                );
+            }
+            Rc::new(RefCell::new(Node {
+                kind: Nodekind::VaStartNd,
+                token: Some(token),
+                typ: Some(void),
+                left: Some(ap),
+                offset: area,
+                ..Default::default()
+            }))
+        }
+        "__builtin_va_arg" => {
+            expect(token_ptr, ",");
+            let typ = type_name(token_ptr);
+            expect(token_ptr, ")");
+            if typ.is_void() || typ.is_array() || typ.typ == Type::Func {
+                error_with_token!(
+                    "va_arg では型\"{}\"の値を取り出すことはできません。",
+                    &token.borrow(),
+                    typ
+                );
+            }
+            if typ.typ == Type::Float {
+                error_with_token!(
+                    "float の可変長引数は double として渡されるため、 va_arg には double を指定する必要があります。",
+                    &token.borrow()
+                );
+            }
+            let addr = Rc::new(RefCell::new(Node {
+                kind: Nodekind::VaArgNd,
+                token: Some(Rc::clone(&token)),
+                typ: Some(typ.make_ptr_to()),
+                left: Some(ap),
+                ..Default::default()
+            }));
+            let node = new_unary(Nodekind::DerefNd, addr, token);
+            confirm_type(&node);
+            node
+        }
+        "__builtin_va_end" => {
+            expect(token_ptr, ")");
+            new_cast(&ap, void)
+        }
+        "__builtin_va_copy" => {
+            expect(token_ptr, ",");
+            let src = va_list_arg(token_ptr, name, &token);
+            expect(token_ptr, ")");
+            Rc::new(RefCell::new(Node {
+                kind: Nodekind::VaCopyNd,
+                token: Some(token),
+                typ: Some(void),
+                left: Some(ap),
+                right: Some(src),
+                ..Default::default()
+            }))
+        }
+        _ => {
+            error_with_token!(
+                "組み込み関数\"{}\"は定義されていません。",
+                &token.borrow(),
+                name
+            );
+        }
+    }
+}
+
+/// va_list 型(もしくは引数として受け取った va_list である long *)の式を読む
+fn va_list_arg(token_ptr: &mut TokenRef, name: &str, token: &TokenRef) -> NodeRef {
+    let ap = assign(token_ptr);
+    confirm_type(&ap);
+    let typ = ap.borrow().typ.clone().unwrap();
+    if typ != TypeCell::new(Type::Long).make_array_of(3) {
+        error_with_token!(
+            "{} の引数は va_list 型である必要があります。(\"{}\"型が与えられました)",
+            &token.borrow(),
+            name,
+            typ
+        );
+    }
+    ap
+}
+
 /// 生成規則:
 /// primary = num
-///			| string-literal
-///			| ident ( "(" params ")" | "[" expr "]")?
-///			| "(" expr ")"
+///         | float-num
+///         | string-literal
+///         | ident ( "(" params ")" )?
+///         | "(" expr ")"
 fn primary(token_ptr: &mut TokenRef) -> NodeRef {
     let token = Rc::clone(token_ptr);
     if consume(token_ptr, "(") {
@@ -2035,86 +4665,73 @@ fn primary(token_ptr: &mut TokenRef) -> NodeRef {
         expect(token_ptr, ")");
         node_ptr
     } else if let Some(name) = consume_ident(token_ptr) {
-        if consume(token_ptr, "(") {
-            let func_typ: TypeCell;
-            let args: Vec<NodeRef> = params(token_ptr);
-            // 本来、宣言されているかを contains_key で確認したいが、今は外部の C ソースとリンクさせているため、このコンパイラの処理でパースした関数に対してのみ引数の数チェックをするにとどめる。
-            let glb_access = GLOBALS.try_lock().unwrap();
-            if glb_access.contains_key(&name) {
-                let glob = glb_access.get(&name).unwrap();
-                func_typ = if let Some(_typ) = glob.func_typ.clone() {
-                    _typ
-                } else {
-                    error_with_token!(
-                        "型\"{}\"は関数として扱えません。",
-                        &*token.borrow(),
-                        glob.typ.clone().unwrap()
-                    );
-                };
-
-                // 現在利用できる型は一応全て エラーレベルで compatible (ただしまともなコンパイラは warning を出す) なので、引数の数があっていれば良いものとする
-                let argc = func_typ.arg_typs.as_ref().unwrap().len();
-                if args.len() != argc {
-                    error_with_token!(
-                        "\"{}\" の引数は{}個で宣言されていますが、{}個が渡されました。",
-                        &*token.borrow(),
-                        name,
-                        argc,
-                        args.len()
-                    );
-                }
-                new_funcall(name, func_typ, args, token)
-            } else {
-                // 外部ソースの関数の戻り値の型をコンパイル時に得ることはできないため、int で固定とする
-                // また、引数の型は正しいとして args のものをコピーする
-                let mut arg_typs = vec![];
-                for arg in &args {
-                    arg_typs.push(Rc::new(RefCell::new(arg.borrow().typ.clone().unwrap())));
-                }
-                func_typ = TypeCell::new(Type::Int).make_func(arg_typs);
-                new_funcall(name, func_typ, args, token)
+        if name == "__builtin_alloca" && consume(token_ptr, "(") {
+            let size = assign(token_ptr);
+            expect(token_ptr, ")");
+            confirm_type(&size);
+            if !size.borrow().typ.as_ref().unwrap().is_integer() {
+                error_with_token!(
+                    "__builtin_alloca の引数は整数型である必要があります。",
+                    &token.borrow()
+                );
+            }
+            return new_alloca(size, token);
+        }
+        if name.starts_with("__builtin_va_") && consume(token_ptr, "(") {
+            return va_builtin(token_ptr, &name, token);
+        }
+        // 関数ポインタ型の変数の呼び出しは、 tailed で式を介した呼び出しとして扱う
+        if !is_variable(&name) && consume(token_ptr, "(") {
+            let mut args: Vec<NodeRef> = params(token_ptr);
+            for arg in &args {
+                check_void(arg);
             }
+            // 宣言されていない関数は、暗黙に extern int name(); と宣言されたものとして扱う
+            let declared = GLOBALS
+                .try_lock()
+                .unwrap()
+                .get(&name)
+                .map(|glob| glob.func_typ.clone().unwrap());
+            let func_typ = declared.unwrap_or_else(|| implicit_declaration(&name, &token));
+            cast_args(&mut args, &func_typ, &name, &token);
+            new_funcall(name, func_typ, args, token)
         } else {
-            // グローバル変数については、外部ソースとのリンクは禁止として、LOCALS, GLOBALS に当たらなければエラーになるようにする
+            // 外部ソースのグローバル変数は extern 宣言されている必要があるため、LOCALS, GLOBALS に当たらなければエラーになるようにする
             let mut typ: TypeCell = TypeCell::default();
             let mut is_local = false;
             let mut level = 0;
+            let mut label: Option<String> = None;
             for (l, scope) in LOCALS.try_lock().unwrap().iter_mut().enumerate().rev() {
-                let _ = scope.entry(name.clone()).and_modify(|(_, t)| {
+                let _ = scope.entry(name.clone()).and_modify(|(_, t, lbl)| {
                     typ = t.clone();
                     level = l;
                     is_local = true;
+                    label = lbl.clone();
                 });
                 if is_local {
                     break;
                 }
             }
 
+            // static 変数・extern 宣言はブロックスコープでも実体はグローバルなラベルを持つ
+            if let Some(label) = label {
+                return new_lvar(label, token, typ, false, 0);
+            }
+
             if !is_local {
-                typ = GLOBALS
+                // 関数名は関数型の値(関数指示子)として扱い、値として使う場合は関数へのポインタになる
+                let glob = GLOBALS
                     .try_lock()
                     .unwrap()
                     .entry(name.clone())
                     .or_insert_with(|| {
                         error_with_token!("定義されていない変数です。", &*token.borrow());
                     })
-                    .typ
-                    .clone()
-                    .unwrap();
-            }
-
-            let mut node_ptr = new_lvar(name, Rc::clone(&token), typ, is_local, level);
-            while consume(token_ptr, "[") {
-                let index_token = Rc::clone(token_ptr);
-                let index = expr(token_ptr);
-                node_ptr = new_unary(
-                    Nodekind::DerefNd,
-                    new_add(node_ptr, index, index_token),
-                    Rc::clone(&token),
-                );
-                expect(token_ptr, "]");
+                    .clone();
+                typ = glob.typ.or(glob.func_typ).unwrap();
             }
-            node_ptr
+
+            new_lvar(name, token, typ, is_local, level)
         }
     } else if let Some(literal) = consume_literal(token_ptr) {
         let size = literal.len() + 1;
@@ -2126,8 +4743,21 @@ fn primary(token_ptr: &mut TokenRef) -> NodeRef {
             false,
             0,
         )
+    } else if let Some(fval) = consume_float(token_ptr) {
+        // f サフィックスがあれば float, l サフィックスがあれば long double, なければ double
+        let body = token.borrow().body.clone().unwrap();
+        let typ = if body.ends_with(['f', 'F']) {
+            TypeCell::new(Type::Float)
+        } else if body.ends_with(['l', 'L']) {
+            TypeCell::new(Type::LDouble)
+        } else {
+            TypeCell::new(Type::Double)
+        };
+        new_float(fval, typ, token)
     } else {
-        new_num(expect_number(token_ptr), token)
+        let val = expect_number(token_ptr);
+        let typ = literal_type(val, token.borrow().body.as_ref().unwrap());
+        new_num(val, typ, token)
     }
 }
 
@@ -2208,130 +4838,592 @@ pub mod tests {
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn shift() {
+        let src: &str = "
+			int x;
+			x = 10 << 2 + 3 % 2 >> 3;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn bitops() {
+        let src: &str = "
+			int x, z;
+			int* y;
+			2 + (3 + 5) * 6;
+			1 ^ 2 | 2 != 3 / 2;
+			1 + -1 ^ 2;
+			3 ^ 2 & 1 | 2 & 9;
+			x = 10;
+			y = &x;
+			3 ^ 2 & *y | 2 & &x;
+			z = ~x;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn logops() {
+        let src: &str = "
+			1 && 2 || 3 && 4;
+			1 && 2 ^ 3 || 4 && 5 || 6;
+			!2;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn inc_dec() {
+        let src: &str = "
+			int i;
+			i = 0;
+			++i;
+			--i;
+			i++;
+			i--;
+			int *p;
+			++*p;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn for_() {
+        let src: &str = "
+			int sum, i;
+			sum = 10;
+			sum = sum + i;
+			for (i = 1 ; i < 10; i = i + 1) sum = sum +i;
+			return sum;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn while_() {
+        let src: &str = "
+			int sum;
+			sum = 10;
+			while(sum > 0) sum = sum - 1;
+			return sum;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn if_() {
+        let src: &str = "
+			int i;
+			i = 10;
+			if (i == 10) i = i / 5;
+			if (i == 2) i = i + 5; else i = i / 5;
+			return i;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn ctrls() {
+        let src: &str = "
+			int i, sum;
+			sum = 0;
+			i = 10;
+			if (i == 10) while(i < 0) for(;;) sum = sum + 1;
+			return sum;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn block() {
+        let src: &str = "
+			for( i = 10; ; ) {i = i + 1;}
+			{}
+			{i = i + 1; 10;}
+			return 10;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn block2() {
+        let src: &str = "
+			int i, x;
+			while(i < 10) {i = i + 1; i = i * 2;}
+			x = 10;
+			if ( x == 10 ){
+				x = x + 200;
+				x = x / 20;
+			} else {
+				x = x - 20;
+				;
+			}
+			{{}}
+			{i = i + 1; 10;}
+			return 200;
+			return;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn func() {
+        let src: &str = "
+			int i, j;
+			call_fprint();
+			i = getOne();
+			j = getTwo();
+			return i + j;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn func2() {
+        let src: &str = "
+			int i, j, k;
+			call_fprint();
+			i = get(1);
+			j = get(2, 3, 4);
+			k = get(i+j, (i=3), k);
+			return i + j;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn addr_deref() {
+        let src: &str = "
+			int x, y, z;
+			x = 3;
+			y = 5;
+			z = &y + 8;
+			return *z;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn addr_deref2() {
+        let src: &str = "
+			int x;
+			int *y;
+			int **z;
+			x = 3;
+			y = &x;
+			z = &y;
+			return *&**z;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn comma() {
+        let src: &str = "
+			int x, y, z;
+			x = 3, y = 4, z = 10;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn assign_op() {
+        let src: &str = "
+			int x;
+			x = 10;
+			x += 1;
+			x <<= 1;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn sizeof() {
+        let src: &str = "
+			int x, y, z;
+			x = 0; y = 0; z = 0;
+			int *p; p = &x;
+			int *pp; pp = &p;
+
+			sizeof(int);
+			sizeof(int **);
+			sizeof(0);
+			sizeof(x);
+			sizeof x;
+			sizeof ++x;
+			sizeof ++p;
+			sizeof(x+y);
+			sizeof x + y * z;
+			sizeof(x && x);
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn array() {
+        let src: &str = "
+			int x[20];
+			int y[10][20];
+			int *p[10][20][30];
+			int *q;
+			int z;
+			sizeof(*y);
+			sizeof(x);
+			x - q;
+			x + 10;
+			y - &q;
+			**p - y;
+			&p - z;
+			****p;
+			*****&p;
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = parse_stmts(&mut token_ptr);
+        let mut count: usize = 1;
+        for node_ptr in node_heads {
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            search_tree(&node_ptr);
+            count += 1;
+        }
+    }
+
+    #[test]
+    fn declare() {
+        let src: &str = "
+			int func(int x, int y) {
+				return x + y;
+			}
+			int calc(int a, int b, int c, int d, int e, int f) {
+				return a*b + c - d + e/f;
+			}
+			int main() {
+				int i, sum;
+				i = 0;
+				sum = 0;
+				for (; i < 10; i=i+1) {
+					sum = sum + i;
+				}
+				return func(i, sum);
+			}
+		";
+        test_init(src);
+
+        let mut token_ptr = tokenize(0);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("declare{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn shift() {
+    fn qualifier() {
         let src: &str = "
-			int x;
-			x = 10 << 2 + 3 % 2 >> 3;
+			const int g = 10;
+			int *const gp = 0;
+			int sum(const int *restrict p, int n) {
+				int s = 0;
+				for (int i = 0; i < n; i++) s += p[i];
+				return s;
+			}
+			int main() {
+				const int x = 1;
+				int const *p = &x;
+				volatile int v = 0;
+				const volatile char *const cvp = 0;
+				v++;
+				return sum(p, 1) + g;
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("qualifier{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn bitops() {
+    fn declarator() {
         let src: &str = "
-			int x, z;
-			int* y;
-			2 + (3 + 5) * 6;
-			1 ^ 2 | 2 != 3 / 2;
-			1 + -1 ^ 2;
-			3 ^ 2 & 1 | 2 & 9;
-			x = 10;
-			y = &x;
-			3 ^ 2 & *y | 2 & &x;
-			z = ~x;
+			int X[3][10];
+			int (*rows)[10];
+			char *(*table[4])(void);
+			int (*(*fp)(int, char))[2];
+			int (*get(int x))(int);
+			int main() {
+				int (*p)[10] = X;
+				long l = sizeof(int (*)[10]) + sizeof(char *(*[4])(void));
+				return (*p)[1] + p[1][2];
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("declarator{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn logops() {
+    fn funcptr() {
         let src: &str = "
-			1 && 2 || 3 && 4;
-			1 && 2 ^ 3 || 4 && 5 || 6;
-			!2;
+			int add(int a, int b) { return a + b; }
+			int (*ops[2])(int, int) = {add, &add};
+			int apply(int (*f)(int, int), int x) { return f(x, x); }
+			int main() {
+				int (*fp)(int, int) = add;
+				return fp(1, 2) + (*fp)(3, 4) + ops[1](5, 6) + apply(add, 7);
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("funcptr{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn inc_dec() {
+    fn storage() {
         let src: &str = "
-			int i;
-			i = 0;
-			++i;
-			--i;
-			i++;
-			i--;
-			int *p;
-			++*p;
+			extern int ext;
+			static int file_local = 3;
+			static int twice(int x) { return 2 * x; }
+			int counter() {
+				static int count = 1;
+				extern int ext;
+				return count++ + ext;
+			}
+			int main() {
+				return twice(file_local) + counter();
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("storage{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn for_() {
+    fn tentative() {
         let src: &str = "
-			int sum, i;
-			sum = 10;
-			sum = sum + i;
-			for (i = 1 ; i < 10; i = i + 1) sum = sum +i;
-			return sum;
+			int x;
+			int x = 3;
+			int x;
+			long arr[];
+			long arr[4];
+			int y, z;
+			int main() {
+				return x + y + arr[1];
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("tentative{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn while_() {
+    fn align() {
         let src: &str = "
-			int sum;
-			sum = 10;
-			while(sum > 0) sum = sum - 1;
-			return sum;
+			char c;
+			_Alignas(16) int x;
+			int _Alignas(long) y;
+			_Alignof(long double);
+			_Alignof(int[4]);
 		";
         test_init(src);
 
@@ -2339,277 +5431,315 @@ pub mod tests {
         let node_heads = parse_stmts(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("stmt{} {}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn if_() {
+    fn vla() {
         let src: &str = "
-			int i;
-			i = 10;
-			if (i == 10) i = i / 5;
-			if (i == 2) i = i + 5; else i = i / 5;
-			return i;
+			int sum(int n, int a[n][n]) {
+				int b[n][n + 1];
+				b[1][2] = a[1][1];
+				return sizeof b + b[1][2];
+			}
+			int main() {
+				int *p = __builtin_alloca(16);
+				return p[0];
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("vla{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn ctrls() {
+    fn designated() {
         let src: &str = "
-			int i, sum;
-			sum = 0;
-			i = 10;
-			if (i == 10) while(i < 0) for(;;) sum = sum + 1;
-			return sum;
+			int g[] = {[3] = 1, 2, [0 ... 1] = 5};
+			int main() {
+				int n = 0;
+				int a[2][3] = {1, [1] = {[2] = 4}, [0][1 ... 2] = n++};
+				return a[1][2];
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("designated{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn block() {
+    fn compound_literal() {
         let src: &str = "
-			for( i = 10; ; ) {i = i + 1;}
-			{}
-			{i = i + 1; 10;}
-			return 10;
+			int *g = (int[]){1, 2, 3};
+			int main() {
+				int *p = &(int){42};
+				return (int[]){5, 6, 7}[1] + *p + sizeof (char[]){\"abc\"};
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{}{}", count, ">".to_string().repeat(REP));
+            println!("compound_literal{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn block2() {
+    fn jump() {
         let src: &str = "
-			int i, x;
-			while(i < 10) {i = i + 1; i = i * 2;}
-			x = 10;
-			if ( x == 10 ){
-				x = x + 200;
-				x = x / 20;
-			} else {
-				x = x - 20;
-				;
+			int main() {
+				int s = 0;
+				for (int i = 0; i < 10; i++) {
+					if (i == 5) continue;
+					while (1) break;
+					if (i == 8) break;
+					s += i;
+				}
+				return s;
 			}
-			{{}}
-			{i = i + 1; 10;}
-			return 200;
-			return;
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("jump{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn func() {
+    fn do_while() {
         let src: &str = "
-			int i, j;
-			call_fprint();
-			i = getOne();
-			j = getTwo();
-			return i + j;
+			int main() {
+				int i = 0;
+				do {
+					i++;
+					if (i == 3) continue;
+					if (i > 5) break;
+				} while (i < 10);
+				return i;
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("do_while{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn func2() {
+    fn switch() {
         let src: &str = "
-			int i, j, k;
-			call_fprint();
-			i = get(1);
-			j = get(2, 3, 4);
-			k = get(i+j, (i=3), k);
-			return i + j;
+			int main() {
+				int x = 3;
+				switch (x) {
+				case 1:
+					x = 10;
+					break;
+				case 'a':
+				case 3:
+					x++;
+				default:
+					x *= 2;
+				}
+				return x;
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("switch{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn addr_deref() {
+    fn goto() {
         let src: &str = "
-			int x, y, z;
-			x = 3;
-			y = 5;
-			z = &y + 8;
-			return *z;
+			int main() {
+				int i = 0;
+				void *p = &&done;
+			again:
+				if (i < 3) {
+					i++;
+					goto again;
+				}
+				goto *p;
+			done:
+				return i;
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("goto{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn addr_deref2() {
+    fn conditional() {
         let src: &str = "
-			int x;
-			int *y;
-			int **z;
-			x = 3;
-			y = &x;
-			z = &y;
-			return *&**z;
+			int g[1 ? 2 : 3] = {0 ?: 4, 1 ? 5 : 6};
+			int main() {
+				int x = 1, *p = &x;
+				char *s = x ? \"yes\" : 0;
+				return (x > 0 ? *p : 2.5) + (p ?: 0)[0] + s[0];
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("conditional{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn comma() {
+    fn return_path() {
         let src: &str = "
-			int x, y, z;
-			x = 3, y = 4, z = 10;
+			int f(int x) {
+				while (1) {
+					if (x > 3) {
+						return x;
+					}
+					x++;
+				}
+			}
+			int g(int x) {
+				switch (x) {
+				case 0:
+					return 1;
+				default:
+					return 2;
+				}
+			}
+			int main() {
+				f(1);
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("return_path{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn assign_op() {
+    fn variadic() {
         let src: &str = "
-			int x;
-			x = 10;
-			x += 1;
-			x <<= 1;
+			#include <stdarg.h>
+			int printf(const char *fmt, ...);
+			int sum(int n, ...) {
+				va_list ap;
+				va_start(ap, n);
+				int s = 0;
+				while (n--) {
+					s += va_arg(ap, int);
+				}
+				va_end(ap);
+				return s;
+			}
+			int main() {
+				char c = 1;
+				printf(\"%d %f\\n\", c, 1.5f);
+				return sum(2, 3, 4);
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("variadic{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn sizeof() {
+    fn unprototyped() {
         let src: &str = "
-			int x, y, z;
-			x = 0; y = 0; z = 0;
-			int *p; p = &x;
-			int *pp; pp = &p;
-
-			sizeof(int);
-			sizeof(int **);
-			sizeof(0);
-			sizeof(x);
-			sizeof x;
-			sizeof ++x;
-			sizeof ++p;
-			sizeof(x+y);
-			sizeof x + y * z;
-			sizeof(x && x);
+			char *strchr(const char *s, int c);
+			double half();
+			int main() {
+				float f = 1.5;
+				char c = 2;
+				char *p = strchr(\"abc\", c);
+				return half(f) + undeclared(c, f) + *p;
+			}
 		";
         test_init(src);
 
         let mut token_ptr = tokenize(0);
-        let node_heads = parse_stmts(&mut token_ptr);
+        let node_heads = program(&mut token_ptr);
         let mut count: usize = 1;
         for node_ptr in node_heads {
-            println!("stmt{} {}", count, ">".to_string().repeat(REP));
+            println!("unprototyped{}{}", count, ">".to_string().repeat(REP));
             search_tree(&node_ptr);
             count += 1;
         }
     }
 
     #[test]
-    fn array() {
+    fn cast() {
         let src: &str = "
-			int x[20];
-			int y[10][20];
-			int *p[10][20][30];
-			int *q;
-			int z;
-			sizeof(*y);
-			sizeof(x);
-			x - q;
-			x + 10;
-			y - &q;
-			**p - y;
-			&p - z;
-			****p;
-			*****&p;
+			int x, *p;
+			(char)x;
+			(unsigned long)p;
+			(int *)(long)p;
+			(const int)x + 1;
+			(int (*)[2])p;
+			(double)x / 2;
+			-(long)x;
+			(void)x;
 		";
         test_init(src);
 
@@ -2624,22 +5754,19 @@ pub mod tests {
     }
 
     #[test]
-    fn declare() {
+    fn no_return() {
         let src: &str = "
 			int func(int x, int y) {
 				return x + y;
 			}
-			int calc(int a, int b, int c, int d, int e, int f) {
-				return a*b + c - d + e/f;
-			}
 			int main() {
-				int i, sum;
+				int i, sum, x, y, z;
 				i = 0;
 				sum = 0;
 				for (; i < 10; i=i+1) {
 					sum = sum + i;
 				}
-				return func(i, sum);
+				func(x=1, (y=1, z=1));
 			}
 		";
         test_init(src);
@@ -2655,19 +5782,20 @@ pub mod tests {
     }
 
     #[test]
-    fn no_return() {
+    fn void_() {
         let src: &str = "
-			int func(int x, int y) {
-				return x + y;
+			void inc(int *p);
+			void *pass(void *p) {
+				return p;
 			}
-			int main() {
-				int i, sum, x, y, z;
-				i = 0;
-				sum = 0;
-				for (; i < 10; i=i+1) {
-					sum = sum + i;
-				}
-				func(x=1, (y=1, z=1));
+			void inc(int *p) {
+				if (*p > 10) return;
+				*p = *p + 1;
+			}
+			int main(void) {
+				int x;
+				int *q = pass(&x);
+				(void)inc(q);
 			}
 		";
         test_init(src);
@@ -0,0 +1,145 @@
+// 簡易的なプリプロセッサ: 同梱のヘッダの #include と、引数のないマクロの #define, #undef のみをサポートする
+use std::collections::{HashMap, HashSet};
+use std::iter::FromIterator;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::{
+    globals::{FILE_NAMES, SRC},
+    token::TokenRef,
+    tokenizer::tokenize,
+};
+
+/// 同梱のヘッダファイル名 -> その中身
+static HEADERS: Lazy<HashMap<&str, &str>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    let _ = map.insert("stdbool.h", include_str!("../include/stdbool.h"));
+    let _ = map.insert("stdarg.h", include_str!("../include/stdarg.h"));
+    map
+});
+
+/// マクロ名 -> 置き換え後の内容を持つ(疑似的な)ソースのファイル番号
+static MACROS: Lazy<Mutex<HashMap<String, usize>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 展開中のマクロ名: 自身を含むマクロが無限に展開されるのを防ぐ
+static EXPANDING: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// 行頭の "#" から始まる指令を処理する
+/// 挿入すべきトークン列がある場合はその先頭を返し、エラーの場合は(行内のオフセット, メッセージ)を返す
+pub fn directive(
+    string: &[char],
+    mut index: usize,
+    file_num: usize,
+    line_num: usize,
+) -> Result<Option<TokenRef>, (usize, &'static str)> {
+    index += 1; // "#" を読み飛ばす
+    skip_blank(string, &mut index);
+    let name = read_word(string, &mut index);
+    skip_blank(string, &mut index);
+
+    match name.as_str() {
+        "include" => {
+            let close = match string.get(index) {
+                Some('<') => '>',
+                Some('"') => '"',
+                _ => {
+                    return Err((
+                        index,
+                        "ヘッダファイル名は <...> か \"...\" で指定する必要があります。",
+                    ))
+                }
+            };
+            let start = index + 1;
+            let end = if let Some(pos) = string[start..].iter().position(|c| *c == close) {
+                start + pos
+            } else {
+                return Err((index, "ヘッダファイル名が閉じられていません。"));
+            };
+            let header = String::from_iter(string[start..end].iter());
+            if let Some(content) = HEADERS.get(header.as_str()) {
+                let file_num = load_src(header, content);
+                Ok(Some(tokenize(file_num)))
+            } else {
+                Err((
+                    start,
+                    "ヘッダファイルが見つかりません。(現在は同梱のヘッダのみ使用できます)",
+                ))
+            }
+        }
+        "define" => {
+            let macro_name = read_word(string, &mut index);
+            if macro_name.is_empty() {
+                return Err((index, "マクロ名が必要です。"));
+            }
+            if string.get(index) == Some(&'(') {
+                return Err((index, "引数付きのマクロはサポートされていません。"));
+            }
+
+            // マクロの本体は、エラー表示のために定義された行と同じ位置に置いた疑似的なソースとして保存しておく
+            let body = string
+                .iter()
+                .enumerate()
+                .map(|(i, c)| if i < index && *c != '\t' { ' ' } else { *c })
+                .collect::<String>();
+            let file_name = FILE_NAMES.try_lock().unwrap()[file_num].clone();
+            let mut code = vec![String::new(); line_num];
+            code.push(body);
+            FILE_NAMES.try_lock().unwrap().push(file_name);
+            let mut src = SRC.try_lock().unwrap();
+            src.push(code);
+            let _ = MACROS.try_lock().unwrap().insert(macro_name, src.len() - 1);
+            Ok(None)
+        }
+        "undef" => {
+            let macro_name = read_word(string, &mut index);
+            if macro_name.is_empty() {
+                return Err((index, "マクロ名が必要です。"));
+            }
+            let _ = MACROS.try_lock().unwrap().remove(&macro_name);
+            Ok(None)
+        }
+        // "#" のみの行と、処理系定義の #pragma は何もしない
+        "" | "pragma" => Ok(None),
+        _ => Err((index, "サポートされていないプリプロセッサ指令です。")),
+    }
+}
+
+/// 識別子がマクロであれば、展開したトークン列の先頭を返す
+pub fn expand_macro(name: &str) -> Option<TokenRef> {
+    let file_num = *MACROS.try_lock().unwrap().get(name)?;
+    if !EXPANDING.try_lock().unwrap().insert(name.to_string()) {
+        return None;
+    }
+    let head = tokenize(file_num);
+    let _ = EXPANDING.try_lock().unwrap().remove(name);
+    Some(head)
+}
+
+/// ヘッダの中身を新しいソースとして SRC, FILE_NAMES に登録し、そのファイル番号を返す
+fn load_src(file_name: String, content: &str) -> usize {
+    FILE_NAMES.try_lock().unwrap().push(file_name);
+    let mut code = vec!["".to_string()]; // code_load と同様に、行の index を1始まりにする
+    for line in content.lines() {
+        code.push(line.to_string() + "\n");
+    }
+    let mut src = SRC.try_lock().unwrap();
+    src.push(code);
+    src.len() - 1
+}
+
+fn skip_blank(string: &[char], index: &mut usize) {
+    while *index < string.len() && [' ', '\t'].contains(&string[*index]) {
+        *index += 1;
+    }
+}
+
+// 英数字とアンダーバーからなる語を読む
+fn read_word(string: &[char], index: &mut usize) -> String {
+    let start = *index;
+    while *index < string.len() && (string[*index].is_ascii_alphanumeric() || string[*index] == '_')
+    {
+        *index += 1;
+    }
+    String::from_iter(string[start..*index].iter())
+}
@@ -3,7 +3,10 @@ use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::rc::Rc;
 
-use crate::{exit_eprintln, utils::error_at};
+use crate::{
+    exit_eprintln,
+    utils::{error_at, warn_at},
+};
 
 pub type TokenRef = Rc<RefCell<Token>>;
 
@@ -15,6 +18,7 @@ pub enum Tokenkind {
     ReservedTk, // 記号
     StringTk,   // 文字列リテラル
     NumTk,      // 整数トークン
+    FloatTk,    // 浮動小数点数トークン
     ReturnTk,   // リターン
     EOFTk,      // 入力終わり
 }
@@ -28,6 +32,7 @@ impl Display for Tokenkind {
             Tokenkind::ReservedTk => "Reserved Token",
             Tokenkind::StringTk => "String Token",
             Tokenkind::NumTk => "Number Token",
+            Tokenkind::FloatTk => "Float Token",
             Tokenkind::ReturnTk => "Return Token",
             Tokenkind::EOFTk => "EOF Token",
         };
@@ -38,7 +43,8 @@ impl Display for Tokenkind {
 #[derive(Debug)]
 pub struct Token {
     pub kind: Tokenkind,
-    pub val: Option<i32>,
+    pub val: Option<i64>,
+    pub fval: Option<f64>,
     pub body: Option<String>,
     pub len: usize, // 1文字でないトークンもあるので、文字列の長さを保持しておく(非負)
     pub next: Option<TokenRef>, // Tokenは単純に単方向非循環LinkedListを構成することしかしないため、リークは起きないものと考える(循環の可能性があるなら、Weakを使うべき)
@@ -54,6 +60,7 @@ impl Default for Token {
         Token {
             kind: Tokenkind::DefaultTk,
             val: None,
+            fval: None,
             body: None,
             len: 0,
             next: None,
@@ -89,16 +96,38 @@ impl Token {
                 ..Default::default()
             },
             Tokenkind::NumTk => {
-                let val = body.parse::<i32>().unwrap();
+                // "10ul" のようなサフィックスは body にのみ残し、値は数字部分から読む
+                // 文字リテラル由来の負の値もあり得るので、 i64 で読めなければ u64 として読む
+                let digits = body.trim_end_matches(|c| "uUlL".contains(c));
+                let val = digits
+                    .parse::<i64>()
+                    .unwrap_or_else(|_| digits.parse::<u64>().unwrap() as i64);
                 Token {
                     kind: kind,
                     val: Some(val),
                     body: Some(body),
                     len: len,
-                    next: None,
                     file_num: file_num,
                     line_num: line_num,
                     line_offset: line_offset,
+                    ..Default::default()
+                }
+            }
+            Tokenkind::FloatTk => {
+                // "1.5f" のようなサフィックスも body にのみ残す
+                let fval = body
+                    .trim_end_matches(|c| "fFlL".contains(c))
+                    .parse::<f64>()
+                    .unwrap();
+                Token {
+                    kind,
+                    fval: Some(fval),
+                    body: Some(body),
+                    len,
+                    file_num,
+                    line_num,
+                    line_offset,
+                    ..Default::default()
                 }
             }
             Tokenkind::ReservedTk => Token {
@@ -159,6 +188,10 @@ impl Display for Token {
             s = format!("{}val: -\n", s);
         }
 
+        if let Some(e) = self.fval.as_ref() {
+            s = format!("{}fval: {}\n", s, e);
+        }
+
         if let Some(e) = self.next.as_ref() {
             s = format!("{}next: -> {:?}\n", s, (**e).borrow().kind);
         } else {
@@ -202,6 +235,19 @@ macro_rules! error_with_token {
 	);
 }
 
+#[macro_export]
+macro_rules! warning_with_token {
+	($fmt: expr, $tok: expr) => (
+		use $crate::token::warn_tok;
+		warn_tok($fmt, $tok);
+	);
+
+	($fmt: expr, $tok: expr, $($arg: tt)*) => (
+		use $crate::token::warn_tok;
+		warn_tok(format!($fmt, $($arg)*).as_str(), $tok);
+	);
+}
+
 /// エラー送出のためのラッパー
 pub fn error_tok(msg: &str, token: &Token) -> ! {
     // token.line_offset は token.len 以上であるはずなので負になる可能性をチェックしない
@@ -213,6 +259,16 @@ pub fn error_tok(msg: &str, token: &Token) -> ! {
     );
 }
 
+/// 警告のためのラッパー
+pub fn warn_tok(msg: &str, token: &Token) {
+    warn_at(
+        msg,
+        token.file_num,
+        token.line_num,
+        token.line_offset - token.len,
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
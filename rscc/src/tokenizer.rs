@@ -10,6 +10,8 @@ use once_cell::sync::Lazy;
 use crate::{
     error_with_token,
     globals::SRC,
+    parser::alignas_specifier,
+    preprocessor::{directive, expand_macro},
     token::{token_ptr_exceed, Token, TokenRef, Tokenkind},
     typecell::{Type, TypeCell},
     utils::{error_at, is_digit, strtol},
@@ -22,15 +24,17 @@ pub fn tokenize(file_num: usize) -> TokenRef {
     let mut token_head_ptr: TokenRef = Rc::clone(&token_ptr);
     let mut err_profile: (bool, usize, usize, &str) = (false, 0, 0, "");
     // error_at を使うタイミングで SRC のロックが外れているようにスコープを調整
+    // また、 #include などで再帰的に tokenize を呼ぶので、ロックを保持したままにせずコードを複製して読む
     {
-        let code = &mut SRC.try_lock().unwrap()[file_num];
+        let code = SRC.try_lock().unwrap()[file_num].clone();
         let mut is_block_comment = false;
-        for (line_num, string) in (&*code).iter().enumerate() {
+        for (line_num, string) in code.iter().enumerate() {
             // StringをVec<char>としてlookat(インデックス)を進めることでトークナイズを行う(*char p; p++;みたいなことは気軽にできない)
             let mut lookat: usize = 0;
             let mut c: char;
             let string: Vec<char> = string.as_str().chars().collect::<Vec<char>>();
             let len: usize = string.len(); // Vec<char> にしてから len() を呼ぶことで、複数バイト文字も正しく1文字ずつ扱える
+            let mut is_line_head = true;
 
             while lookat < len {
                 // 余白をまとめて飛ばす。streamを最後まで読んだならbreakする。
@@ -59,6 +63,32 @@ pub fn tokenize(file_num: usize) -> TokenRef {
                     break;
                 }
 
+                // 行頭の "#" はプリプロセッサの指令として、行末までをまとめて処理する
+                if is_line_head && string[lookat] == '#' {
+                    match directive(&string, lookat, file_num, line_num) {
+                        Ok(Some(head)) => splice(&mut token_ptr, head),
+                        Ok(None) => {}
+                        Err((offset, msg)) => {
+                            err_profile = (true, line_num, offset, msg);
+                        }
+                    }
+                    break;
+                }
+                is_line_head = false;
+
+                // 浮動小数点数のリテラルは整数より先に判定する(.5 のような "." で始まるものがあるため、予約文字よりも先に判定する)
+                if let Some(body) = read_float(&string, &mut lookat, len) {
+                    token_ptr.borrow_mut().next = Some(Rc::new(RefCell::new(Token::new(
+                        Tokenkind::FloatTk,
+                        body,
+                        file_num,
+                        line_num,
+                        lookat,
+                    ))));
+                    token_ptr_exceed(&mut token_ptr);
+                    continue;
+                }
+
                 // 予約文字を判定
                 if let Some(body) = is_reserved(&string, &mut lookat, len) {
                     token_ptr.borrow_mut().next = Some(Rc::new(RefCell::new(Token::new(
@@ -89,9 +119,10 @@ pub fn tokenize(file_num: usize) -> TokenRef {
                 c = string[lookat];
                 if is_digit(&c) {
                     let num = strtol(&string, &mut lookat);
+                    let suffix = read_int_suffix(&string, &mut lookat, len);
                     token_ptr.borrow_mut().next = Some(Rc::new(RefCell::new(Token::new(
                         Tokenkind::NumTk,
-                        num.to_string(),
+                        format!("{}{}", num, suffix),
                         file_num,
                         line_num,
                         lookat,
@@ -104,13 +135,20 @@ pub fn tokenize(file_num: usize) -> TokenRef {
                 if (c >= 'a' && c <= 'z') | (c >= 'A' && c <= 'Z') | (c == '_') {
                     let name = read_lvar(&string, &mut lookat);
 
-                    // トークン列にIdentTkとして追加する必要がある
+                    // マクロであれば展開したトークン列に置き換える
+                    if let Some(head) = expand_macro(&name) {
+                        splice(&mut token_ptr, head);
+                        continue;
+                    }
+
+                    // 識別子の形をした型指定子(__builtin_va_list)は予約語として扱う
+                    let kind = if TYPES.try_lock().unwrap().contains_key(&name) {
+                        Tokenkind::ReservedTk
+                    } else {
+                        Tokenkind::IdentTk
+                    };
                     token_ptr.borrow_mut().next = Some(Rc::new(RefCell::new(Token::new(
-                        Tokenkind::IdentTk,
-                        name,
-                        file_num,
-                        line_num,
-                        lookat,
+                        kind, name, file_num, line_num, lookat,
                     ))));
                     token_ptr_exceed(&mut token_ptr);
                     continue;
@@ -184,9 +222,38 @@ pub fn tokenize(file_num: usize) -> TokenRef {
 }
 
 /* ------------------------------------------------- トークナイズ用関数 ------------------------------------------------- */
-static QUAD_KEYWORDS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| Mutex::new(vec!["else", "char"]));
+// 別途トークナイズしたトークン列を EOF の手前まで繋げ、 token_ptr をその末尾まで進める
+fn splice(token_ptr: &mut TokenRef, head: TokenRef) {
+    let mut ptr = head;
+    while ptr.borrow().kind != Tokenkind::EOFTk {
+        token_ptr.borrow_mut().next = Some(Rc::clone(&ptr));
+        token_ptr_exceed(token_ptr);
+        let next = Rc::clone(ptr.borrow().next.as_ref().unwrap());
+        ptr = next;
+    }
+}
+
+static OCTA_KEYWORDS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| {
+    Mutex::new(vec![
+        "unsigned", "volatile", "restrict", "_Alignas", "_Alignof", "continue",
+    ])
+});
+
+static SEPTA_KEYWORDS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| Mutex::new(vec!["default"]));
+
+static HEXA_KEYWORDS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| {
+    Mutex::new(vec![
+        "sizeof", "signed", "double", "static", "extern", "switch",
+    ])
+});
 
-static TRI_OPS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| Mutex::new(vec!["<<=", ">>="]));
+static PENTA_KEYWORDS: Lazy<Mutex<Vec<&str>>> =
+    Lazy::new(|| Mutex::new(vec!["while", "short", "_Bool", "float", "const", "break"]));
+
+static QUAD_KEYWORDS: Lazy<Mutex<Vec<&str>>> =
+    Lazy::new(|| Mutex::new(vec!["else", "char", "long", "void", "case", "goto"]));
+
+static TRI_OPS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| Mutex::new(vec!["<<=", ">>=", "..."]));
 
 static TRI_KEYWORDS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| Mutex::new(vec!["for", "int"]));
 
@@ -199,20 +266,35 @@ static BI_OPS: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| {
 static UNI_RESERVED: Lazy<Mutex<Vec<char>>> = Lazy::new(|| {
     Mutex::new(vec![
         ';', ',', '(', ')', '{', '}', '[', ']', '+', '-', '*', '/', '%', '&', '|', '^', '!', '~',
-        '=', '<', '>',
+        '=', '<', '>', '.', ':', '?',
     ])
 });
 
 static SPACES: Lazy<Mutex<Vec<char>>> = Lazy::new(|| Mutex::new(vec![' ', '\t', '\n']));
 
-// 現在は int のみサポート
+// signed, unsigned は単体では int を表す
 static TYPES: Lazy<Mutex<HashMap<String, Type>>> = Lazy::new(|| {
     let mut map = HashMap::new();
     let _ = map.insert(String::from("int"), Type::Int);
     let _ = map.insert(String::from("char"), Type::Char);
+    let _ = map.insert(String::from("short"), Type::Short);
+    let _ = map.insert(String::from("long"), Type::Long);
+    let _ = map.insert(String::from("signed"), Type::Int);
+    let _ = map.insert(String::from("unsigned"), Type::Int);
+    let _ = map.insert(String::from("void"), Type::Void);
+    let _ = map.insert(String::from("_Bool"), Type::Bool);
+    let _ = map.insert(String::from("float"), Type::Float);
+    let _ = map.insert(String::from("double"), Type::Double);
+    // va_list の実体: gcc と同じ 24 バイトの領域を long[3] として表す
+    let _ = map.insert(String::from("__builtin_va_list"), Type::Long);
     Mutex::new(map)
 });
 
+static QUALIFIERS: Lazy<Mutex<Vec<&str>>> =
+    Lazy::new(|| Mutex::new(vec!["const", "volatile", "restrict"]));
+
+static STORAGE_CLASSES: Lazy<Mutex<Vec<&str>>> = Lazy::new(|| Mutex::new(vec!["static", "extern"]));
+
 // 空白を飛ばして読み進める
 fn skipspace(string: &Vec<char>, index: &mut usize, len: usize) -> Result<(), ()> {
     // 既にEOFだったならErrを即返す
@@ -304,6 +386,65 @@ fn read(string: &Vec<char>, read: impl Into<String>, index: &mut usize, len: usi
     true
 }
 
+// 浮動小数点数のリテラル(小数点か指数部を含むもの)であれば、サフィックスまで含めて読む
+fn read_float(string: &[char], index: &mut usize, len: usize) -> Option<String> {
+    let mut i = *index;
+    let int_start = i;
+    while i < len && is_digit(&string[i]) {
+        i += 1;
+    }
+    let mut has_digit = i > int_start;
+    let mut is_float = false;
+
+    if i < len && string[i] == '.' {
+        is_float = true;
+        i += 1;
+        let frac_start = i;
+        while i < len && is_digit(&string[i]) {
+            i += 1;
+        }
+        has_digit |= i > frac_start;
+    }
+    if !has_digit {
+        return None;
+    }
+
+    if i < len && (string[i] == 'e' || string[i] == 'E') {
+        let mut j = i + 1;
+        if j < len && (string[j] == '+' || string[j] == '-') {
+            j += 1;
+        }
+        let exp_start = j;
+        while j < len && is_digit(&string[j]) {
+            j += 1;
+        }
+        if j > exp_start {
+            is_float = true;
+            i = j;
+        }
+    }
+    if !is_float {
+        return None;
+    }
+
+    if i < len && "fFlL".contains(string[i]) {
+        i += 1;
+    }
+    let body = String::from_iter(string[*index..i].iter());
+    *index = i;
+    Some(body)
+}
+
+// 整数リテラルのサフィックス(u, l, ll とその組み合わせ)を読む
+fn read_int_suffix(string: &[char], index: &mut usize, len: usize) -> String {
+    let mut suffix = String::new();
+    while *index < len && "uUlL".contains(string[*index]) {
+        suffix.push(string[*index]);
+        *index += 1;
+    }
+    suffix
+}
+
 // 識別子の一部として使用可能な文字であるかどうかを判別する
 fn canbe_ident_part(c: &char) -> bool {
     return (*c >= 'a' && *c <= 'z')
@@ -327,10 +468,34 @@ fn can_follow_reserved(string: &Vec<char>, index: usize) -> bool {
 // 予約されたトークンだった場合はSome(String)を返す
 fn is_reserved(string: &Vec<char>, index: &mut usize, len: usize) -> Option<String> {
     // 先に複数文字の演算子かどうかチェックする(文字数の多い方から)
+    let lim = *index + 8;
+    if lim <= len {
+        let slice: String = String::from_iter(string[*index..lim].iter());
+        if OCTA_KEYWORDS.try_lock().unwrap().contains(&slice.as_str())
+            && can_follow_reserved(string, lim)
+        {
+            *index = lim;
+            return Some(slice);
+        }
+    }
+
+    let lim = *index + 7;
+    if lim <= len {
+        let slice: String = String::from_iter(string[*index..lim].iter());
+        if SEPTA_KEYWORDS.try_lock().unwrap().contains(&slice.as_str())
+            && can_follow_reserved(string, lim)
+        {
+            *index = lim;
+            return Some(slice);
+        }
+    }
+
     let lim = *index + 6;
     if lim <= len {
         let slice: String = String::from_iter(string[*index..lim].iter());
-        if slice == "sizeof" && can_follow_reserved(string, lim) {
+        if HEXA_KEYWORDS.try_lock().unwrap().contains(&slice.as_str())
+            && can_follow_reserved(string, lim)
+        {
             *index = lim;
             return Some(slice);
         }
@@ -339,7 +504,9 @@ fn is_reserved(string: &Vec<char>, index: &mut usize, len: usize) -> Option<Stri
     let lim = *index + 5;
     if lim <= len {
         let slice: String = String::from_iter(string[*index..lim].iter());
-        if slice == "while" && can_follow_reserved(string, lim) {
+        if PENTA_KEYWORDS.try_lock().unwrap().contains(&slice.as_str())
+            && can_follow_reserved(string, lim)
+        {
             *index = lim;
             return Some(slice);
         }
@@ -368,12 +535,12 @@ fn is_reserved(string: &Vec<char>, index: &mut usize, len: usize) -> Option<Stri
         }
     }
 
-    // 2文字演算子とif
+    // 2文字演算子と if, do
     let lim = *index + 2;
     if lim <= len {
         let slice: String = String::from_iter(string[*index..(*index + 2)].iter());
         if BI_OPS.try_lock().unwrap().contains(&slice.as_str())
-            || (slice == "if" && can_follow_reserved(string, lim))
+            || ((slice == "if" || slice == "do") && can_follow_reserved(string, lim))
         {
             *index = lim;
             return Some(slice);
@@ -465,7 +632,7 @@ fn is_number(token_ptr: &mut TokenRef) -> bool {
 }
 
 #[inline]
-pub fn consume_number(token_ptr: &mut TokenRef) -> Option<i32> {
+pub fn consume_number(token_ptr: &mut TokenRef) -> Option<i64> {
     if is_number(token_ptr) {
         let val = token_ptr.borrow().val.unwrap();
         token_ptr_exceed(token_ptr);
@@ -476,7 +643,18 @@ pub fn consume_number(token_ptr: &mut TokenRef) -> Option<i32> {
 }
 
 #[inline]
-pub fn expect_number(token_ptr: &mut TokenRef) -> i32 {
+pub fn consume_float(token_ptr: &mut TokenRef) -> Option<f64> {
+    if is_kind(token_ptr, Tokenkind::FloatTk) {
+        let fval = token_ptr.borrow().fval.unwrap();
+        token_ptr_exceed(token_ptr);
+        Some(fval)
+    } else {
+        None
+    }
+}
+
+#[inline]
+pub fn expect_number(token_ptr: &mut TokenRef) -> i64 {
     if let Some(val) = consume_number(token_ptr) {
         val
     } else {
@@ -517,30 +695,179 @@ pub fn expect_ident(token_ptr: &mut TokenRef) -> String {
     }
 }
 
+/// 型指定子、型修飾子、記憶域クラス指定子であれば true (いずれも宣言の始まりになり得る)
 #[inline]
 pub fn is_type(token_ptr: &mut TokenRef) -> bool {
     is_kind(token_ptr, Tokenkind::ReservedTk)
-        && TYPES
+        && (TYPES
             .try_lock()
             .unwrap()
             .contains_key(token_ptr.borrow().body.as_ref().unwrap())
+            || is_qualifier(token_ptr)
+            || is_storage_class(token_ptr)
+            || is(token_ptr, "_Alignas"))
 }
 
 #[inline]
-pub fn consume_type(token_ptr: &mut TokenRef) -> Option<TypeCell> {
-    if is_type(token_ptr) {
-        let base: Type = *TYPES
+fn is_storage_class(token_ptr: &mut TokenRef) -> bool {
+    is_kind(token_ptr, Tokenkind::ReservedTk)
+        && STORAGE_CLASSES
+            .try_lock()
+            .unwrap()
+            .contains(&token_ptr.borrow().body.as_ref().unwrap().as_str())
+}
+
+#[inline]
+pub fn is_qualifier(token_ptr: &mut TokenRef) -> bool {
+    is_kind(token_ptr, Tokenkind::ReservedTk)
+        && QUALIFIERS
             .try_lock()
             .unwrap()
-            .get(token_ptr.borrow().body.as_ref().unwrap())
-            .unwrap();
+            .contains(&token_ptr.borrow().body.as_ref().unwrap().as_str())
+}
+
+/// 型修飾子の並び(const, volatile, restrict)を読み、 typ に設定する
+pub fn consume_qualifiers(token_ptr: &mut TokenRef, typ: &mut TypeCell) {
+    while is_qualifier(token_ptr) {
+        let body = token_ptr.borrow().body.clone().unwrap();
+        match body.as_str() {
+            "const" => typ.is_const = true,
+            "volatile" => typ.is_volatile = true,
+            _ => typ.is_restrict = true,
+        }
         token_ptr_exceed(token_ptr);
-        Some(TypeCell::new(base))
-    } else {
-        None
     }
 }
 
+/// "const unsigned long int" のような型指定子と型修飾子の並びを読み、対応する TypeCell を返す
+#[inline]
+pub fn consume_type(token_ptr: &mut TokenRef) -> Option<TypeCell> {
+    let token = Rc::clone(token_ptr);
+    let (typ, storage) = consume_decl_type(token_ptr)?;
+    if typ.alignas.is_some() {
+        error_with_token!("_Alignas はここでは使用できません。", &token.borrow());
+    }
+    if let Some(storage) = storage {
+        error_with_token!(
+            "記憶域クラス指定子\"{}\"はここでは使用できません。",
+            &token.borrow(),
+            storage
+        );
+    }
+    Some(typ)
+}
+
+/// 宣言の先頭で、型に加えて記憶域クラス指定子("static" か "extern")も読む
+/// _Alignas 指定子があれば、そのうち最大のアラインメントを typ.alignas に設定する
+pub fn consume_decl_type(token_ptr: &mut TokenRef) -> Option<(TypeCell, Option<String>)> {
+    if !is_type(token_ptr) {
+        return None;
+    }
+
+    // 各型指定子の出現回数を数え、読むたびに組み合わせが正しいかを確認する
+    // 型修飾子は型指定子の前後どこに現れても良いので、別に記録しておく
+    let token = Rc::clone(token_ptr);
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut typ = TypeCell::default();
+    let mut quals = TypeCell::default();
+    let mut storage: Option<String> = None;
+    let mut alignas: Option<usize> = None;
+    while is_type(token_ptr) {
+        if is(token_ptr, "_Alignas") {
+            let align = alignas_specifier(token_ptr);
+            let _ = alignas.insert(alignas.unwrap_or(0).max(align));
+            continue;
+        }
+        if is_qualifier(token_ptr) {
+            consume_qualifiers(token_ptr, &mut quals);
+            continue;
+        }
+        if is_storage_class(token_ptr) {
+            if storage.is_some() {
+                error_with_token!(
+                    "記憶域クラス指定子は1つまでしか指定できません。",
+                    &token_ptr.borrow()
+                );
+            }
+            let _ = storage.insert(token_ptr.borrow().body.clone().unwrap());
+            token_ptr_exceed(token_ptr);
+            continue;
+        }
+        let body = token_ptr.borrow().body.clone().unwrap();
+        *counts.entry(body).or_insert(0) += 1;
+        typ = if let Some(_typ) = specified_type(&counts) {
+            _typ
+        } else {
+            error_with_token!("不正な型指定子の組み合わせです。", &token_ptr.borrow());
+        };
+        token_ptr_exceed(token_ptr);
+    }
+    if counts.is_empty() {
+        error_with_token!("型指定子が必要です。", &token.borrow());
+    }
+    if quals.is_restrict {
+        error_with_token!("restrict はポインタ型にのみ指定できます。", &token.borrow());
+    }
+    typ.is_const = quals.is_const;
+    typ.is_volatile = quals.is_volatile;
+    typ.alignas = alignas;
+    Some((typ, storage))
+}
+
+/// 型指定子の出現回数から型を決定する(不正な組み合わせの場合は None)
+fn specified_type(counts: &HashMap<String, usize>) -> Option<TypeCell> {
+    let count = |spec: &str| *counts.get(spec).unwrap_or(&0);
+
+    // long double のみ、 long と double の組み合わせが許される
+    if counts.len() == 2 && count("long") == 1 && count("double") == 1 {
+        return Some(TypeCell::new(Type::LDouble));
+    }
+
+    // va_list は要素数 3 の long の配列(gcc の __va_list_tag[1] と同じサイズ・アラインメント)
+    if count("__builtin_va_list") > 0 {
+        return if counts.len() == 1 && count("__builtin_va_list") == 1 {
+            Some(TypeCell::new(Type::Long).make_array_of(3))
+        } else {
+            None
+        };
+    }
+
+    // void, _Bool, float, double は他の型指定子と組み合わせられない
+    for (spec, typ) in [
+        ("void", Type::Void),
+        ("_Bool", Type::Bool),
+        ("float", Type::Float),
+        ("double", Type::Double),
+    ] {
+        if count(spec) > 0 {
+            return if counts.len() == 1 && count(spec) == 1 {
+                Some(TypeCell::new(typ))
+            } else {
+                None
+            };
+        }
+    }
+
+    let is_unsigned = match (count("signed"), count("unsigned")) {
+        (0, 0) | (1, 0) => false,
+        (0, 1) => true,
+        _ => return None,
+    };
+    let base = match (count("char"), count("short"), count("int"), count("long")) {
+        (1, 0, 0, 0) => Type::Char,
+        (0, 1, 0, 0) | (0, 1, 1, 0) => Type::Short,
+        (0, 0, 0, 0) | (0, 0, 1, 0) => Type::Int,
+        (0, 0, 0, 1) | (0, 0, 1, 1) | (0, 0, 0, 2) | (0, 0, 1, 2) => Type::Long,
+        _ => return None,
+    };
+    let typ = TypeCell::new(base);
+    Some(if is_unsigned {
+        typ.make_unsigned()
+    } else {
+        typ
+    })
+}
+
 #[inline]
 pub fn expect_type(token_ptr: &mut TokenRef) -> TypeCell {
     if let Some(typ) = consume_type(token_ptr) {
@@ -550,6 +877,15 @@ pub fn expect_type(token_ptr: &mut TokenRef) -> TypeCell {
     }
 }
 
+#[inline]
+pub fn expect_decl_type(token_ptr: &mut TokenRef) -> (TypeCell, Option<String>) {
+    if let Some(decl_type) = consume_decl_type(token_ptr) {
+        decl_type
+    } else {
+        error_with_token!("型の指定が必要です。", &*token_ptr.borrow());
+    }
+}
+
 #[inline]
 pub fn is_kind(token_ptr: &mut TokenRef, kind: Tokenkind) -> bool {
     token_ptr.borrow().kind == kind
@@ -810,6 +1146,68 @@ mod tests {
         println!("{}", token_ptr.borrow());
     }
 
+    #[test]
+    fn int_types() {
+        let src: &str = "
+			unsigned char uc;
+			short int s;
+			long long ll;
+			signed sx;
+			x = 10ul + 3U + 5ll;
+		";
+        test_init(src);
+
+        let mut token_ptr: TokenRef = tokenize(0);
+        while token_ptr.borrow().kind != Tokenkind::EOFTk {
+            println!("{}", token_ptr.borrow());
+            token_ptr_exceed(&mut token_ptr);
+        }
+        assert_eq!(token_ptr.borrow().kind, Tokenkind::EOFTk);
+        println!("{}", token_ptr.borrow());
+    }
+
+    #[test]
+    fn float_literals() {
+        let src: &str = "
+			float f = 1.5f;
+			double d = .25 + 3. + 1e3 + 2.5E-3L;
+			long double ld = 1.1L;
+			double long dl;
+			x = 1.5 + 10;
+		";
+        test_init(src);
+
+        let mut token_ptr: TokenRef = tokenize(0);
+        while token_ptr.borrow().kind != Tokenkind::EOFTk {
+            println!("{}", token_ptr.borrow());
+            token_ptr_exceed(&mut token_ptr);
+        }
+        assert_eq!(token_ptr.borrow().kind, Tokenkind::EOFTk);
+        println!("{}", token_ptr.borrow());
+    }
+
+    #[test]
+    fn preprocess() {
+        let src: &str = "
+			#include <stdbool.h>
+			#define N 10
+			#define M (N + 1)
+			bool b = true;
+			int x[M];
+			#undef N
+			int N;
+		";
+        test_init(src);
+
+        let mut token_ptr: TokenRef = tokenize(0);
+        while token_ptr.borrow().kind != Tokenkind::EOFTk {
+            println!("{}", token_ptr.borrow());
+            token_ptr_exceed(&mut token_ptr);
+        }
+        assert_eq!(token_ptr.borrow().kind, Tokenkind::EOFTk);
+        println!("{}", token_ptr.borrow());
+    }
+
     #[test]
     fn array() {
         let src: &str = "
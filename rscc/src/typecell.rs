@@ -8,8 +8,15 @@ pub type TypeCellRef = Rc<RefCell<TypeCell>>;
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Type {
     Invalid, // デフォルトや無名ノードに割り当てる
-    Int,
+    Void,    // 不完全型なのでサイズを持たない
+    Bool,
     Char,
+    Short,
+    Int,
+    Long, // long long も同じサイズなので Long として扱う
+    Float,
+    Double,
+    LDouble, // x87 の 80bit 拡張倍精度(メモリ上では 16 バイト)
     Ptr,
     Func,
     Array,
@@ -20,8 +27,15 @@ impl Type {
     pub fn bytes(&self) -> usize {
         match self {
             Type::Invalid => panic!("cannot extract size of invalid type."),
+            Type::Void => panic!("cannot extract size of void type."),
+            Type::Bool => 1,
             Type::Char => 1,
+            Type::Short => 2,
             Type::Int => 4,
+            Type::Long => 8,
+            Type::Float => 4,
+            Type::Double => 8,
+            Type::LDouble => 16,
             Type::Ptr => 8,
             Type::Array => panic!("cannot infer size of array from only itself"),
             Type::Func => panic!("access to the size of function should not be implemented yet"),
@@ -33,8 +47,15 @@ impl Display for Type {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         let s: &str = match self {
             Type::Invalid => "invalid",
+            Type::Void => "void",
+            Type::Bool => "_Bool",
             Type::Char => "char",
+            Type::Short => "short",
             Type::Int => "int",
+            Type::Long => "long",
+            Type::Float => "float",
+            Type::Double => "double",
+            Type::LDouble => "long double",
             Type::Ptr => "pointer",
             Type::Array => "array",
             Type::Func => "function",
@@ -43,11 +64,20 @@ impl Display for Type {
     }
 }
 
+/// キャストの際に参照する、レジスタ上での値の表現
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum RawType {
     I8 = 0,
-    I32 = 1,
-    U64 = 2,
+    I16 = 1,
+    I32 = 2,
+    I64 = 3,
+    U8 = 4,
+    U16 = 5,
+    U32 = 6,
+    U64 = 7,
+    F32 = 8,
+    F64 = 9,
+    F80 = 10,
 }
 
 #[derive(Clone, Debug, Eq)] // PartialEq は別で実装
@@ -60,20 +90,33 @@ pub struct TypeCell {
     pub ptr_end: Option<Type>,
     pub chains: usize,
     pub array_size: Option<usize>,
+    // 可変長配列の場合、実行時に決まる要素数を保持する隠れたローカル変数のオフセット(array_size は 0 とする)
+    pub vla_len: Option<usize>,
 
     // self.typ == Type::Func
     pub ret_typ: Option<TypeCellRef>,
     pub arg_typs: Option<Vec<TypeCellRef>>,
     pub is_abstract: bool,
+    pub is_variadic: bool,     // 引数の最後が "..." の関数
+    pub is_unprototyped: bool, // "()" で宣言された、引数の情報を持たない関数
 
     pub is_unsigned: bool,
+
+    // 型修飾子: ポインタの各段ごとに持つ(const int *p なら p 自身ではなく ptr_to 側が const)
+    // なお、最適化を行わないので volatile なオブジェクトへのアクセスも常にそのままコードが出力される
+    pub is_const: bool,
+    pub is_volatile: bool,
+    pub is_restrict: bool,
+
+    // _Alignas で指定されたアラインメント(宣言されたオブジェクトの型にのみ付く)
+    pub alignas: Option<usize>,
 }
 
 impl TypeCell {
     #[inline]
     pub fn new(typ: Type) -> Self {
         let is_unsigned = match typ {
-            Type::Func | Type::Ptr => true,
+            Type::Func | Type::Ptr | Type::Bool => true,
             _ => false,
         };
         TypeCell {
@@ -93,6 +136,11 @@ impl TypeCell {
         self.typ != Type::Array
     }
 
+    #[inline]
+    pub fn is_void(&self) -> bool {
+        self.typ == Type::Void
+    }
+
     #[inline]
     pub fn is_pointer(&self) -> bool {
         self.typ == Type::Ptr
@@ -117,6 +165,7 @@ impl TypeCell {
             ptr_to: ptr_to,
             ptr_end: ptr_end,
             chains: chains,
+            is_unsigned: true,
             ..Default::default()
         }
     }
@@ -186,15 +235,94 @@ impl TypeCell {
         }
     }
 
+    // ptr_end に対応する型を ptr_to を辿って取得する(unsigned などの情報を落とさないため、 Self::new(ptr_end) とはしない)
     #[inline]
     pub fn get_base_cell(&self) -> Self {
         if let Some(_typ) = self.ptr_end {
-            Self::new(_typ)
+            let mut cell = self.make_deref().unwrap();
+            while cell.typ != _typ {
+                cell = cell.make_deref().unwrap();
+            }
+            cell
         } else {
             panic!("cannot extract base type from non-pointer.");
         }
     }
 
+    #[inline]
+    pub fn is_integer(&self) -> bool {
+        self.is_one_of(&[Type::Bool, Type::Char, Type::Short, Type::Int, Type::Long])
+    }
+
+    #[inline]
+    pub fn is_floating(&self) -> bool {
+        self.is_one_of(&[Type::Float, Type::Double, Type::LDouble])
+    }
+
+    // 整数型の変換順位(C11 6.3.1.1): long long は long と同一視する
+    #[inline]
+    pub fn int_rank(&self) -> usize {
+        match self.typ {
+            Type::Bool => 0,
+            Type::Char => 1,
+            Type::Short => 2,
+            Type::Int => 3,
+            Type::Long => 4,
+            _ => panic!("cannot get integer conversion rank of {}.", self.typ),
+        }
+    }
+
+    /// 修飾子を外した型(右辺値の型や、修飾子の比較に用いる)
+    #[inline]
+    pub fn unqualified(&self) -> Self {
+        TypeCell {
+            is_const: false,
+            is_volatile: false,
+            is_restrict: false,
+            ..self.clone()
+        }
+    }
+
+    /// other の持つ修飾子を全て持っているかどうか
+    #[inline]
+    pub fn has_qualifiers_of(&self, other: &Self) -> bool {
+        (self.is_const || !other.is_const)
+            && (self.is_volatile || !other.is_volatile)
+            && (self.is_restrict || !other.is_restrict)
+    }
+
+    fn qualifier_string(&self) -> String {
+        let mut s = String::new();
+        for (has, qual) in [
+            (self.is_const, "const "),
+            (self.is_volatile, "volatile "),
+            (self.is_restrict, "restrict "),
+        ] {
+            if has {
+                s.push_str(qual);
+            }
+        }
+        s
+    }
+
+    #[inline]
+    pub fn make_unsigned(&self) -> Self {
+        TypeCell {
+            is_unsigned: true,
+            ..self.clone()
+        }
+    }
+
+    // 整数拡張: int より順位の低い型は(unsigned であっても int で全ての値を表せるので) int になる
+    #[inline]
+    pub fn int_promoted(&self) -> Self {
+        if self.is_integer() && self.int_rank() < TypeCell::new(Type::Int).int_rank() {
+            TypeCell::new(Type::Int)
+        } else {
+            self.clone()
+        }
+    }
+
     #[inline]
     pub fn make_func(&self, arg_typs: Vec<TypeCellRef>) -> Self {
         let _ret_typ = Some(Rc::new(RefCell::new(self.clone())));
@@ -213,6 +341,11 @@ impl TypeCell {
         dim.iter().product::<usize>()
     }
 
+    /// 可変長配列(いずれかの次元の要素数が実行時に決まる配列)かどうか
+    pub fn is_vla(&self) -> bool {
+        self.typ == Type::Array && (self.vla_len.is_some() || self.make_deref().unwrap().is_vla())
+    }
+
     #[inline]
     pub fn is_char_1d_array(&self) -> bool {
         self.typ == Type::Array && self.make_deref().unwrap().typ == Type::Char
@@ -228,6 +361,24 @@ impl TypeCell {
         }
     }
 
+    /// 型のアラインメント(_Alignof の値): 配列は要素のアラインメントに従う
+    pub fn align(&self) -> usize {
+        let natural = match self.typ {
+            Type::Array => self.make_deref().unwrap().align(),
+            _ => self.typ.bytes(),
+        };
+        natural.max(self.alignas.unwrap_or(1))
+    }
+
+    /// 変数として領域を確保する際のアラインメント: x86-64 ABI に従い、16 bytes 以上の配列は 16 bytes に揃える
+    pub fn var_align(&self) -> usize {
+        if self.is_array() && !self.is_flex_array() && self.bytes() >= 16 {
+            self.align().max(16)
+        } else {
+            self.align()
+        }
+    }
+
     pub fn get_last_level_array(&self) -> Option<TypeCell> {
         let (dim, typ) = self.array_dim();
         if let Some(d) = dim.last() {
@@ -241,7 +392,9 @@ impl TypeCell {
         let s = s.into();
         if let Some(deref) = &self.ptr_to {
             let string = if self.typ == Type::Array {
-                if let Some(size) = self.array_size {
+                if self.vla_len.is_some() {
+                    format!("{}[*]", s)
+                } else if let Some(size) = self.array_size {
                     format!("{}[{}]", s, size)
                 } else {
                     format!("{}[]", s)
@@ -249,7 +402,7 @@ impl TypeCell {
             } else if deref.borrow().is_array() {
                 format!("({}*)", s)
             } else {
-                format!("*{}", s)
+                format!("*{}{}", self.qualifier_string(), s)
             };
             (*deref).borrow().get_type_string(string)
         } else if self.typ == Type::Func {
@@ -262,9 +415,14 @@ impl TypeCell {
                     format!("{}, {}", args_str, arg.borrow())
                 };
             }
+            if self.is_variadic {
+                args_str = format!("{}, ...", args_str);
+            }
             format!("{} ({}func)({})", ret_typ, s, args_str)
+        } else if self.is_integer() && self.is_unsigned && self.typ != Type::Bool {
+            format!("{}unsigned {}{}", self.qualifier_string(), self.typ, s)
         } else {
-            format!("{}{}", self.typ, s)
+            format!("{}{}{}", self.qualifier_string(), self.typ, s)
         }
     }
 }
@@ -277,17 +435,24 @@ impl Default for TypeCell {
             ptr_end: None,
             chains: 0,
             array_size: None,
+            vla_len: None,
             arg_typs: None,
             ret_typ: None,
             is_abstract: false,
+            is_variadic: false,
+            is_unprototyped: false,
             is_unsigned: false,
+            is_const: false,
+            is_volatile: false,
+            is_restrict: false,
+            alignas: None,
         }
     }
 }
 
 impl Display for TypeCell {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", self.get_type_string(""))
+        write!(f, "{}", self.get_type_string("").trim_end())
     }
 }
 
@@ -304,31 +469,91 @@ impl PartialEq for TypeCell {
             }
         } else {
             self.typ == other.typ
+                && self.is_unsigned == other.is_unsigned
                 && self.ret_typ == other.ret_typ
-                && self.arg_typs == other.arg_typs
+                // 引数の情報を持たない関数型は、戻り値の型が同じ関数型と互換とみなす
+                && (self.is_unprototyped
+                    || other.is_unprototyped
+                    || (self.arg_typs == other.arg_typs && self.is_variadic == other.is_variadic))
         }
     }
 }
 
 // 計算時、代入時などに暗黙のキャストを行うための処理
+// 計算結果は左辺値ではないので、修飾子は外しておく
 pub fn get_common_type(left_typ: &TypeCell, right_typ: &TypeCell) -> TypeCell {
-    // 現在はポインタ、 int, char しかサポートされていない
+    common_type(left_typ, right_typ).unqualified()
+}
+
+fn common_type(left_typ: &TypeCell, right_typ: &TypeCell) -> TypeCell {
+    // 関数は関数へのポインタとして扱う
+    if left_typ.typ == Type::Func {
+        return left_typ.make_ptr_to();
+    } else if right_typ.typ == Type::Func {
+        return right_typ.make_ptr_to();
+    }
     // 右側"のみ"がポインタになることはない(そのようなノード生成が起きる前にエラーになる)ことに注意
     if let Some(_typ) = &left_typ.ptr_to {
-        _typ.borrow().make_ptr_to()
+        return _typ.borrow().make_ptr_to();
     } else if let Some(_typ) = &right_typ.ptr_to {
-        _typ.borrow().make_ptr_to()
+        return _typ.borrow().make_ptr_to();
+    }
+
+    // 通常の算術型変換(C11 6.3.1.8): 浮動小数点型が含まれる場合は、よりサイズの大きい浮動小数点型
+    match (left_typ.is_floating(), right_typ.is_floating()) {
+        (true, true) => {
+            return if left_typ.bytes() >= right_typ.bytes() {
+                left_typ.clone()
+            } else {
+                right_typ.clone()
+            };
+        }
+        (true, false) => return left_typ.clone(),
+        (false, true) => return right_typ.clone(),
+        _ => {}
+    }
+
+    // 整数型同士の場合は、まず両辺に整数拡張を行う
+    let left_typ = left_typ.int_promoted();
+    let right_typ = right_typ.int_promoted();
+    if left_typ.is_unsigned == right_typ.is_unsigned {
+        // 符号の有無が同じなら順位の高い方
+        return if left_typ.int_rank() >= right_typ.int_rank() {
+            left_typ
+        } else {
+            right_typ
+        };
+    }
+
+    let (unsigned_typ, signed_typ) = if left_typ.is_unsigned {
+        (left_typ, right_typ)
+    } else {
+        (right_typ, left_typ)
+    };
+    if unsigned_typ.int_rank() >= signed_typ.int_rank() {
+        unsigned_typ
+    } else if signed_typ.bytes() > unsigned_typ.bytes() {
+        // 符号付きの型で符号なしの型の値を全て表現できる場合
+        signed_typ
     } else {
-        // int 以下のサイズの数は全て int にキャストされる
-        TypeCell::new(Type::Int)
+        signed_typ.make_unsigned()
     }
 }
 
-pub fn get_raw_type(typ: Type) -> RawType {
-    match typ {
-        Type::Invalid => panic!("cannot extract raw type from {}.", typ),
-        Type::Char => RawType::I8,
-        Type::Int => RawType::I32,
+pub fn get_raw_type(typ: &TypeCell) -> RawType {
+    match (typ.typ, typ.is_unsigned) {
+        (Type::Invalid, _) => panic!("cannot extract raw type from {}.", typ.typ),
+        (Type::Bool, _) => RawType::U8,
+        (Type::Char, false) => RawType::I8,
+        (Type::Char, true) => RawType::U8,
+        (Type::Short, false) => RawType::I16,
+        (Type::Short, true) => RawType::U16,
+        (Type::Int, false) => RawType::I32,
+        (Type::Int, true) => RawType::U32,
+        (Type::Long, false) => RawType::I64,
+        (Type::Float, _) => RawType::F32,
+        (Type::Double, _) => RawType::F64,
+        (Type::LDouble, _) => RawType::F80,
         _ => RawType::U64,
     }
 }
@@ -371,4 +596,77 @@ mod tests {
 
         assert_eq!(t1, t2);
     }
+
+    #[test]
+    fn qualifier_test() {
+        let mut int = TypeCell::new(Type::Int);
+        int.is_const = true;
+        let mut ptr = int.make_ptr_to();
+        assert!(!ptr.is_const);
+        assert!(ptr.make_deref().unwrap().is_const);
+        ptr.is_volatile = true;
+        assert_eq!(format!("{}", ptr), "const int*volatile");
+
+        // 修飾子の違いは型の比較では無視する
+        assert_eq!(ptr, TypeCell::new(Type::Int).make_ptr_to());
+        assert!(ptr.make_deref().unwrap().has_qualifiers_of(&int));
+        assert!(!TypeCell::new(Type::Int).has_qualifiers_of(&int));
+        assert!(!get_common_type(&int, &int).is_const);
+    }
+
+    #[test]
+    fn align_test() {
+        assert_eq!(TypeCell::new(Type::Char).align(), 1);
+        assert_eq!(TypeCell::new(Type::LDouble).align(), 16);
+        let arr = TypeCell::new(Type::Int).make_array_of(5);
+        assert_eq!(arr.align(), 4);
+        assert_eq!(arr.var_align(), 16);
+        assert_eq!(TypeCell::new(Type::Char).make_array_of(3).var_align(), 1);
+
+        let mut int = TypeCell::new(Type::Int);
+        int.alignas = Some(32);
+        assert_eq!(int.align(), 32);
+        assert_eq!(int, TypeCell::new(Type::Int));
+    }
+
+    #[test]
+    fn common_type_test() {
+        let char_ = TypeCell::new(Type::Char);
+        let short = TypeCell::new(Type::Short);
+        let int = TypeCell::new(Type::Int);
+        let long = TypeCell::new(Type::Long);
+
+        // int より小さい型は int に拡張される
+        assert_eq!(get_common_type(&char_, &short), int);
+        assert_eq!(
+            get_common_type(&char_.make_unsigned(), &short.make_unsigned()),
+            int
+        );
+        assert_eq!(get_common_type(&int, &long), long);
+
+        // 符号の有無が異なる場合
+        assert_eq!(
+            get_common_type(&int, &int.make_unsigned()),
+            int.make_unsigned()
+        );
+        assert_eq!(get_common_type(&long, &int.make_unsigned()), long);
+        assert_eq!(
+            get_common_type(&long.make_unsigned(), &int),
+            long.make_unsigned()
+        );
+
+        // ポインタが含まれる場合はポインタの型
+        let ptr = int.make_ptr_to();
+        assert_eq!(get_common_type(&ptr, &long), ptr);
+
+        // 浮動小数点型が含まれる場合は浮動小数点型
+        let float = TypeCell::new(Type::Float);
+        let double = TypeCell::new(Type::Double);
+        assert_eq!(get_common_type(&long.make_unsigned(), &float), float);
+        assert_eq!(get_common_type(&float, &double), double);
+        assert_eq!(get_common_type(&double, &char_), double);
+        let ldouble = TypeCell::new(Type::LDouble);
+        assert_eq!(get_common_type(&double, &ldouble), ldouble);
+        assert_eq!(get_common_type(&ldouble, &float), ldouble);
+    }
 }
@@ -48,14 +48,14 @@ pub fn is_digit(c: &char) -> bool {
 }
 
 // 数字を読みつつindexを進める
-pub fn strtol(string: &Vec<char>, index: &mut usize) -> u32 {
+pub fn strtol(string: &Vec<char>, index: &mut usize) -> u64 {
     let mut c = string[*index];
     let mut val = 0;
     let limit = string.len();
 
-    // 数字を読む限りu32として加える
+    // 数字を読む限りu64として加える
     while is_digit(&c) {
-        val = val * 10 + (c.to_digit(10).unwrap() - '0'.to_digit(10).unwrap());
+        val = val * 10 + (c.to_digit(10).unwrap() - '0'.to_digit(10).unwrap()) as u64;
         *index += 1;
 
         // 最後に到達した場合は処理を終える
@@ -68,9 +68,29 @@ pub fn strtol(string: &Vec<char>, index: &mut usize) -> u32 {
 }
 
 pub const RED: usize = 31;
+pub const MAGENTA: usize = 35;
 pub const LIGHTBLUE: usize = 36;
 /// エラー位置を報告し、exit_eprintln! する関数
 pub fn error_at(msg: &str, file_num: usize, line_num: usize, line_offset: usize) -> ! {
+    report_at("Compile Error", RED, msg, file_num, line_num, line_offset);
+    exit_eprintln!();
+}
+
+/// 警告を報告する関数: error_at と異なりコンパイルは続行する
+pub fn warn_at(msg: &str, file_num: usize, line_num: usize, line_offset: usize) {
+    report_at("Warning", MAGENTA, msg, file_num, line_num, line_offset);
+    eprintln!();
+}
+
+/// 位置情報とコード行、メッセージを表示する (末尾の改行は呼び出し側で出力する)
+fn report_at(
+    kind: &str,
+    color: usize,
+    msg: &str,
+    file_num: usize,
+    line_num: usize,
+    line_offset: usize,
+) {
     // ファイル名には今のところこの関数でしかアクセスしないので、デッドロックの検査はしない
     let file_name = &FILE_NAMES.try_lock().unwrap()[file_num];
 
@@ -82,13 +102,13 @@ pub fn error_at(msg: &str, file_num: usize, line_num: usize, line_offset: usize)
                 .map(|c| if c == '\t' { '\t' } else { ' ' })
                 .collect::<String>();
             let space = &all_space[..line_offset];
-            eprintln!("\x1b[{}mrscc: Compile Error\x1b[m", RED);
+            eprintln!("\x1b[{}mrscc: {}\x1b[m", color, kind);
             eprintln!(
                 "\x1b[{}m{}:{}:{}\x1b[m",
                 LIGHTBLUE, file_name, line_num, line_offset
             );
             eprint!("{}", code_line); // code_line には \n が含まれるので eprint! を使う
-            exit_eprintln!("{}\x1b[{}m^\x1b[m {}", space, RED, msg);
+            eprint!("{}\x1b[{}m^\x1b[m {}", space, color, msg);
         }
         // ここのエラーが出ないように SRC の lock をとった状態でエラー関係の関数やマクロを呼ばないことにする
         Err(e) => {
@@ -0,0 +1,87 @@
+use std::fs::{remove_file, File};
+use std::io::{self, Write};
+use std::process::{Command, ExitStatus};
+
+const RSCC: &str = env!("CARGO_BIN_EXE_rscc");
+const CMP: &str = "tests/utils/cmp.sh";
+
+/// gcc でコンパイルした結果と出力を比較するテスト: (tests/utils 以下のソース, 確かめる内容)
+const CASES: [(&str, &str); 22] = [
+    ("arith.c", "各整数型の組み合わせの計算"),
+    ("float.c", "float, double の計算や整数との変換"),
+    ("ldouble.c", "long double の計算や他の型との変換、引数の受け渡し"),
+    ("qualifier.c", "const, volatile, restrict で修飾された変数やポインタ"),
+    ("declarator.c", "括弧を含む宣言子や抽象宣言子で宣言した変数・型"),
+    ("funcptr.c", "関数ポインタの代入や、関数ポインタを介した呼び出し"),
+    ("cast.c", "整数・浮動小数点数・ポインタの間の明示的なキャスト"),
+    ("storage.c", "static な変数・関数と extern 宣言"),
+    ("tentative.c", "仮定義の統合と共通シンボルの出力"),
+    ("align.c", "変数のアラインメントと _Alignas, _Alignof"),
+    ("vla.c", "可変長配列と __builtin_alloca"),
+    ("designated.c", "配列の designator による初期化"),
+    ("compound.c", "複合リテラル"),
+    ("jump.c", "break, continue"),
+    ("dowhile.c", "do-while 文"),
+    ("switch.c", "switch 文"),
+    ("goto.c", "goto とラベル"),
+    ("conditional.c", "条件演算子"),
+    ("return.c", "関数の return 文の解析"),
+    ("stackargs.c", "7つ以上の引数をスタックで渡す関数呼び出し"),
+    ("stdarg.c", "可変長引数をとる関数の定義・呼び出しと stdarg.h"),
+    ("unprototyped.c", "引数の情報を持たない関数宣言とポインタを返す外部の関数の呼び出し"),
+];
+
+macro_rules! cprintln {
+	($fmt:expr, $color:expr) => {
+		println!(concat!("\x1b[{}m", $fmt, "\x1b[m"), $color);
+	};
+
+	($fmt:expr, $color:expr, $($args:tt)*) => {
+		println!(concat!("\x1b[{}m", $fmt, "\x1b[m"), $color, $($args)*);
+	};
+}
+
+/// 各ソースについて、 gcc と同じ結果になるかを確かめる
+#[test]
+pub fn cmp_test() {
+    let mut failed = vec![];
+    for (src, about) in CASES {
+        cprintln!("{}: {}", 36, src, about);
+        if !cmp(src) {
+            failed.push(src);
+        }
+    }
+    assert!(failed.is_empty(), "failed: {:?}", failed);
+    cprintln!("test succeeded!", 36);
+}
+
+/// rscc はグローバル変数に状態を持つので、ソースごとに別のプロセスでコンパイルする
+fn cmp(src: &str) -> bool {
+    let src = format!("tests/utils/{}", src);
+    let asm = src.replace("utils/", "").replace(".c", "_tmp.s");
+    let output = Command::new(RSCC).arg(&src).output().unwrap();
+    if !output.status.success() {
+        io::stderr().write_all(&output.stderr).unwrap();
+        return false;
+    }
+    assert!(output_asm(&asm, &output.stdout).is_ok());
+
+    let status = exec_cmp(&asm, &src);
+    assert!(remove_file(&asm).is_ok());
+    status.is_ok() && status.unwrap().success()
+}
+
+fn output_asm(asm: &str, body: &[u8]) -> io::Result<()> {
+    let mut f = File::create(asm)?;
+    f.write_all(body)?;
+    Ok(())
+}
+
+fn exec_cmp(asm: &str, src: &str) -> io::Result<ExitStatus> {
+    let output = Command::new(CMP).arg(asm).arg(src).output()?;
+    cprintln!("diff follows {}", 32, ">".repeat(42));
+    io::stdout().write_all(&output.stdout).unwrap();
+    io::stderr().write_all(&output.stderr).unwrap();
+    cprintln!("diff end {}", 32, "<".repeat(46));
+    Ok(output.status)
+}
@@ -0,0 +1,80 @@
+/*
+ * 変数のアラインメントと _Alignas, _Alignof のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+_Alignas(32) int g_aligned;
+_Alignas(long) char g_char = 1;
+char g_small[3];
+int g_arr[5];
+long double g_ld;
+_Alignas(64) int g_init[2] = {1, 2};
+
+int aligned(void *p, long align) { return (long)p % align == 0; }
+
+int locals() {
+	char c1 = 1;
+	int i1 = 2;
+	char c2 = 3;
+	short s1 = 4;
+	double d1 = 5.0;
+	char c3 = 6;
+	long l1 = 7;
+	print_helper(aligned(&i1, 4));
+	print_helper(aligned(&s1, 2));
+	print_helper(aligned(&d1, 8));
+	print_helper(aligned(&l1, 8));
+	return c1 + i1 + c2 + s1 + (int)d1 + c3 + l1;
+}
+
+int over_aligned() {
+	char c = 1;
+	_Alignas(16) char buf[3];
+	_Alignas(8) int x = 5;
+	int _Alignas(16) y = 6;
+	_Alignas(double) short z = 7;
+	long double ld = 8;
+	char arr[20];
+	print_helper(aligned(buf, 16));
+	print_helper(aligned(&x, 8));
+	print_helper(aligned(&y, 16));
+	print_helper(aligned(&z, 8));
+	print_helper(aligned(&ld, 16));
+	print_helper(aligned(arr, 16));
+	buf[0] = 1;
+	arr[19] = 2;
+	return c + buf[0] + x + y + z + (int)ld + arr[19];
+}
+
+int deep(int n) {
+	char pad = 1;
+	_Alignas(16) long v = n;
+	if (n == 0)
+		return aligned(&v, 16);
+	return deep(n - 1) + aligned(&v, 16) + pad - 1;
+}
+
+int main() {
+	print_helper(_Alignof(char));
+	print_helper(_Alignof(short));
+	print_helper(_Alignof(int));
+	print_helper(_Alignof(long));
+	print_helper(_Alignof(float));
+	print_helper(_Alignof(double));
+	print_helper(_Alignof(long double));
+	print_helper(_Alignof(int *));
+	print_helper(_Alignof(char[10]));
+	print_helper(_Alignof(int[3][4]));
+	print_helper(_Alignof(int (*)(int)));
+	print_helper(sizeof(_Alignof(int)));
+	print_helper(aligned(&g_aligned, 32));
+	print_helper(aligned(&g_char, 8));
+	print_helper(aligned(g_arr, 16));
+	print_helper(aligned(&g_ld, 16));
+	print_helper(aligned(g_init, 64));
+	print_helper(g_char + g_init[1]);
+	print_helper(locals());
+	print_helper(over_aligned());
+	print_helper(deep(5));
+	return 0;
+}
@@ -0,0 +1,3516 @@
+/*
+ * 整数拡張と通常の算術型変換のテスト: gcc でコンパイルした結果と出力を比較する
+ * tests/utils/gen_arith.py で生成しているので、直接編集しないこと
+ */
+int print_helper(long long x);
+
+int binary0() {
+	char a_c;
+	char b_c;
+	a_c = -7;
+	b_c = 3;
+	unsigned char a_uc;
+	unsigned char b_uc;
+	a_uc = -7;
+	b_uc = 3;
+	short a_s;
+	short b_s;
+	a_s = -7;
+	b_s = 3;
+	unsigned short a_us;
+	unsigned short b_us;
+	a_us = -7;
+	b_us = 3;
+	int a_i;
+	int b_i;
+	a_i = -7;
+	b_i = 3;
+	unsigned int a_ui;
+	unsigned int b_ui;
+	a_ui = -7;
+	b_ui = 3;
+	long a_l;
+	long b_l;
+	a_l = -7;
+	b_l = 3;
+	unsigned long a_ul;
+	unsigned long b_ul;
+	a_ul = -7;
+	b_ul = 3;
+	print_helper(a_c + b_c);
+	print_helper(a_c - b_c);
+	print_helper(a_c * b_c);
+	print_helper(a_c / b_c);
+	print_helper(a_c % b_c);
+	print_helper(a_c & b_c);
+	print_helper(a_c | b_c);
+	print_helper(a_c ^ b_c);
+	print_helper(a_c == b_c);
+	print_helper(a_c != b_c);
+	print_helper(a_c < b_c);
+	print_helper(a_c <= b_c);
+	print_helper(a_c > b_c);
+	print_helper(a_c >= b_c);
+	print_helper(a_c + b_uc);
+	print_helper(a_c - b_uc);
+	print_helper(a_c * b_uc);
+	print_helper(a_c / b_uc);
+	print_helper(a_c % b_uc);
+	print_helper(a_c & b_uc);
+	print_helper(a_c | b_uc);
+	print_helper(a_c ^ b_uc);
+	print_helper(a_c == b_uc);
+	print_helper(a_c != b_uc);
+	print_helper(a_c < b_uc);
+	print_helper(a_c <= b_uc);
+	print_helper(a_c > b_uc);
+	print_helper(a_c >= b_uc);
+	print_helper(a_c + b_s);
+	print_helper(a_c - b_s);
+	print_helper(a_c * b_s);
+	print_helper(a_c / b_s);
+	print_helper(a_c % b_s);
+	print_helper(a_c & b_s);
+	print_helper(a_c | b_s);
+	print_helper(a_c ^ b_s);
+	print_helper(a_c == b_s);
+	print_helper(a_c != b_s);
+	print_helper(a_c < b_s);
+	print_helper(a_c <= b_s);
+	print_helper(a_c > b_s);
+	print_helper(a_c >= b_s);
+	print_helper(a_c + b_us);
+	print_helper(a_c - b_us);
+	print_helper(a_c * b_us);
+	print_helper(a_c / b_us);
+	print_helper(a_c % b_us);
+	print_helper(a_c & b_us);
+	print_helper(a_c | b_us);
+	print_helper(a_c ^ b_us);
+	print_helper(a_c == b_us);
+	print_helper(a_c != b_us);
+	print_helper(a_c < b_us);
+	print_helper(a_c <= b_us);
+	print_helper(a_c > b_us);
+	print_helper(a_c >= b_us);
+	print_helper(a_c + b_i);
+	print_helper(a_c - b_i);
+	print_helper(a_c * b_i);
+	print_helper(a_c / b_i);
+	print_helper(a_c % b_i);
+	print_helper(a_c & b_i);
+	print_helper(a_c | b_i);
+	print_helper(a_c ^ b_i);
+	print_helper(a_c == b_i);
+	print_helper(a_c != b_i);
+	print_helper(a_c < b_i);
+	print_helper(a_c <= b_i);
+	print_helper(a_c > b_i);
+	print_helper(a_c >= b_i);
+	print_helper(a_c + b_ui);
+	print_helper(a_c - b_ui);
+	print_helper(a_c * b_ui);
+	print_helper(a_c / b_ui);
+	print_helper(a_c % b_ui);
+	print_helper(a_c & b_ui);
+	print_helper(a_c | b_ui);
+	print_helper(a_c ^ b_ui);
+	print_helper(a_c == b_ui);
+	print_helper(a_c != b_ui);
+	print_helper(a_c < b_ui);
+	print_helper(a_c <= b_ui);
+	print_helper(a_c > b_ui);
+	print_helper(a_c >= b_ui);
+	print_helper(a_c + b_l);
+	print_helper(a_c - b_l);
+	print_helper(a_c * b_l);
+	print_helper(a_c / b_l);
+	print_helper(a_c % b_l);
+	print_helper(a_c & b_l);
+	print_helper(a_c | b_l);
+	print_helper(a_c ^ b_l);
+	print_helper(a_c == b_l);
+	print_helper(a_c != b_l);
+	print_helper(a_c < b_l);
+	print_helper(a_c <= b_l);
+	print_helper(a_c > b_l);
+	print_helper(a_c >= b_l);
+	print_helper(a_c + b_ul);
+	print_helper(a_c - b_ul);
+	print_helper(a_c * b_ul);
+	print_helper(a_c / b_ul);
+	print_helper(a_c % b_ul);
+	print_helper(a_c & b_ul);
+	print_helper(a_c | b_ul);
+	print_helper(a_c ^ b_ul);
+	print_helper(a_c == b_ul);
+	print_helper(a_c != b_ul);
+	print_helper(a_c < b_ul);
+	print_helper(a_c <= b_ul);
+	print_helper(a_c > b_ul);
+	print_helper(a_c >= b_ul);
+	print_helper(a_uc + b_c);
+	print_helper(a_uc - b_c);
+	print_helper(a_uc * b_c);
+	print_helper(a_uc / b_c);
+	print_helper(a_uc % b_c);
+	print_helper(a_uc & b_c);
+	print_helper(a_uc | b_c);
+	print_helper(a_uc ^ b_c);
+	print_helper(a_uc == b_c);
+	print_helper(a_uc != b_c);
+	print_helper(a_uc < b_c);
+	print_helper(a_uc <= b_c);
+	print_helper(a_uc > b_c);
+	print_helper(a_uc >= b_c);
+	print_helper(a_uc + b_uc);
+	print_helper(a_uc - b_uc);
+	print_helper(a_uc * b_uc);
+	print_helper(a_uc / b_uc);
+	print_helper(a_uc % b_uc);
+	print_helper(a_uc & b_uc);
+	print_helper(a_uc | b_uc);
+	print_helper(a_uc ^ b_uc);
+	print_helper(a_uc == b_uc);
+	print_helper(a_uc != b_uc);
+	print_helper(a_uc < b_uc);
+	print_helper(a_uc <= b_uc);
+	print_helper(a_uc > b_uc);
+	print_helper(a_uc >= b_uc);
+	print_helper(a_uc + b_s);
+	print_helper(a_uc - b_s);
+	print_helper(a_uc * b_s);
+	print_helper(a_uc / b_s);
+	print_helper(a_uc % b_s);
+	print_helper(a_uc & b_s);
+	print_helper(a_uc | b_s);
+	print_helper(a_uc ^ b_s);
+	print_helper(a_uc == b_s);
+	print_helper(a_uc != b_s);
+	print_helper(a_uc < b_s);
+	print_helper(a_uc <= b_s);
+	print_helper(a_uc > b_s);
+	print_helper(a_uc >= b_s);
+	print_helper(a_uc + b_us);
+	print_helper(a_uc - b_us);
+	print_helper(a_uc * b_us);
+	print_helper(a_uc / b_us);
+	print_helper(a_uc % b_us);
+	print_helper(a_uc & b_us);
+	print_helper(a_uc | b_us);
+	print_helper(a_uc ^ b_us);
+	print_helper(a_uc == b_us);
+	print_helper(a_uc != b_us);
+	print_helper(a_uc < b_us);
+	print_helper(a_uc <= b_us);
+	print_helper(a_uc > b_us);
+	print_helper(a_uc >= b_us);
+	print_helper(a_uc + b_i);
+	print_helper(a_uc - b_i);
+	print_helper(a_uc * b_i);
+	print_helper(a_uc / b_i);
+	print_helper(a_uc % b_i);
+	print_helper(a_uc & b_i);
+	print_helper(a_uc | b_i);
+	print_helper(a_uc ^ b_i);
+	print_helper(a_uc == b_i);
+	print_helper(a_uc != b_i);
+	print_helper(a_uc < b_i);
+	print_helper(a_uc <= b_i);
+	print_helper(a_uc > b_i);
+	print_helper(a_uc >= b_i);
+	print_helper(a_uc + b_ui);
+	print_helper(a_uc - b_ui);
+	print_helper(a_uc * b_ui);
+	print_helper(a_uc / b_ui);
+	print_helper(a_uc % b_ui);
+	print_helper(a_uc & b_ui);
+	print_helper(a_uc | b_ui);
+	print_helper(a_uc ^ b_ui);
+	print_helper(a_uc == b_ui);
+	print_helper(a_uc != b_ui);
+	print_helper(a_uc < b_ui);
+	print_helper(a_uc <= b_ui);
+	print_helper(a_uc > b_ui);
+	print_helper(a_uc >= b_ui);
+	print_helper(a_uc + b_l);
+	print_helper(a_uc - b_l);
+	print_helper(a_uc * b_l);
+	print_helper(a_uc / b_l);
+	print_helper(a_uc % b_l);
+	print_helper(a_uc & b_l);
+	print_helper(a_uc | b_l);
+	print_helper(a_uc ^ b_l);
+	print_helper(a_uc == b_l);
+	print_helper(a_uc != b_l);
+	print_helper(a_uc < b_l);
+	print_helper(a_uc <= b_l);
+	print_helper(a_uc > b_l);
+	print_helper(a_uc >= b_l);
+	print_helper(a_uc + b_ul);
+	print_helper(a_uc - b_ul);
+	print_helper(a_uc * b_ul);
+	print_helper(a_uc / b_ul);
+	print_helper(a_uc % b_ul);
+	print_helper(a_uc & b_ul);
+	print_helper(a_uc | b_ul);
+	print_helper(a_uc ^ b_ul);
+	print_helper(a_uc == b_ul);
+	print_helper(a_uc != b_ul);
+	print_helper(a_uc < b_ul);
+	print_helper(a_uc <= b_ul);
+	print_helper(a_uc > b_ul);
+	print_helper(a_uc >= b_ul);
+	print_helper(a_s + b_c);
+	print_helper(a_s - b_c);
+	print_helper(a_s * b_c);
+	print_helper(a_s / b_c);
+	print_helper(a_s % b_c);
+	print_helper(a_s & b_c);
+	print_helper(a_s | b_c);
+	print_helper(a_s ^ b_c);
+	print_helper(a_s == b_c);
+	print_helper(a_s != b_c);
+	print_helper(a_s < b_c);
+	print_helper(a_s <= b_c);
+	print_helper(a_s > b_c);
+	print_helper(a_s >= b_c);
+	print_helper(a_s + b_uc);
+	print_helper(a_s - b_uc);
+	print_helper(a_s * b_uc);
+	print_helper(a_s / b_uc);
+	print_helper(a_s % b_uc);
+	print_helper(a_s & b_uc);
+	print_helper(a_s | b_uc);
+	print_helper(a_s ^ b_uc);
+	print_helper(a_s == b_uc);
+	print_helper(a_s != b_uc);
+	print_helper(a_s < b_uc);
+	print_helper(a_s <= b_uc);
+	print_helper(a_s > b_uc);
+	print_helper(a_s >= b_uc);
+	print_helper(a_s + b_s);
+	print_helper(a_s - b_s);
+	print_helper(a_s * b_s);
+	print_helper(a_s / b_s);
+	print_helper(a_s % b_s);
+	print_helper(a_s & b_s);
+	print_helper(a_s | b_s);
+	print_helper(a_s ^ b_s);
+	print_helper(a_s == b_s);
+	print_helper(a_s != b_s);
+	print_helper(a_s < b_s);
+	print_helper(a_s <= b_s);
+	print_helper(a_s > b_s);
+	print_helper(a_s >= b_s);
+	print_helper(a_s + b_us);
+	print_helper(a_s - b_us);
+	print_helper(a_s * b_us);
+	print_helper(a_s / b_us);
+	print_helper(a_s % b_us);
+	print_helper(a_s & b_us);
+	print_helper(a_s | b_us);
+	print_helper(a_s ^ b_us);
+	print_helper(a_s == b_us);
+	print_helper(a_s != b_us);
+	print_helper(a_s < b_us);
+	print_helper(a_s <= b_us);
+	print_helper(a_s > b_us);
+	print_helper(a_s >= b_us);
+	print_helper(a_s + b_i);
+	print_helper(a_s - b_i);
+	print_helper(a_s * b_i);
+	print_helper(a_s / b_i);
+	print_helper(a_s % b_i);
+	print_helper(a_s & b_i);
+	print_helper(a_s | b_i);
+	print_helper(a_s ^ b_i);
+	print_helper(a_s == b_i);
+	print_helper(a_s != b_i);
+	print_helper(a_s < b_i);
+	print_helper(a_s <= b_i);
+	print_helper(a_s > b_i);
+	print_helper(a_s >= b_i);
+	print_helper(a_s + b_ui);
+	print_helper(a_s - b_ui);
+	print_helper(a_s * b_ui);
+	print_helper(a_s / b_ui);
+	print_helper(a_s % b_ui);
+	print_helper(a_s & b_ui);
+	print_helper(a_s | b_ui);
+	print_helper(a_s ^ b_ui);
+	print_helper(a_s == b_ui);
+	print_helper(a_s != b_ui);
+	print_helper(a_s < b_ui);
+	print_helper(a_s <= b_ui);
+	print_helper(a_s > b_ui);
+	print_helper(a_s >= b_ui);
+	print_helper(a_s + b_l);
+	print_helper(a_s - b_l);
+	print_helper(a_s * b_l);
+	print_helper(a_s / b_l);
+	print_helper(a_s % b_l);
+	print_helper(a_s & b_l);
+	print_helper(a_s | b_l);
+	print_helper(a_s ^ b_l);
+	print_helper(a_s == b_l);
+	print_helper(a_s != b_l);
+	print_helper(a_s < b_l);
+	print_helper(a_s <= b_l);
+	print_helper(a_s > b_l);
+	print_helper(a_s >= b_l);
+	print_helper(a_s + b_ul);
+	print_helper(a_s - b_ul);
+	print_helper(a_s * b_ul);
+	print_helper(a_s / b_ul);
+	print_helper(a_s % b_ul);
+	print_helper(a_s & b_ul);
+	print_helper(a_s | b_ul);
+	print_helper(a_s ^ b_ul);
+	print_helper(a_s == b_ul);
+	print_helper(a_s != b_ul);
+	print_helper(a_s < b_ul);
+	print_helper(a_s <= b_ul);
+	print_helper(a_s > b_ul);
+	print_helper(a_s >= b_ul);
+	print_helper(a_us + b_c);
+	print_helper(a_us - b_c);
+	print_helper(a_us * b_c);
+	print_helper(a_us / b_c);
+	print_helper(a_us % b_c);
+	print_helper(a_us & b_c);
+	print_helper(a_us | b_c);
+	print_helper(a_us ^ b_c);
+	print_helper(a_us == b_c);
+	print_helper(a_us != b_c);
+	print_helper(a_us < b_c);
+	print_helper(a_us <= b_c);
+	print_helper(a_us > b_c);
+	print_helper(a_us >= b_c);
+	print_helper(a_us + b_uc);
+	print_helper(a_us - b_uc);
+	print_helper(a_us * b_uc);
+	print_helper(a_us / b_uc);
+	print_helper(a_us % b_uc);
+	print_helper(a_us & b_uc);
+	print_helper(a_us | b_uc);
+	print_helper(a_us ^ b_uc);
+	print_helper(a_us == b_uc);
+	print_helper(a_us != b_uc);
+	print_helper(a_us < b_uc);
+	print_helper(a_us <= b_uc);
+	print_helper(a_us > b_uc);
+	print_helper(a_us >= b_uc);
+	print_helper(a_us + b_s);
+	print_helper(a_us - b_s);
+	print_helper(a_us * b_s);
+	print_helper(a_us / b_s);
+	print_helper(a_us % b_s);
+	print_helper(a_us & b_s);
+	print_helper(a_us | b_s);
+	print_helper(a_us ^ b_s);
+	print_helper(a_us == b_s);
+	print_helper(a_us != b_s);
+	print_helper(a_us < b_s);
+	print_helper(a_us <= b_s);
+	print_helper(a_us > b_s);
+	print_helper(a_us >= b_s);
+	print_helper(a_us + b_us);
+	print_helper(a_us - b_us);
+	print_helper(a_us * b_us);
+	print_helper(a_us / b_us);
+	print_helper(a_us % b_us);
+	print_helper(a_us & b_us);
+	print_helper(a_us | b_us);
+	print_helper(a_us ^ b_us);
+	print_helper(a_us == b_us);
+	print_helper(a_us != b_us);
+	print_helper(a_us < b_us);
+	print_helper(a_us <= b_us);
+	print_helper(a_us > b_us);
+	print_helper(a_us >= b_us);
+	print_helper(a_us + b_i);
+	print_helper(a_us - b_i);
+	print_helper(a_us * b_i);
+	print_helper(a_us / b_i);
+	print_helper(a_us % b_i);
+	print_helper(a_us & b_i);
+	print_helper(a_us | b_i);
+	print_helper(a_us ^ b_i);
+	print_helper(a_us == b_i);
+	print_helper(a_us != b_i);
+	print_helper(a_us < b_i);
+	print_helper(a_us <= b_i);
+	print_helper(a_us > b_i);
+	print_helper(a_us >= b_i);
+	print_helper(a_us + b_ui);
+	print_helper(a_us - b_ui);
+	print_helper(a_us * b_ui);
+	print_helper(a_us / b_ui);
+	print_helper(a_us % b_ui);
+	print_helper(a_us & b_ui);
+	print_helper(a_us | b_ui);
+	print_helper(a_us ^ b_ui);
+	print_helper(a_us == b_ui);
+	print_helper(a_us != b_ui);
+	print_helper(a_us < b_ui);
+	print_helper(a_us <= b_ui);
+	print_helper(a_us > b_ui);
+	print_helper(a_us >= b_ui);
+	print_helper(a_us + b_l);
+	print_helper(a_us - b_l);
+	print_helper(a_us * b_l);
+	print_helper(a_us / b_l);
+	print_helper(a_us % b_l);
+	print_helper(a_us & b_l);
+	print_helper(a_us | b_l);
+	print_helper(a_us ^ b_l);
+	print_helper(a_us == b_l);
+	print_helper(a_us != b_l);
+	print_helper(a_us < b_l);
+	print_helper(a_us <= b_l);
+	print_helper(a_us > b_l);
+	print_helper(a_us >= b_l);
+	print_helper(a_us + b_ul);
+	print_helper(a_us - b_ul);
+	print_helper(a_us * b_ul);
+	print_helper(a_us / b_ul);
+	print_helper(a_us % b_ul);
+	print_helper(a_us & b_ul);
+	print_helper(a_us | b_ul);
+	print_helper(a_us ^ b_ul);
+	print_helper(a_us == b_ul);
+	print_helper(a_us != b_ul);
+	print_helper(a_us < b_ul);
+	print_helper(a_us <= b_ul);
+	print_helper(a_us > b_ul);
+	print_helper(a_us >= b_ul);
+	print_helper(a_i + b_c);
+	print_helper(a_i - b_c);
+	print_helper(a_i * b_c);
+	print_helper(a_i / b_c);
+	print_helper(a_i % b_c);
+	print_helper(a_i & b_c);
+	print_helper(a_i | b_c);
+	print_helper(a_i ^ b_c);
+	print_helper(a_i == b_c);
+	print_helper(a_i != b_c);
+	print_helper(a_i < b_c);
+	print_helper(a_i <= b_c);
+	print_helper(a_i > b_c);
+	print_helper(a_i >= b_c);
+	print_helper(a_i + b_uc);
+	print_helper(a_i - b_uc);
+	print_helper(a_i * b_uc);
+	print_helper(a_i / b_uc);
+	print_helper(a_i % b_uc);
+	print_helper(a_i & b_uc);
+	print_helper(a_i | b_uc);
+	print_helper(a_i ^ b_uc);
+	print_helper(a_i == b_uc);
+	print_helper(a_i != b_uc);
+	print_helper(a_i < b_uc);
+	print_helper(a_i <= b_uc);
+	print_helper(a_i > b_uc);
+	print_helper(a_i >= b_uc);
+	print_helper(a_i + b_s);
+	print_helper(a_i - b_s);
+	print_helper(a_i * b_s);
+	print_helper(a_i / b_s);
+	print_helper(a_i % b_s);
+	print_helper(a_i & b_s);
+	print_helper(a_i | b_s);
+	print_helper(a_i ^ b_s);
+	print_helper(a_i == b_s);
+	print_helper(a_i != b_s);
+	print_helper(a_i < b_s);
+	print_helper(a_i <= b_s);
+	print_helper(a_i > b_s);
+	print_helper(a_i >= b_s);
+	print_helper(a_i + b_us);
+	print_helper(a_i - b_us);
+	print_helper(a_i * b_us);
+	print_helper(a_i / b_us);
+	print_helper(a_i % b_us);
+	print_helper(a_i & b_us);
+	print_helper(a_i | b_us);
+	print_helper(a_i ^ b_us);
+	print_helper(a_i == b_us);
+	print_helper(a_i != b_us);
+	print_helper(a_i < b_us);
+	print_helper(a_i <= b_us);
+	print_helper(a_i > b_us);
+	print_helper(a_i >= b_us);
+	print_helper(a_i + b_i);
+	print_helper(a_i - b_i);
+	print_helper(a_i * b_i);
+	print_helper(a_i / b_i);
+	print_helper(a_i % b_i);
+	print_helper(a_i & b_i);
+	print_helper(a_i | b_i);
+	print_helper(a_i ^ b_i);
+	print_helper(a_i == b_i);
+	print_helper(a_i != b_i);
+	print_helper(a_i < b_i);
+	print_helper(a_i <= b_i);
+	print_helper(a_i > b_i);
+	print_helper(a_i >= b_i);
+	print_helper(a_i + b_ui);
+	print_helper(a_i - b_ui);
+	print_helper(a_i * b_ui);
+	print_helper(a_i / b_ui);
+	print_helper(a_i % b_ui);
+	print_helper(a_i & b_ui);
+	print_helper(a_i | b_ui);
+	print_helper(a_i ^ b_ui);
+	print_helper(a_i == b_ui);
+	print_helper(a_i != b_ui);
+	print_helper(a_i < b_ui);
+	print_helper(a_i <= b_ui);
+	print_helper(a_i > b_ui);
+	print_helper(a_i >= b_ui);
+	print_helper(a_i + b_l);
+	print_helper(a_i - b_l);
+	print_helper(a_i * b_l);
+	print_helper(a_i / b_l);
+	print_helper(a_i % b_l);
+	print_helper(a_i & b_l);
+	print_helper(a_i | b_l);
+	print_helper(a_i ^ b_l);
+	print_helper(a_i == b_l);
+	print_helper(a_i != b_l);
+	print_helper(a_i < b_l);
+	print_helper(a_i <= b_l);
+	print_helper(a_i > b_l);
+	print_helper(a_i >= b_l);
+	print_helper(a_i + b_ul);
+	print_helper(a_i - b_ul);
+	print_helper(a_i * b_ul);
+	print_helper(a_i / b_ul);
+	print_helper(a_i % b_ul);
+	print_helper(a_i & b_ul);
+	print_helper(a_i | b_ul);
+	print_helper(a_i ^ b_ul);
+	print_helper(a_i == b_ul);
+	print_helper(a_i != b_ul);
+	print_helper(a_i < b_ul);
+	print_helper(a_i <= b_ul);
+	print_helper(a_i > b_ul);
+	print_helper(a_i >= b_ul);
+	print_helper(a_ui + b_c);
+	print_helper(a_ui - b_c);
+	print_helper(a_ui * b_c);
+	print_helper(a_ui / b_c);
+	print_helper(a_ui % b_c);
+	print_helper(a_ui & b_c);
+	print_helper(a_ui | b_c);
+	print_helper(a_ui ^ b_c);
+	print_helper(a_ui == b_c);
+	print_helper(a_ui != b_c);
+	print_helper(a_ui < b_c);
+	print_helper(a_ui <= b_c);
+	print_helper(a_ui > b_c);
+	print_helper(a_ui >= b_c);
+	print_helper(a_ui + b_uc);
+	print_helper(a_ui - b_uc);
+	print_helper(a_ui * b_uc);
+	print_helper(a_ui / b_uc);
+	print_helper(a_ui % b_uc);
+	print_helper(a_ui & b_uc);
+	print_helper(a_ui | b_uc);
+	print_helper(a_ui ^ b_uc);
+	print_helper(a_ui == b_uc);
+	print_helper(a_ui != b_uc);
+	print_helper(a_ui < b_uc);
+	print_helper(a_ui <= b_uc);
+	print_helper(a_ui > b_uc);
+	print_helper(a_ui >= b_uc);
+	print_helper(a_ui + b_s);
+	print_helper(a_ui - b_s);
+	print_helper(a_ui * b_s);
+	print_helper(a_ui / b_s);
+	print_helper(a_ui % b_s);
+	print_helper(a_ui & b_s);
+	print_helper(a_ui | b_s);
+	print_helper(a_ui ^ b_s);
+	print_helper(a_ui == b_s);
+	print_helper(a_ui != b_s);
+	print_helper(a_ui < b_s);
+	print_helper(a_ui <= b_s);
+	print_helper(a_ui > b_s);
+	print_helper(a_ui >= b_s);
+	print_helper(a_ui + b_us);
+	print_helper(a_ui - b_us);
+	print_helper(a_ui * b_us);
+	print_helper(a_ui / b_us);
+	print_helper(a_ui % b_us);
+	print_helper(a_ui & b_us);
+	print_helper(a_ui | b_us);
+	print_helper(a_ui ^ b_us);
+	print_helper(a_ui == b_us);
+	print_helper(a_ui != b_us);
+	print_helper(a_ui < b_us);
+	print_helper(a_ui <= b_us);
+	print_helper(a_ui > b_us);
+	print_helper(a_ui >= b_us);
+	print_helper(a_ui + b_i);
+	print_helper(a_ui - b_i);
+	print_helper(a_ui * b_i);
+	print_helper(a_ui / b_i);
+	print_helper(a_ui % b_i);
+	print_helper(a_ui & b_i);
+	print_helper(a_ui | b_i);
+	print_helper(a_ui ^ b_i);
+	print_helper(a_ui == b_i);
+	print_helper(a_ui != b_i);
+	print_helper(a_ui < b_i);
+	print_helper(a_ui <= b_i);
+	print_helper(a_ui > b_i);
+	print_helper(a_ui >= b_i);
+	print_helper(a_ui + b_ui);
+	print_helper(a_ui - b_ui);
+	print_helper(a_ui * b_ui);
+	print_helper(a_ui / b_ui);
+	print_helper(a_ui % b_ui);
+	print_helper(a_ui & b_ui);
+	print_helper(a_ui | b_ui);
+	print_helper(a_ui ^ b_ui);
+	print_helper(a_ui == b_ui);
+	print_helper(a_ui != b_ui);
+	print_helper(a_ui < b_ui);
+	print_helper(a_ui <= b_ui);
+	print_helper(a_ui > b_ui);
+	print_helper(a_ui >= b_ui);
+	print_helper(a_ui + b_l);
+	print_helper(a_ui - b_l);
+	print_helper(a_ui * b_l);
+	print_helper(a_ui / b_l);
+	print_helper(a_ui % b_l);
+	print_helper(a_ui & b_l);
+	print_helper(a_ui | b_l);
+	print_helper(a_ui ^ b_l);
+	print_helper(a_ui == b_l);
+	print_helper(a_ui != b_l);
+	print_helper(a_ui < b_l);
+	print_helper(a_ui <= b_l);
+	print_helper(a_ui > b_l);
+	print_helper(a_ui >= b_l);
+	print_helper(a_ui + b_ul);
+	print_helper(a_ui - b_ul);
+	print_helper(a_ui * b_ul);
+	print_helper(a_ui / b_ul);
+	print_helper(a_ui % b_ul);
+	print_helper(a_ui & b_ul);
+	print_helper(a_ui | b_ul);
+	print_helper(a_ui ^ b_ul);
+	print_helper(a_ui == b_ul);
+	print_helper(a_ui != b_ul);
+	print_helper(a_ui < b_ul);
+	print_helper(a_ui <= b_ul);
+	print_helper(a_ui > b_ul);
+	print_helper(a_ui >= b_ul);
+	print_helper(a_l + b_c);
+	print_helper(a_l - b_c);
+	print_helper(a_l * b_c);
+	print_helper(a_l / b_c);
+	print_helper(a_l % b_c);
+	print_helper(a_l & b_c);
+	print_helper(a_l | b_c);
+	print_helper(a_l ^ b_c);
+	print_helper(a_l == b_c);
+	print_helper(a_l != b_c);
+	print_helper(a_l < b_c);
+	print_helper(a_l <= b_c);
+	print_helper(a_l > b_c);
+	print_helper(a_l >= b_c);
+	print_helper(a_l + b_uc);
+	print_helper(a_l - b_uc);
+	print_helper(a_l * b_uc);
+	print_helper(a_l / b_uc);
+	print_helper(a_l % b_uc);
+	print_helper(a_l & b_uc);
+	print_helper(a_l | b_uc);
+	print_helper(a_l ^ b_uc);
+	print_helper(a_l == b_uc);
+	print_helper(a_l != b_uc);
+	print_helper(a_l < b_uc);
+	print_helper(a_l <= b_uc);
+	print_helper(a_l > b_uc);
+	print_helper(a_l >= b_uc);
+	print_helper(a_l + b_s);
+	print_helper(a_l - b_s);
+	print_helper(a_l * b_s);
+	print_helper(a_l / b_s);
+	print_helper(a_l % b_s);
+	print_helper(a_l & b_s);
+	print_helper(a_l | b_s);
+	print_helper(a_l ^ b_s);
+	print_helper(a_l == b_s);
+	print_helper(a_l != b_s);
+	print_helper(a_l < b_s);
+	print_helper(a_l <= b_s);
+	print_helper(a_l > b_s);
+	print_helper(a_l >= b_s);
+	print_helper(a_l + b_us);
+	print_helper(a_l - b_us);
+	print_helper(a_l * b_us);
+	print_helper(a_l / b_us);
+	print_helper(a_l % b_us);
+	print_helper(a_l & b_us);
+	print_helper(a_l | b_us);
+	print_helper(a_l ^ b_us);
+	print_helper(a_l == b_us);
+	print_helper(a_l != b_us);
+	print_helper(a_l < b_us);
+	print_helper(a_l <= b_us);
+	print_helper(a_l > b_us);
+	print_helper(a_l >= b_us);
+	print_helper(a_l + b_i);
+	print_helper(a_l - b_i);
+	print_helper(a_l * b_i);
+	print_helper(a_l / b_i);
+	print_helper(a_l % b_i);
+	print_helper(a_l & b_i);
+	print_helper(a_l | b_i);
+	print_helper(a_l ^ b_i);
+	print_helper(a_l == b_i);
+	print_helper(a_l != b_i);
+	print_helper(a_l < b_i);
+	print_helper(a_l <= b_i);
+	print_helper(a_l > b_i);
+	print_helper(a_l >= b_i);
+	print_helper(a_l + b_ui);
+	print_helper(a_l - b_ui);
+	print_helper(a_l * b_ui);
+	print_helper(a_l / b_ui);
+	print_helper(a_l % b_ui);
+	print_helper(a_l & b_ui);
+	print_helper(a_l | b_ui);
+	print_helper(a_l ^ b_ui);
+	print_helper(a_l == b_ui);
+	print_helper(a_l != b_ui);
+	print_helper(a_l < b_ui);
+	print_helper(a_l <= b_ui);
+	print_helper(a_l > b_ui);
+	print_helper(a_l >= b_ui);
+	print_helper(a_l + b_l);
+	print_helper(a_l - b_l);
+	print_helper(a_l * b_l);
+	print_helper(a_l / b_l);
+	print_helper(a_l % b_l);
+	print_helper(a_l & b_l);
+	print_helper(a_l | b_l);
+	print_helper(a_l ^ b_l);
+	print_helper(a_l == b_l);
+	print_helper(a_l != b_l);
+	print_helper(a_l < b_l);
+	print_helper(a_l <= b_l);
+	print_helper(a_l > b_l);
+	print_helper(a_l >= b_l);
+	print_helper(a_l + b_ul);
+	print_helper(a_l - b_ul);
+	print_helper(a_l * b_ul);
+	print_helper(a_l / b_ul);
+	print_helper(a_l % b_ul);
+	print_helper(a_l & b_ul);
+	print_helper(a_l | b_ul);
+	print_helper(a_l ^ b_ul);
+	print_helper(a_l == b_ul);
+	print_helper(a_l != b_ul);
+	print_helper(a_l < b_ul);
+	print_helper(a_l <= b_ul);
+	print_helper(a_l > b_ul);
+	print_helper(a_l >= b_ul);
+	print_helper(a_ul + b_c);
+	print_helper(a_ul - b_c);
+	print_helper(a_ul * b_c);
+	print_helper(a_ul / b_c);
+	print_helper(a_ul % b_c);
+	print_helper(a_ul & b_c);
+	print_helper(a_ul | b_c);
+	print_helper(a_ul ^ b_c);
+	print_helper(a_ul == b_c);
+	print_helper(a_ul != b_c);
+	print_helper(a_ul < b_c);
+	print_helper(a_ul <= b_c);
+	print_helper(a_ul > b_c);
+	print_helper(a_ul >= b_c);
+	print_helper(a_ul + b_uc);
+	print_helper(a_ul - b_uc);
+	print_helper(a_ul * b_uc);
+	print_helper(a_ul / b_uc);
+	print_helper(a_ul % b_uc);
+	print_helper(a_ul & b_uc);
+	print_helper(a_ul | b_uc);
+	print_helper(a_ul ^ b_uc);
+	print_helper(a_ul == b_uc);
+	print_helper(a_ul != b_uc);
+	print_helper(a_ul < b_uc);
+	print_helper(a_ul <= b_uc);
+	print_helper(a_ul > b_uc);
+	print_helper(a_ul >= b_uc);
+	print_helper(a_ul + b_s);
+	print_helper(a_ul - b_s);
+	print_helper(a_ul * b_s);
+	print_helper(a_ul / b_s);
+	print_helper(a_ul % b_s);
+	print_helper(a_ul & b_s);
+	print_helper(a_ul | b_s);
+	print_helper(a_ul ^ b_s);
+	print_helper(a_ul == b_s);
+	print_helper(a_ul != b_s);
+	print_helper(a_ul < b_s);
+	print_helper(a_ul <= b_s);
+	print_helper(a_ul > b_s);
+	print_helper(a_ul >= b_s);
+	print_helper(a_ul + b_us);
+	print_helper(a_ul - b_us);
+	print_helper(a_ul * b_us);
+	print_helper(a_ul / b_us);
+	print_helper(a_ul % b_us);
+	print_helper(a_ul & b_us);
+	print_helper(a_ul | b_us);
+	print_helper(a_ul ^ b_us);
+	print_helper(a_ul == b_us);
+	print_helper(a_ul != b_us);
+	print_helper(a_ul < b_us);
+	print_helper(a_ul <= b_us);
+	print_helper(a_ul > b_us);
+	print_helper(a_ul >= b_us);
+	print_helper(a_ul + b_i);
+	print_helper(a_ul - b_i);
+	print_helper(a_ul * b_i);
+	print_helper(a_ul / b_i);
+	print_helper(a_ul % b_i);
+	print_helper(a_ul & b_i);
+	print_helper(a_ul | b_i);
+	print_helper(a_ul ^ b_i);
+	print_helper(a_ul == b_i);
+	print_helper(a_ul != b_i);
+	print_helper(a_ul < b_i);
+	print_helper(a_ul <= b_i);
+	print_helper(a_ul > b_i);
+	print_helper(a_ul >= b_i);
+	print_helper(a_ul + b_ui);
+	print_helper(a_ul - b_ui);
+	print_helper(a_ul * b_ui);
+	print_helper(a_ul / b_ui);
+	print_helper(a_ul % b_ui);
+	print_helper(a_ul & b_ui);
+	print_helper(a_ul | b_ui);
+	print_helper(a_ul ^ b_ui);
+	print_helper(a_ul == b_ui);
+	print_helper(a_ul != b_ui);
+	print_helper(a_ul < b_ui);
+	print_helper(a_ul <= b_ui);
+	print_helper(a_ul > b_ui);
+	print_helper(a_ul >= b_ui);
+	print_helper(a_ul + b_l);
+	print_helper(a_ul - b_l);
+	print_helper(a_ul * b_l);
+	print_helper(a_ul / b_l);
+	print_helper(a_ul % b_l);
+	print_helper(a_ul & b_l);
+	print_helper(a_ul | b_l);
+	print_helper(a_ul ^ b_l);
+	print_helper(a_ul == b_l);
+	print_helper(a_ul != b_l);
+	print_helper(a_ul < b_l);
+	print_helper(a_ul <= b_l);
+	print_helper(a_ul > b_l);
+	print_helper(a_ul >= b_l);
+	print_helper(a_ul + b_ul);
+	print_helper(a_ul - b_ul);
+	print_helper(a_ul * b_ul);
+	print_helper(a_ul / b_ul);
+	print_helper(a_ul % b_ul);
+	print_helper(a_ul & b_ul);
+	print_helper(a_ul | b_ul);
+	print_helper(a_ul ^ b_ul);
+	print_helper(a_ul == b_ul);
+	print_helper(a_ul != b_ul);
+	print_helper(a_ul < b_ul);
+	print_helper(a_ul <= b_ul);
+	print_helper(a_ul > b_ul);
+	print_helper(a_ul >= b_ul);
+	return 0;
+}
+
+int binary1() {
+	char a_c;
+	char b_c;
+	a_c = 100;
+	b_c = -9;
+	unsigned char a_uc;
+	unsigned char b_uc;
+	a_uc = 100;
+	b_uc = -9;
+	short a_s;
+	short b_s;
+	a_s = 100;
+	b_s = -9;
+	unsigned short a_us;
+	unsigned short b_us;
+	a_us = 100;
+	b_us = -9;
+	int a_i;
+	int b_i;
+	a_i = 100;
+	b_i = -9;
+	unsigned int a_ui;
+	unsigned int b_ui;
+	a_ui = 100;
+	b_ui = -9;
+	long a_l;
+	long b_l;
+	a_l = 100;
+	b_l = -9;
+	unsigned long a_ul;
+	unsigned long b_ul;
+	a_ul = 100;
+	b_ul = -9;
+	print_helper(a_c + b_c);
+	print_helper(a_c - b_c);
+	print_helper(a_c * b_c);
+	print_helper(a_c / b_c);
+	print_helper(a_c % b_c);
+	print_helper(a_c & b_c);
+	print_helper(a_c | b_c);
+	print_helper(a_c ^ b_c);
+	print_helper(a_c == b_c);
+	print_helper(a_c != b_c);
+	print_helper(a_c < b_c);
+	print_helper(a_c <= b_c);
+	print_helper(a_c > b_c);
+	print_helper(a_c >= b_c);
+	print_helper(a_c + b_uc);
+	print_helper(a_c - b_uc);
+	print_helper(a_c * b_uc);
+	print_helper(a_c / b_uc);
+	print_helper(a_c % b_uc);
+	print_helper(a_c & b_uc);
+	print_helper(a_c | b_uc);
+	print_helper(a_c ^ b_uc);
+	print_helper(a_c == b_uc);
+	print_helper(a_c != b_uc);
+	print_helper(a_c < b_uc);
+	print_helper(a_c <= b_uc);
+	print_helper(a_c > b_uc);
+	print_helper(a_c >= b_uc);
+	print_helper(a_c + b_s);
+	print_helper(a_c - b_s);
+	print_helper(a_c * b_s);
+	print_helper(a_c / b_s);
+	print_helper(a_c % b_s);
+	print_helper(a_c & b_s);
+	print_helper(a_c | b_s);
+	print_helper(a_c ^ b_s);
+	print_helper(a_c == b_s);
+	print_helper(a_c != b_s);
+	print_helper(a_c < b_s);
+	print_helper(a_c <= b_s);
+	print_helper(a_c > b_s);
+	print_helper(a_c >= b_s);
+	print_helper(a_c + b_us);
+	print_helper(a_c - b_us);
+	print_helper(a_c * b_us);
+	print_helper(a_c / b_us);
+	print_helper(a_c % b_us);
+	print_helper(a_c & b_us);
+	print_helper(a_c | b_us);
+	print_helper(a_c ^ b_us);
+	print_helper(a_c == b_us);
+	print_helper(a_c != b_us);
+	print_helper(a_c < b_us);
+	print_helper(a_c <= b_us);
+	print_helper(a_c > b_us);
+	print_helper(a_c >= b_us);
+	print_helper(a_c + b_i);
+	print_helper(a_c - b_i);
+	print_helper(a_c * b_i);
+	print_helper(a_c / b_i);
+	print_helper(a_c % b_i);
+	print_helper(a_c & b_i);
+	print_helper(a_c | b_i);
+	print_helper(a_c ^ b_i);
+	print_helper(a_c == b_i);
+	print_helper(a_c != b_i);
+	print_helper(a_c < b_i);
+	print_helper(a_c <= b_i);
+	print_helper(a_c > b_i);
+	print_helper(a_c >= b_i);
+	print_helper(a_c + b_ui);
+	print_helper(a_c - b_ui);
+	print_helper(a_c * b_ui);
+	print_helper(a_c / b_ui);
+	print_helper(a_c % b_ui);
+	print_helper(a_c & b_ui);
+	print_helper(a_c | b_ui);
+	print_helper(a_c ^ b_ui);
+	print_helper(a_c == b_ui);
+	print_helper(a_c != b_ui);
+	print_helper(a_c < b_ui);
+	print_helper(a_c <= b_ui);
+	print_helper(a_c > b_ui);
+	print_helper(a_c >= b_ui);
+	print_helper(a_c + b_l);
+	print_helper(a_c - b_l);
+	print_helper(a_c * b_l);
+	print_helper(a_c / b_l);
+	print_helper(a_c % b_l);
+	print_helper(a_c & b_l);
+	print_helper(a_c | b_l);
+	print_helper(a_c ^ b_l);
+	print_helper(a_c == b_l);
+	print_helper(a_c != b_l);
+	print_helper(a_c < b_l);
+	print_helper(a_c <= b_l);
+	print_helper(a_c > b_l);
+	print_helper(a_c >= b_l);
+	print_helper(a_c + b_ul);
+	print_helper(a_c - b_ul);
+	print_helper(a_c * b_ul);
+	print_helper(a_c / b_ul);
+	print_helper(a_c % b_ul);
+	print_helper(a_c & b_ul);
+	print_helper(a_c | b_ul);
+	print_helper(a_c ^ b_ul);
+	print_helper(a_c == b_ul);
+	print_helper(a_c != b_ul);
+	print_helper(a_c < b_ul);
+	print_helper(a_c <= b_ul);
+	print_helper(a_c > b_ul);
+	print_helper(a_c >= b_ul);
+	print_helper(a_uc + b_c);
+	print_helper(a_uc - b_c);
+	print_helper(a_uc * b_c);
+	print_helper(a_uc / b_c);
+	print_helper(a_uc % b_c);
+	print_helper(a_uc & b_c);
+	print_helper(a_uc | b_c);
+	print_helper(a_uc ^ b_c);
+	print_helper(a_uc == b_c);
+	print_helper(a_uc != b_c);
+	print_helper(a_uc < b_c);
+	print_helper(a_uc <= b_c);
+	print_helper(a_uc > b_c);
+	print_helper(a_uc >= b_c);
+	print_helper(a_uc + b_uc);
+	print_helper(a_uc - b_uc);
+	print_helper(a_uc * b_uc);
+	print_helper(a_uc / b_uc);
+	print_helper(a_uc % b_uc);
+	print_helper(a_uc & b_uc);
+	print_helper(a_uc | b_uc);
+	print_helper(a_uc ^ b_uc);
+	print_helper(a_uc == b_uc);
+	print_helper(a_uc != b_uc);
+	print_helper(a_uc < b_uc);
+	print_helper(a_uc <= b_uc);
+	print_helper(a_uc > b_uc);
+	print_helper(a_uc >= b_uc);
+	print_helper(a_uc + b_s);
+	print_helper(a_uc - b_s);
+	print_helper(a_uc * b_s);
+	print_helper(a_uc / b_s);
+	print_helper(a_uc % b_s);
+	print_helper(a_uc & b_s);
+	print_helper(a_uc | b_s);
+	print_helper(a_uc ^ b_s);
+	print_helper(a_uc == b_s);
+	print_helper(a_uc != b_s);
+	print_helper(a_uc < b_s);
+	print_helper(a_uc <= b_s);
+	print_helper(a_uc > b_s);
+	print_helper(a_uc >= b_s);
+	print_helper(a_uc + b_us);
+	print_helper(a_uc - b_us);
+	print_helper(a_uc * b_us);
+	print_helper(a_uc / b_us);
+	print_helper(a_uc % b_us);
+	print_helper(a_uc & b_us);
+	print_helper(a_uc | b_us);
+	print_helper(a_uc ^ b_us);
+	print_helper(a_uc == b_us);
+	print_helper(a_uc != b_us);
+	print_helper(a_uc < b_us);
+	print_helper(a_uc <= b_us);
+	print_helper(a_uc > b_us);
+	print_helper(a_uc >= b_us);
+	print_helper(a_uc + b_i);
+	print_helper(a_uc - b_i);
+	print_helper(a_uc * b_i);
+	print_helper(a_uc / b_i);
+	print_helper(a_uc % b_i);
+	print_helper(a_uc & b_i);
+	print_helper(a_uc | b_i);
+	print_helper(a_uc ^ b_i);
+	print_helper(a_uc == b_i);
+	print_helper(a_uc != b_i);
+	print_helper(a_uc < b_i);
+	print_helper(a_uc <= b_i);
+	print_helper(a_uc > b_i);
+	print_helper(a_uc >= b_i);
+	print_helper(a_uc + b_ui);
+	print_helper(a_uc - b_ui);
+	print_helper(a_uc * b_ui);
+	print_helper(a_uc / b_ui);
+	print_helper(a_uc % b_ui);
+	print_helper(a_uc & b_ui);
+	print_helper(a_uc | b_ui);
+	print_helper(a_uc ^ b_ui);
+	print_helper(a_uc == b_ui);
+	print_helper(a_uc != b_ui);
+	print_helper(a_uc < b_ui);
+	print_helper(a_uc <= b_ui);
+	print_helper(a_uc > b_ui);
+	print_helper(a_uc >= b_ui);
+	print_helper(a_uc + b_l);
+	print_helper(a_uc - b_l);
+	print_helper(a_uc * b_l);
+	print_helper(a_uc / b_l);
+	print_helper(a_uc % b_l);
+	print_helper(a_uc & b_l);
+	print_helper(a_uc | b_l);
+	print_helper(a_uc ^ b_l);
+	print_helper(a_uc == b_l);
+	print_helper(a_uc != b_l);
+	print_helper(a_uc < b_l);
+	print_helper(a_uc <= b_l);
+	print_helper(a_uc > b_l);
+	print_helper(a_uc >= b_l);
+	print_helper(a_uc + b_ul);
+	print_helper(a_uc - b_ul);
+	print_helper(a_uc * b_ul);
+	print_helper(a_uc / b_ul);
+	print_helper(a_uc % b_ul);
+	print_helper(a_uc & b_ul);
+	print_helper(a_uc | b_ul);
+	print_helper(a_uc ^ b_ul);
+	print_helper(a_uc == b_ul);
+	print_helper(a_uc != b_ul);
+	print_helper(a_uc < b_ul);
+	print_helper(a_uc <= b_ul);
+	print_helper(a_uc > b_ul);
+	print_helper(a_uc >= b_ul);
+	print_helper(a_s + b_c);
+	print_helper(a_s - b_c);
+	print_helper(a_s * b_c);
+	print_helper(a_s / b_c);
+	print_helper(a_s % b_c);
+	print_helper(a_s & b_c);
+	print_helper(a_s | b_c);
+	print_helper(a_s ^ b_c);
+	print_helper(a_s == b_c);
+	print_helper(a_s != b_c);
+	print_helper(a_s < b_c);
+	print_helper(a_s <= b_c);
+	print_helper(a_s > b_c);
+	print_helper(a_s >= b_c);
+	print_helper(a_s + b_uc);
+	print_helper(a_s - b_uc);
+	print_helper(a_s * b_uc);
+	print_helper(a_s / b_uc);
+	print_helper(a_s % b_uc);
+	print_helper(a_s & b_uc);
+	print_helper(a_s | b_uc);
+	print_helper(a_s ^ b_uc);
+	print_helper(a_s == b_uc);
+	print_helper(a_s != b_uc);
+	print_helper(a_s < b_uc);
+	print_helper(a_s <= b_uc);
+	print_helper(a_s > b_uc);
+	print_helper(a_s >= b_uc);
+	print_helper(a_s + b_s);
+	print_helper(a_s - b_s);
+	print_helper(a_s * b_s);
+	print_helper(a_s / b_s);
+	print_helper(a_s % b_s);
+	print_helper(a_s & b_s);
+	print_helper(a_s | b_s);
+	print_helper(a_s ^ b_s);
+	print_helper(a_s == b_s);
+	print_helper(a_s != b_s);
+	print_helper(a_s < b_s);
+	print_helper(a_s <= b_s);
+	print_helper(a_s > b_s);
+	print_helper(a_s >= b_s);
+	print_helper(a_s + b_us);
+	print_helper(a_s - b_us);
+	print_helper(a_s * b_us);
+	print_helper(a_s / b_us);
+	print_helper(a_s % b_us);
+	print_helper(a_s & b_us);
+	print_helper(a_s | b_us);
+	print_helper(a_s ^ b_us);
+	print_helper(a_s == b_us);
+	print_helper(a_s != b_us);
+	print_helper(a_s < b_us);
+	print_helper(a_s <= b_us);
+	print_helper(a_s > b_us);
+	print_helper(a_s >= b_us);
+	print_helper(a_s + b_i);
+	print_helper(a_s - b_i);
+	print_helper(a_s * b_i);
+	print_helper(a_s / b_i);
+	print_helper(a_s % b_i);
+	print_helper(a_s & b_i);
+	print_helper(a_s | b_i);
+	print_helper(a_s ^ b_i);
+	print_helper(a_s == b_i);
+	print_helper(a_s != b_i);
+	print_helper(a_s < b_i);
+	print_helper(a_s <= b_i);
+	print_helper(a_s > b_i);
+	print_helper(a_s >= b_i);
+	print_helper(a_s + b_ui);
+	print_helper(a_s - b_ui);
+	print_helper(a_s * b_ui);
+	print_helper(a_s / b_ui);
+	print_helper(a_s % b_ui);
+	print_helper(a_s & b_ui);
+	print_helper(a_s | b_ui);
+	print_helper(a_s ^ b_ui);
+	print_helper(a_s == b_ui);
+	print_helper(a_s != b_ui);
+	print_helper(a_s < b_ui);
+	print_helper(a_s <= b_ui);
+	print_helper(a_s > b_ui);
+	print_helper(a_s >= b_ui);
+	print_helper(a_s + b_l);
+	print_helper(a_s - b_l);
+	print_helper(a_s * b_l);
+	print_helper(a_s / b_l);
+	print_helper(a_s % b_l);
+	print_helper(a_s & b_l);
+	print_helper(a_s | b_l);
+	print_helper(a_s ^ b_l);
+	print_helper(a_s == b_l);
+	print_helper(a_s != b_l);
+	print_helper(a_s < b_l);
+	print_helper(a_s <= b_l);
+	print_helper(a_s > b_l);
+	print_helper(a_s >= b_l);
+	print_helper(a_s + b_ul);
+	print_helper(a_s - b_ul);
+	print_helper(a_s * b_ul);
+	print_helper(a_s / b_ul);
+	print_helper(a_s % b_ul);
+	print_helper(a_s & b_ul);
+	print_helper(a_s | b_ul);
+	print_helper(a_s ^ b_ul);
+	print_helper(a_s == b_ul);
+	print_helper(a_s != b_ul);
+	print_helper(a_s < b_ul);
+	print_helper(a_s <= b_ul);
+	print_helper(a_s > b_ul);
+	print_helper(a_s >= b_ul);
+	print_helper(a_us + b_c);
+	print_helper(a_us - b_c);
+	print_helper(a_us * b_c);
+	print_helper(a_us / b_c);
+	print_helper(a_us % b_c);
+	print_helper(a_us & b_c);
+	print_helper(a_us | b_c);
+	print_helper(a_us ^ b_c);
+	print_helper(a_us == b_c);
+	print_helper(a_us != b_c);
+	print_helper(a_us < b_c);
+	print_helper(a_us <= b_c);
+	print_helper(a_us > b_c);
+	print_helper(a_us >= b_c);
+	print_helper(a_us + b_uc);
+	print_helper(a_us - b_uc);
+	print_helper(a_us * b_uc);
+	print_helper(a_us / b_uc);
+	print_helper(a_us % b_uc);
+	print_helper(a_us & b_uc);
+	print_helper(a_us | b_uc);
+	print_helper(a_us ^ b_uc);
+	print_helper(a_us == b_uc);
+	print_helper(a_us != b_uc);
+	print_helper(a_us < b_uc);
+	print_helper(a_us <= b_uc);
+	print_helper(a_us > b_uc);
+	print_helper(a_us >= b_uc);
+	print_helper(a_us + b_s);
+	print_helper(a_us - b_s);
+	print_helper(a_us * b_s);
+	print_helper(a_us / b_s);
+	print_helper(a_us % b_s);
+	print_helper(a_us & b_s);
+	print_helper(a_us | b_s);
+	print_helper(a_us ^ b_s);
+	print_helper(a_us == b_s);
+	print_helper(a_us != b_s);
+	print_helper(a_us < b_s);
+	print_helper(a_us <= b_s);
+	print_helper(a_us > b_s);
+	print_helper(a_us >= b_s);
+	print_helper(a_us + b_us);
+	print_helper(a_us - b_us);
+	print_helper(a_us * b_us);
+	print_helper(a_us / b_us);
+	print_helper(a_us % b_us);
+	print_helper(a_us & b_us);
+	print_helper(a_us | b_us);
+	print_helper(a_us ^ b_us);
+	print_helper(a_us == b_us);
+	print_helper(a_us != b_us);
+	print_helper(a_us < b_us);
+	print_helper(a_us <= b_us);
+	print_helper(a_us > b_us);
+	print_helper(a_us >= b_us);
+	print_helper(a_us + b_i);
+	print_helper(a_us - b_i);
+	print_helper(a_us * b_i);
+	print_helper(a_us / b_i);
+	print_helper(a_us % b_i);
+	print_helper(a_us & b_i);
+	print_helper(a_us | b_i);
+	print_helper(a_us ^ b_i);
+	print_helper(a_us == b_i);
+	print_helper(a_us != b_i);
+	print_helper(a_us < b_i);
+	print_helper(a_us <= b_i);
+	print_helper(a_us > b_i);
+	print_helper(a_us >= b_i);
+	print_helper(a_us + b_ui);
+	print_helper(a_us - b_ui);
+	print_helper(a_us * b_ui);
+	print_helper(a_us / b_ui);
+	print_helper(a_us % b_ui);
+	print_helper(a_us & b_ui);
+	print_helper(a_us | b_ui);
+	print_helper(a_us ^ b_ui);
+	print_helper(a_us == b_ui);
+	print_helper(a_us != b_ui);
+	print_helper(a_us < b_ui);
+	print_helper(a_us <= b_ui);
+	print_helper(a_us > b_ui);
+	print_helper(a_us >= b_ui);
+	print_helper(a_us + b_l);
+	print_helper(a_us - b_l);
+	print_helper(a_us * b_l);
+	print_helper(a_us / b_l);
+	print_helper(a_us % b_l);
+	print_helper(a_us & b_l);
+	print_helper(a_us | b_l);
+	print_helper(a_us ^ b_l);
+	print_helper(a_us == b_l);
+	print_helper(a_us != b_l);
+	print_helper(a_us < b_l);
+	print_helper(a_us <= b_l);
+	print_helper(a_us > b_l);
+	print_helper(a_us >= b_l);
+	print_helper(a_us + b_ul);
+	print_helper(a_us - b_ul);
+	print_helper(a_us * b_ul);
+	print_helper(a_us / b_ul);
+	print_helper(a_us % b_ul);
+	print_helper(a_us & b_ul);
+	print_helper(a_us | b_ul);
+	print_helper(a_us ^ b_ul);
+	print_helper(a_us == b_ul);
+	print_helper(a_us != b_ul);
+	print_helper(a_us < b_ul);
+	print_helper(a_us <= b_ul);
+	print_helper(a_us > b_ul);
+	print_helper(a_us >= b_ul);
+	print_helper(a_i + b_c);
+	print_helper(a_i - b_c);
+	print_helper(a_i * b_c);
+	print_helper(a_i / b_c);
+	print_helper(a_i % b_c);
+	print_helper(a_i & b_c);
+	print_helper(a_i | b_c);
+	print_helper(a_i ^ b_c);
+	print_helper(a_i == b_c);
+	print_helper(a_i != b_c);
+	print_helper(a_i < b_c);
+	print_helper(a_i <= b_c);
+	print_helper(a_i > b_c);
+	print_helper(a_i >= b_c);
+	print_helper(a_i + b_uc);
+	print_helper(a_i - b_uc);
+	print_helper(a_i * b_uc);
+	print_helper(a_i / b_uc);
+	print_helper(a_i % b_uc);
+	print_helper(a_i & b_uc);
+	print_helper(a_i | b_uc);
+	print_helper(a_i ^ b_uc);
+	print_helper(a_i == b_uc);
+	print_helper(a_i != b_uc);
+	print_helper(a_i < b_uc);
+	print_helper(a_i <= b_uc);
+	print_helper(a_i > b_uc);
+	print_helper(a_i >= b_uc);
+	print_helper(a_i + b_s);
+	print_helper(a_i - b_s);
+	print_helper(a_i * b_s);
+	print_helper(a_i / b_s);
+	print_helper(a_i % b_s);
+	print_helper(a_i & b_s);
+	print_helper(a_i | b_s);
+	print_helper(a_i ^ b_s);
+	print_helper(a_i == b_s);
+	print_helper(a_i != b_s);
+	print_helper(a_i < b_s);
+	print_helper(a_i <= b_s);
+	print_helper(a_i > b_s);
+	print_helper(a_i >= b_s);
+	print_helper(a_i + b_us);
+	print_helper(a_i - b_us);
+	print_helper(a_i * b_us);
+	print_helper(a_i / b_us);
+	print_helper(a_i % b_us);
+	print_helper(a_i & b_us);
+	print_helper(a_i | b_us);
+	print_helper(a_i ^ b_us);
+	print_helper(a_i == b_us);
+	print_helper(a_i != b_us);
+	print_helper(a_i < b_us);
+	print_helper(a_i <= b_us);
+	print_helper(a_i > b_us);
+	print_helper(a_i >= b_us);
+	print_helper(a_i + b_i);
+	print_helper(a_i - b_i);
+	print_helper(a_i * b_i);
+	print_helper(a_i / b_i);
+	print_helper(a_i % b_i);
+	print_helper(a_i & b_i);
+	print_helper(a_i | b_i);
+	print_helper(a_i ^ b_i);
+	print_helper(a_i == b_i);
+	print_helper(a_i != b_i);
+	print_helper(a_i < b_i);
+	print_helper(a_i <= b_i);
+	print_helper(a_i > b_i);
+	print_helper(a_i >= b_i);
+	print_helper(a_i + b_ui);
+	print_helper(a_i - b_ui);
+	print_helper(a_i * b_ui);
+	print_helper(a_i / b_ui);
+	print_helper(a_i % b_ui);
+	print_helper(a_i & b_ui);
+	print_helper(a_i | b_ui);
+	print_helper(a_i ^ b_ui);
+	print_helper(a_i == b_ui);
+	print_helper(a_i != b_ui);
+	print_helper(a_i < b_ui);
+	print_helper(a_i <= b_ui);
+	print_helper(a_i > b_ui);
+	print_helper(a_i >= b_ui);
+	print_helper(a_i + b_l);
+	print_helper(a_i - b_l);
+	print_helper(a_i * b_l);
+	print_helper(a_i / b_l);
+	print_helper(a_i % b_l);
+	print_helper(a_i & b_l);
+	print_helper(a_i | b_l);
+	print_helper(a_i ^ b_l);
+	print_helper(a_i == b_l);
+	print_helper(a_i != b_l);
+	print_helper(a_i < b_l);
+	print_helper(a_i <= b_l);
+	print_helper(a_i > b_l);
+	print_helper(a_i >= b_l);
+	print_helper(a_i + b_ul);
+	print_helper(a_i - b_ul);
+	print_helper(a_i * b_ul);
+	print_helper(a_i / b_ul);
+	print_helper(a_i % b_ul);
+	print_helper(a_i & b_ul);
+	print_helper(a_i | b_ul);
+	print_helper(a_i ^ b_ul);
+	print_helper(a_i == b_ul);
+	print_helper(a_i != b_ul);
+	print_helper(a_i < b_ul);
+	print_helper(a_i <= b_ul);
+	print_helper(a_i > b_ul);
+	print_helper(a_i >= b_ul);
+	print_helper(a_ui + b_c);
+	print_helper(a_ui - b_c);
+	print_helper(a_ui * b_c);
+	print_helper(a_ui / b_c);
+	print_helper(a_ui % b_c);
+	print_helper(a_ui & b_c);
+	print_helper(a_ui | b_c);
+	print_helper(a_ui ^ b_c);
+	print_helper(a_ui == b_c);
+	print_helper(a_ui != b_c);
+	print_helper(a_ui < b_c);
+	print_helper(a_ui <= b_c);
+	print_helper(a_ui > b_c);
+	print_helper(a_ui >= b_c);
+	print_helper(a_ui + b_uc);
+	print_helper(a_ui - b_uc);
+	print_helper(a_ui * b_uc);
+	print_helper(a_ui / b_uc);
+	print_helper(a_ui % b_uc);
+	print_helper(a_ui & b_uc);
+	print_helper(a_ui | b_uc);
+	print_helper(a_ui ^ b_uc);
+	print_helper(a_ui == b_uc);
+	print_helper(a_ui != b_uc);
+	print_helper(a_ui < b_uc);
+	print_helper(a_ui <= b_uc);
+	print_helper(a_ui > b_uc);
+	print_helper(a_ui >= b_uc);
+	print_helper(a_ui + b_s);
+	print_helper(a_ui - b_s);
+	print_helper(a_ui * b_s);
+	print_helper(a_ui / b_s);
+	print_helper(a_ui % b_s);
+	print_helper(a_ui & b_s);
+	print_helper(a_ui | b_s);
+	print_helper(a_ui ^ b_s);
+	print_helper(a_ui == b_s);
+	print_helper(a_ui != b_s);
+	print_helper(a_ui < b_s);
+	print_helper(a_ui <= b_s);
+	print_helper(a_ui > b_s);
+	print_helper(a_ui >= b_s);
+	print_helper(a_ui + b_us);
+	print_helper(a_ui - b_us);
+	print_helper(a_ui * b_us);
+	print_helper(a_ui / b_us);
+	print_helper(a_ui % b_us);
+	print_helper(a_ui & b_us);
+	print_helper(a_ui | b_us);
+	print_helper(a_ui ^ b_us);
+	print_helper(a_ui == b_us);
+	print_helper(a_ui != b_us);
+	print_helper(a_ui < b_us);
+	print_helper(a_ui <= b_us);
+	print_helper(a_ui > b_us);
+	print_helper(a_ui >= b_us);
+	print_helper(a_ui + b_i);
+	print_helper(a_ui - b_i);
+	print_helper(a_ui * b_i);
+	print_helper(a_ui / b_i);
+	print_helper(a_ui % b_i);
+	print_helper(a_ui & b_i);
+	print_helper(a_ui | b_i);
+	print_helper(a_ui ^ b_i);
+	print_helper(a_ui == b_i);
+	print_helper(a_ui != b_i);
+	print_helper(a_ui < b_i);
+	print_helper(a_ui <= b_i);
+	print_helper(a_ui > b_i);
+	print_helper(a_ui >= b_i);
+	print_helper(a_ui + b_ui);
+	print_helper(a_ui - b_ui);
+	print_helper(a_ui * b_ui);
+	print_helper(a_ui / b_ui);
+	print_helper(a_ui % b_ui);
+	print_helper(a_ui & b_ui);
+	print_helper(a_ui | b_ui);
+	print_helper(a_ui ^ b_ui);
+	print_helper(a_ui == b_ui);
+	print_helper(a_ui != b_ui);
+	print_helper(a_ui < b_ui);
+	print_helper(a_ui <= b_ui);
+	print_helper(a_ui > b_ui);
+	print_helper(a_ui >= b_ui);
+	print_helper(a_ui + b_l);
+	print_helper(a_ui - b_l);
+	print_helper(a_ui * b_l);
+	print_helper(a_ui / b_l);
+	print_helper(a_ui % b_l);
+	print_helper(a_ui & b_l);
+	print_helper(a_ui | b_l);
+	print_helper(a_ui ^ b_l);
+	print_helper(a_ui == b_l);
+	print_helper(a_ui != b_l);
+	print_helper(a_ui < b_l);
+	print_helper(a_ui <= b_l);
+	print_helper(a_ui > b_l);
+	print_helper(a_ui >= b_l);
+	print_helper(a_ui + b_ul);
+	print_helper(a_ui - b_ul);
+	print_helper(a_ui * b_ul);
+	print_helper(a_ui / b_ul);
+	print_helper(a_ui % b_ul);
+	print_helper(a_ui & b_ul);
+	print_helper(a_ui | b_ul);
+	print_helper(a_ui ^ b_ul);
+	print_helper(a_ui == b_ul);
+	print_helper(a_ui != b_ul);
+	print_helper(a_ui < b_ul);
+	print_helper(a_ui <= b_ul);
+	print_helper(a_ui > b_ul);
+	print_helper(a_ui >= b_ul);
+	print_helper(a_l + b_c);
+	print_helper(a_l - b_c);
+	print_helper(a_l * b_c);
+	print_helper(a_l / b_c);
+	print_helper(a_l % b_c);
+	print_helper(a_l & b_c);
+	print_helper(a_l | b_c);
+	print_helper(a_l ^ b_c);
+	print_helper(a_l == b_c);
+	print_helper(a_l != b_c);
+	print_helper(a_l < b_c);
+	print_helper(a_l <= b_c);
+	print_helper(a_l > b_c);
+	print_helper(a_l >= b_c);
+	print_helper(a_l + b_uc);
+	print_helper(a_l - b_uc);
+	print_helper(a_l * b_uc);
+	print_helper(a_l / b_uc);
+	print_helper(a_l % b_uc);
+	print_helper(a_l & b_uc);
+	print_helper(a_l | b_uc);
+	print_helper(a_l ^ b_uc);
+	print_helper(a_l == b_uc);
+	print_helper(a_l != b_uc);
+	print_helper(a_l < b_uc);
+	print_helper(a_l <= b_uc);
+	print_helper(a_l > b_uc);
+	print_helper(a_l >= b_uc);
+	print_helper(a_l + b_s);
+	print_helper(a_l - b_s);
+	print_helper(a_l * b_s);
+	print_helper(a_l / b_s);
+	print_helper(a_l % b_s);
+	print_helper(a_l & b_s);
+	print_helper(a_l | b_s);
+	print_helper(a_l ^ b_s);
+	print_helper(a_l == b_s);
+	print_helper(a_l != b_s);
+	print_helper(a_l < b_s);
+	print_helper(a_l <= b_s);
+	print_helper(a_l > b_s);
+	print_helper(a_l >= b_s);
+	print_helper(a_l + b_us);
+	print_helper(a_l - b_us);
+	print_helper(a_l * b_us);
+	print_helper(a_l / b_us);
+	print_helper(a_l % b_us);
+	print_helper(a_l & b_us);
+	print_helper(a_l | b_us);
+	print_helper(a_l ^ b_us);
+	print_helper(a_l == b_us);
+	print_helper(a_l != b_us);
+	print_helper(a_l < b_us);
+	print_helper(a_l <= b_us);
+	print_helper(a_l > b_us);
+	print_helper(a_l >= b_us);
+	print_helper(a_l + b_i);
+	print_helper(a_l - b_i);
+	print_helper(a_l * b_i);
+	print_helper(a_l / b_i);
+	print_helper(a_l % b_i);
+	print_helper(a_l & b_i);
+	print_helper(a_l | b_i);
+	print_helper(a_l ^ b_i);
+	print_helper(a_l == b_i);
+	print_helper(a_l != b_i);
+	print_helper(a_l < b_i);
+	print_helper(a_l <= b_i);
+	print_helper(a_l > b_i);
+	print_helper(a_l >= b_i);
+	print_helper(a_l + b_ui);
+	print_helper(a_l - b_ui);
+	print_helper(a_l * b_ui);
+	print_helper(a_l / b_ui);
+	print_helper(a_l % b_ui);
+	print_helper(a_l & b_ui);
+	print_helper(a_l | b_ui);
+	print_helper(a_l ^ b_ui);
+	print_helper(a_l == b_ui);
+	print_helper(a_l != b_ui);
+	print_helper(a_l < b_ui);
+	print_helper(a_l <= b_ui);
+	print_helper(a_l > b_ui);
+	print_helper(a_l >= b_ui);
+	print_helper(a_l + b_l);
+	print_helper(a_l - b_l);
+	print_helper(a_l * b_l);
+	print_helper(a_l / b_l);
+	print_helper(a_l % b_l);
+	print_helper(a_l & b_l);
+	print_helper(a_l | b_l);
+	print_helper(a_l ^ b_l);
+	print_helper(a_l == b_l);
+	print_helper(a_l != b_l);
+	print_helper(a_l < b_l);
+	print_helper(a_l <= b_l);
+	print_helper(a_l > b_l);
+	print_helper(a_l >= b_l);
+	print_helper(a_l + b_ul);
+	print_helper(a_l - b_ul);
+	print_helper(a_l * b_ul);
+	print_helper(a_l / b_ul);
+	print_helper(a_l % b_ul);
+	print_helper(a_l & b_ul);
+	print_helper(a_l | b_ul);
+	print_helper(a_l ^ b_ul);
+	print_helper(a_l == b_ul);
+	print_helper(a_l != b_ul);
+	print_helper(a_l < b_ul);
+	print_helper(a_l <= b_ul);
+	print_helper(a_l > b_ul);
+	print_helper(a_l >= b_ul);
+	print_helper(a_ul + b_c);
+	print_helper(a_ul - b_c);
+	print_helper(a_ul * b_c);
+	print_helper(a_ul / b_c);
+	print_helper(a_ul % b_c);
+	print_helper(a_ul & b_c);
+	print_helper(a_ul | b_c);
+	print_helper(a_ul ^ b_c);
+	print_helper(a_ul == b_c);
+	print_helper(a_ul != b_c);
+	print_helper(a_ul < b_c);
+	print_helper(a_ul <= b_c);
+	print_helper(a_ul > b_c);
+	print_helper(a_ul >= b_c);
+	print_helper(a_ul + b_uc);
+	print_helper(a_ul - b_uc);
+	print_helper(a_ul * b_uc);
+	print_helper(a_ul / b_uc);
+	print_helper(a_ul % b_uc);
+	print_helper(a_ul & b_uc);
+	print_helper(a_ul | b_uc);
+	print_helper(a_ul ^ b_uc);
+	print_helper(a_ul == b_uc);
+	print_helper(a_ul != b_uc);
+	print_helper(a_ul < b_uc);
+	print_helper(a_ul <= b_uc);
+	print_helper(a_ul > b_uc);
+	print_helper(a_ul >= b_uc);
+	print_helper(a_ul + b_s);
+	print_helper(a_ul - b_s);
+	print_helper(a_ul * b_s);
+	print_helper(a_ul / b_s);
+	print_helper(a_ul % b_s);
+	print_helper(a_ul & b_s);
+	print_helper(a_ul | b_s);
+	print_helper(a_ul ^ b_s);
+	print_helper(a_ul == b_s);
+	print_helper(a_ul != b_s);
+	print_helper(a_ul < b_s);
+	print_helper(a_ul <= b_s);
+	print_helper(a_ul > b_s);
+	print_helper(a_ul >= b_s);
+	print_helper(a_ul + b_us);
+	print_helper(a_ul - b_us);
+	print_helper(a_ul * b_us);
+	print_helper(a_ul / b_us);
+	print_helper(a_ul % b_us);
+	print_helper(a_ul & b_us);
+	print_helper(a_ul | b_us);
+	print_helper(a_ul ^ b_us);
+	print_helper(a_ul == b_us);
+	print_helper(a_ul != b_us);
+	print_helper(a_ul < b_us);
+	print_helper(a_ul <= b_us);
+	print_helper(a_ul > b_us);
+	print_helper(a_ul >= b_us);
+	print_helper(a_ul + b_i);
+	print_helper(a_ul - b_i);
+	print_helper(a_ul * b_i);
+	print_helper(a_ul / b_i);
+	print_helper(a_ul % b_i);
+	print_helper(a_ul & b_i);
+	print_helper(a_ul | b_i);
+	print_helper(a_ul ^ b_i);
+	print_helper(a_ul == b_i);
+	print_helper(a_ul != b_i);
+	print_helper(a_ul < b_i);
+	print_helper(a_ul <= b_i);
+	print_helper(a_ul > b_i);
+	print_helper(a_ul >= b_i);
+	print_helper(a_ul + b_ui);
+	print_helper(a_ul - b_ui);
+	print_helper(a_ul * b_ui);
+	print_helper(a_ul / b_ui);
+	print_helper(a_ul % b_ui);
+	print_helper(a_ul & b_ui);
+	print_helper(a_ul | b_ui);
+	print_helper(a_ul ^ b_ui);
+	print_helper(a_ul == b_ui);
+	print_helper(a_ul != b_ui);
+	print_helper(a_ul < b_ui);
+	print_helper(a_ul <= b_ui);
+	print_helper(a_ul > b_ui);
+	print_helper(a_ul >= b_ui);
+	print_helper(a_ul + b_l);
+	print_helper(a_ul - b_l);
+	print_helper(a_ul * b_l);
+	print_helper(a_ul / b_l);
+	print_helper(a_ul % b_l);
+	print_helper(a_ul & b_l);
+	print_helper(a_ul | b_l);
+	print_helper(a_ul ^ b_l);
+	print_helper(a_ul == b_l);
+	print_helper(a_ul != b_l);
+	print_helper(a_ul < b_l);
+	print_helper(a_ul <= b_l);
+	print_helper(a_ul > b_l);
+	print_helper(a_ul >= b_l);
+	print_helper(a_ul + b_ul);
+	print_helper(a_ul - b_ul);
+	print_helper(a_ul * b_ul);
+	print_helper(a_ul / b_ul);
+	print_helper(a_ul % b_ul);
+	print_helper(a_ul & b_ul);
+	print_helper(a_ul | b_ul);
+	print_helper(a_ul ^ b_ul);
+	print_helper(a_ul == b_ul);
+	print_helper(a_ul != b_ul);
+	print_helper(a_ul < b_ul);
+	print_helper(a_ul <= b_ul);
+	print_helper(a_ul > b_ul);
+	print_helper(a_ul >= b_ul);
+	return 0;
+}
+
+int binary2() {
+	char a_c;
+	char b_c;
+	a_c = 2000000000;
+	b_c = 70000;
+	unsigned char a_uc;
+	unsigned char b_uc;
+	a_uc = 2000000000;
+	b_uc = 70000;
+	short a_s;
+	short b_s;
+	a_s = 2000000000;
+	b_s = 70000;
+	unsigned short a_us;
+	unsigned short b_us;
+	a_us = 2000000000;
+	b_us = 70000;
+	int a_i;
+	int b_i;
+	a_i = 2000000000;
+	b_i = 70000;
+	unsigned int a_ui;
+	unsigned int b_ui;
+	a_ui = 2000000000;
+	b_ui = 70000;
+	long a_l;
+	long b_l;
+	a_l = 2000000000;
+	b_l = 70000;
+	unsigned long a_ul;
+	unsigned long b_ul;
+	a_ul = 2000000000;
+	b_ul = 70000;
+	print_helper(a_c + b_c);
+	print_helper(a_c - b_c);
+	print_helper(a_c * b_c);
+	print_helper(a_c / b_c);
+	print_helper(a_c % b_c);
+	print_helper(a_c & b_c);
+	print_helper(a_c | b_c);
+	print_helper(a_c ^ b_c);
+	print_helper(a_c == b_c);
+	print_helper(a_c != b_c);
+	print_helper(a_c < b_c);
+	print_helper(a_c <= b_c);
+	print_helper(a_c > b_c);
+	print_helper(a_c >= b_c);
+	print_helper(a_c + b_uc);
+	print_helper(a_c - b_uc);
+	print_helper(a_c * b_uc);
+	print_helper(a_c / b_uc);
+	print_helper(a_c % b_uc);
+	print_helper(a_c & b_uc);
+	print_helper(a_c | b_uc);
+	print_helper(a_c ^ b_uc);
+	print_helper(a_c == b_uc);
+	print_helper(a_c != b_uc);
+	print_helper(a_c < b_uc);
+	print_helper(a_c <= b_uc);
+	print_helper(a_c > b_uc);
+	print_helper(a_c >= b_uc);
+	print_helper(a_c + b_s);
+	print_helper(a_c - b_s);
+	print_helper(a_c * b_s);
+	print_helper(a_c / b_s);
+	print_helper(a_c % b_s);
+	print_helper(a_c & b_s);
+	print_helper(a_c | b_s);
+	print_helper(a_c ^ b_s);
+	print_helper(a_c == b_s);
+	print_helper(a_c != b_s);
+	print_helper(a_c < b_s);
+	print_helper(a_c <= b_s);
+	print_helper(a_c > b_s);
+	print_helper(a_c >= b_s);
+	print_helper(a_c + b_us);
+	print_helper(a_c - b_us);
+	print_helper(a_c * b_us);
+	print_helper(a_c / b_us);
+	print_helper(a_c % b_us);
+	print_helper(a_c & b_us);
+	print_helper(a_c | b_us);
+	print_helper(a_c ^ b_us);
+	print_helper(a_c == b_us);
+	print_helper(a_c != b_us);
+	print_helper(a_c < b_us);
+	print_helper(a_c <= b_us);
+	print_helper(a_c > b_us);
+	print_helper(a_c >= b_us);
+	print_helper(a_c + b_i);
+	print_helper(a_c - b_i);
+	print_helper(a_c * b_i);
+	print_helper(a_c / b_i);
+	print_helper(a_c % b_i);
+	print_helper(a_c & b_i);
+	print_helper(a_c | b_i);
+	print_helper(a_c ^ b_i);
+	print_helper(a_c == b_i);
+	print_helper(a_c != b_i);
+	print_helper(a_c < b_i);
+	print_helper(a_c <= b_i);
+	print_helper(a_c > b_i);
+	print_helper(a_c >= b_i);
+	print_helper(a_c + b_ui);
+	print_helper(a_c - b_ui);
+	print_helper(a_c * b_ui);
+	print_helper(a_c / b_ui);
+	print_helper(a_c % b_ui);
+	print_helper(a_c & b_ui);
+	print_helper(a_c | b_ui);
+	print_helper(a_c ^ b_ui);
+	print_helper(a_c == b_ui);
+	print_helper(a_c != b_ui);
+	print_helper(a_c < b_ui);
+	print_helper(a_c <= b_ui);
+	print_helper(a_c > b_ui);
+	print_helper(a_c >= b_ui);
+	print_helper(a_c + b_l);
+	print_helper(a_c - b_l);
+	print_helper(a_c * b_l);
+	print_helper(a_c / b_l);
+	print_helper(a_c % b_l);
+	print_helper(a_c & b_l);
+	print_helper(a_c | b_l);
+	print_helper(a_c ^ b_l);
+	print_helper(a_c == b_l);
+	print_helper(a_c != b_l);
+	print_helper(a_c < b_l);
+	print_helper(a_c <= b_l);
+	print_helper(a_c > b_l);
+	print_helper(a_c >= b_l);
+	print_helper(a_c + b_ul);
+	print_helper(a_c - b_ul);
+	print_helper(a_c * b_ul);
+	print_helper(a_c / b_ul);
+	print_helper(a_c % b_ul);
+	print_helper(a_c & b_ul);
+	print_helper(a_c | b_ul);
+	print_helper(a_c ^ b_ul);
+	print_helper(a_c == b_ul);
+	print_helper(a_c != b_ul);
+	print_helper(a_c < b_ul);
+	print_helper(a_c <= b_ul);
+	print_helper(a_c > b_ul);
+	print_helper(a_c >= b_ul);
+	print_helper(a_uc + b_c);
+	print_helper(a_uc - b_c);
+	print_helper(a_uc * b_c);
+	print_helper(a_uc / b_c);
+	print_helper(a_uc % b_c);
+	print_helper(a_uc & b_c);
+	print_helper(a_uc | b_c);
+	print_helper(a_uc ^ b_c);
+	print_helper(a_uc == b_c);
+	print_helper(a_uc != b_c);
+	print_helper(a_uc < b_c);
+	print_helper(a_uc <= b_c);
+	print_helper(a_uc > b_c);
+	print_helper(a_uc >= b_c);
+	print_helper(a_uc + b_uc);
+	print_helper(a_uc - b_uc);
+	print_helper(a_uc * b_uc);
+	print_helper(a_uc / b_uc);
+	print_helper(a_uc % b_uc);
+	print_helper(a_uc & b_uc);
+	print_helper(a_uc | b_uc);
+	print_helper(a_uc ^ b_uc);
+	print_helper(a_uc == b_uc);
+	print_helper(a_uc != b_uc);
+	print_helper(a_uc < b_uc);
+	print_helper(a_uc <= b_uc);
+	print_helper(a_uc > b_uc);
+	print_helper(a_uc >= b_uc);
+	print_helper(a_uc + b_s);
+	print_helper(a_uc - b_s);
+	print_helper(a_uc * b_s);
+	print_helper(a_uc / b_s);
+	print_helper(a_uc % b_s);
+	print_helper(a_uc & b_s);
+	print_helper(a_uc | b_s);
+	print_helper(a_uc ^ b_s);
+	print_helper(a_uc == b_s);
+	print_helper(a_uc != b_s);
+	print_helper(a_uc < b_s);
+	print_helper(a_uc <= b_s);
+	print_helper(a_uc > b_s);
+	print_helper(a_uc >= b_s);
+	print_helper(a_uc + b_us);
+	print_helper(a_uc - b_us);
+	print_helper(a_uc * b_us);
+	print_helper(a_uc / b_us);
+	print_helper(a_uc % b_us);
+	print_helper(a_uc & b_us);
+	print_helper(a_uc | b_us);
+	print_helper(a_uc ^ b_us);
+	print_helper(a_uc == b_us);
+	print_helper(a_uc != b_us);
+	print_helper(a_uc < b_us);
+	print_helper(a_uc <= b_us);
+	print_helper(a_uc > b_us);
+	print_helper(a_uc >= b_us);
+	print_helper(a_uc + b_i);
+	print_helper(a_uc - b_i);
+	print_helper(a_uc * b_i);
+	print_helper(a_uc / b_i);
+	print_helper(a_uc % b_i);
+	print_helper(a_uc & b_i);
+	print_helper(a_uc | b_i);
+	print_helper(a_uc ^ b_i);
+	print_helper(a_uc == b_i);
+	print_helper(a_uc != b_i);
+	print_helper(a_uc < b_i);
+	print_helper(a_uc <= b_i);
+	print_helper(a_uc > b_i);
+	print_helper(a_uc >= b_i);
+	print_helper(a_uc + b_ui);
+	print_helper(a_uc - b_ui);
+	print_helper(a_uc * b_ui);
+	print_helper(a_uc / b_ui);
+	print_helper(a_uc % b_ui);
+	print_helper(a_uc & b_ui);
+	print_helper(a_uc | b_ui);
+	print_helper(a_uc ^ b_ui);
+	print_helper(a_uc == b_ui);
+	print_helper(a_uc != b_ui);
+	print_helper(a_uc < b_ui);
+	print_helper(a_uc <= b_ui);
+	print_helper(a_uc > b_ui);
+	print_helper(a_uc >= b_ui);
+	print_helper(a_uc + b_l);
+	print_helper(a_uc - b_l);
+	print_helper(a_uc * b_l);
+	print_helper(a_uc / b_l);
+	print_helper(a_uc % b_l);
+	print_helper(a_uc & b_l);
+	print_helper(a_uc | b_l);
+	print_helper(a_uc ^ b_l);
+	print_helper(a_uc == b_l);
+	print_helper(a_uc != b_l);
+	print_helper(a_uc < b_l);
+	print_helper(a_uc <= b_l);
+	print_helper(a_uc > b_l);
+	print_helper(a_uc >= b_l);
+	print_helper(a_uc + b_ul);
+	print_helper(a_uc - b_ul);
+	print_helper(a_uc * b_ul);
+	print_helper(a_uc / b_ul);
+	print_helper(a_uc % b_ul);
+	print_helper(a_uc & b_ul);
+	print_helper(a_uc | b_ul);
+	print_helper(a_uc ^ b_ul);
+	print_helper(a_uc == b_ul);
+	print_helper(a_uc != b_ul);
+	print_helper(a_uc < b_ul);
+	print_helper(a_uc <= b_ul);
+	print_helper(a_uc > b_ul);
+	print_helper(a_uc >= b_ul);
+	print_helper(a_s + b_c);
+	print_helper(a_s - b_c);
+	print_helper(a_s * b_c);
+	print_helper(a_s / b_c);
+	print_helper(a_s % b_c);
+	print_helper(a_s & b_c);
+	print_helper(a_s | b_c);
+	print_helper(a_s ^ b_c);
+	print_helper(a_s == b_c);
+	print_helper(a_s != b_c);
+	print_helper(a_s < b_c);
+	print_helper(a_s <= b_c);
+	print_helper(a_s > b_c);
+	print_helper(a_s >= b_c);
+	print_helper(a_s + b_uc);
+	print_helper(a_s - b_uc);
+	print_helper(a_s * b_uc);
+	print_helper(a_s / b_uc);
+	print_helper(a_s % b_uc);
+	print_helper(a_s & b_uc);
+	print_helper(a_s | b_uc);
+	print_helper(a_s ^ b_uc);
+	print_helper(a_s == b_uc);
+	print_helper(a_s != b_uc);
+	print_helper(a_s < b_uc);
+	print_helper(a_s <= b_uc);
+	print_helper(a_s > b_uc);
+	print_helper(a_s >= b_uc);
+	print_helper(a_s + b_s);
+	print_helper(a_s - b_s);
+	print_helper(a_s * b_s);
+	print_helper(a_s / b_s);
+	print_helper(a_s % b_s);
+	print_helper(a_s & b_s);
+	print_helper(a_s | b_s);
+	print_helper(a_s ^ b_s);
+	print_helper(a_s == b_s);
+	print_helper(a_s != b_s);
+	print_helper(a_s < b_s);
+	print_helper(a_s <= b_s);
+	print_helper(a_s > b_s);
+	print_helper(a_s >= b_s);
+	print_helper(a_s + b_us);
+	print_helper(a_s - b_us);
+	print_helper(a_s * b_us);
+	print_helper(a_s / b_us);
+	print_helper(a_s % b_us);
+	print_helper(a_s & b_us);
+	print_helper(a_s | b_us);
+	print_helper(a_s ^ b_us);
+	print_helper(a_s == b_us);
+	print_helper(a_s != b_us);
+	print_helper(a_s < b_us);
+	print_helper(a_s <= b_us);
+	print_helper(a_s > b_us);
+	print_helper(a_s >= b_us);
+	print_helper(a_s + b_i);
+	print_helper(a_s - b_i);
+	print_helper(a_s * b_i);
+	print_helper(a_s / b_i);
+	print_helper(a_s % b_i);
+	print_helper(a_s & b_i);
+	print_helper(a_s | b_i);
+	print_helper(a_s ^ b_i);
+	print_helper(a_s == b_i);
+	print_helper(a_s != b_i);
+	print_helper(a_s < b_i);
+	print_helper(a_s <= b_i);
+	print_helper(a_s > b_i);
+	print_helper(a_s >= b_i);
+	print_helper(a_s + b_ui);
+	print_helper(a_s - b_ui);
+	print_helper(a_s * b_ui);
+	print_helper(a_s / b_ui);
+	print_helper(a_s % b_ui);
+	print_helper(a_s & b_ui);
+	print_helper(a_s | b_ui);
+	print_helper(a_s ^ b_ui);
+	print_helper(a_s == b_ui);
+	print_helper(a_s != b_ui);
+	print_helper(a_s < b_ui);
+	print_helper(a_s <= b_ui);
+	print_helper(a_s > b_ui);
+	print_helper(a_s >= b_ui);
+	print_helper(a_s + b_l);
+	print_helper(a_s - b_l);
+	print_helper(a_s * b_l);
+	print_helper(a_s / b_l);
+	print_helper(a_s % b_l);
+	print_helper(a_s & b_l);
+	print_helper(a_s | b_l);
+	print_helper(a_s ^ b_l);
+	print_helper(a_s == b_l);
+	print_helper(a_s != b_l);
+	print_helper(a_s < b_l);
+	print_helper(a_s <= b_l);
+	print_helper(a_s > b_l);
+	print_helper(a_s >= b_l);
+	print_helper(a_s + b_ul);
+	print_helper(a_s - b_ul);
+	print_helper(a_s * b_ul);
+	print_helper(a_s / b_ul);
+	print_helper(a_s % b_ul);
+	print_helper(a_s & b_ul);
+	print_helper(a_s | b_ul);
+	print_helper(a_s ^ b_ul);
+	print_helper(a_s == b_ul);
+	print_helper(a_s != b_ul);
+	print_helper(a_s < b_ul);
+	print_helper(a_s <= b_ul);
+	print_helper(a_s > b_ul);
+	print_helper(a_s >= b_ul);
+	print_helper(a_us + b_c);
+	print_helper(a_us - b_c);
+	print_helper(a_us * b_c);
+	print_helper(a_us / b_c);
+	print_helper(a_us % b_c);
+	print_helper(a_us & b_c);
+	print_helper(a_us | b_c);
+	print_helper(a_us ^ b_c);
+	print_helper(a_us == b_c);
+	print_helper(a_us != b_c);
+	print_helper(a_us < b_c);
+	print_helper(a_us <= b_c);
+	print_helper(a_us > b_c);
+	print_helper(a_us >= b_c);
+	print_helper(a_us + b_uc);
+	print_helper(a_us - b_uc);
+	print_helper(a_us * b_uc);
+	print_helper(a_us / b_uc);
+	print_helper(a_us % b_uc);
+	print_helper(a_us & b_uc);
+	print_helper(a_us | b_uc);
+	print_helper(a_us ^ b_uc);
+	print_helper(a_us == b_uc);
+	print_helper(a_us != b_uc);
+	print_helper(a_us < b_uc);
+	print_helper(a_us <= b_uc);
+	print_helper(a_us > b_uc);
+	print_helper(a_us >= b_uc);
+	print_helper(a_us + b_s);
+	print_helper(a_us - b_s);
+	print_helper(a_us * b_s);
+	print_helper(a_us / b_s);
+	print_helper(a_us % b_s);
+	print_helper(a_us & b_s);
+	print_helper(a_us | b_s);
+	print_helper(a_us ^ b_s);
+	print_helper(a_us == b_s);
+	print_helper(a_us != b_s);
+	print_helper(a_us < b_s);
+	print_helper(a_us <= b_s);
+	print_helper(a_us > b_s);
+	print_helper(a_us >= b_s);
+	print_helper(a_us + b_us);
+	print_helper(a_us - b_us);
+	print_helper(a_us * b_us);
+	print_helper(a_us / b_us);
+	print_helper(a_us % b_us);
+	print_helper(a_us & b_us);
+	print_helper(a_us | b_us);
+	print_helper(a_us ^ b_us);
+	print_helper(a_us == b_us);
+	print_helper(a_us != b_us);
+	print_helper(a_us < b_us);
+	print_helper(a_us <= b_us);
+	print_helper(a_us > b_us);
+	print_helper(a_us >= b_us);
+	print_helper(a_us + b_i);
+	print_helper(a_us - b_i);
+	print_helper(a_us * b_i);
+	print_helper(a_us / b_i);
+	print_helper(a_us % b_i);
+	print_helper(a_us & b_i);
+	print_helper(a_us | b_i);
+	print_helper(a_us ^ b_i);
+	print_helper(a_us == b_i);
+	print_helper(a_us != b_i);
+	print_helper(a_us < b_i);
+	print_helper(a_us <= b_i);
+	print_helper(a_us > b_i);
+	print_helper(a_us >= b_i);
+	print_helper(a_us + b_ui);
+	print_helper(a_us - b_ui);
+	print_helper(a_us * b_ui);
+	print_helper(a_us / b_ui);
+	print_helper(a_us % b_ui);
+	print_helper(a_us & b_ui);
+	print_helper(a_us | b_ui);
+	print_helper(a_us ^ b_ui);
+	print_helper(a_us == b_ui);
+	print_helper(a_us != b_ui);
+	print_helper(a_us < b_ui);
+	print_helper(a_us <= b_ui);
+	print_helper(a_us > b_ui);
+	print_helper(a_us >= b_ui);
+	print_helper(a_us + b_l);
+	print_helper(a_us - b_l);
+	print_helper(a_us * b_l);
+	print_helper(a_us / b_l);
+	print_helper(a_us % b_l);
+	print_helper(a_us & b_l);
+	print_helper(a_us | b_l);
+	print_helper(a_us ^ b_l);
+	print_helper(a_us == b_l);
+	print_helper(a_us != b_l);
+	print_helper(a_us < b_l);
+	print_helper(a_us <= b_l);
+	print_helper(a_us > b_l);
+	print_helper(a_us >= b_l);
+	print_helper(a_us + b_ul);
+	print_helper(a_us - b_ul);
+	print_helper(a_us * b_ul);
+	print_helper(a_us / b_ul);
+	print_helper(a_us % b_ul);
+	print_helper(a_us & b_ul);
+	print_helper(a_us | b_ul);
+	print_helper(a_us ^ b_ul);
+	print_helper(a_us == b_ul);
+	print_helper(a_us != b_ul);
+	print_helper(a_us < b_ul);
+	print_helper(a_us <= b_ul);
+	print_helper(a_us > b_ul);
+	print_helper(a_us >= b_ul);
+	print_helper(a_i + b_c);
+	print_helper(a_i - b_c);
+	print_helper(a_i * b_c);
+	print_helper(a_i / b_c);
+	print_helper(a_i % b_c);
+	print_helper(a_i & b_c);
+	print_helper(a_i | b_c);
+	print_helper(a_i ^ b_c);
+	print_helper(a_i == b_c);
+	print_helper(a_i != b_c);
+	print_helper(a_i < b_c);
+	print_helper(a_i <= b_c);
+	print_helper(a_i > b_c);
+	print_helper(a_i >= b_c);
+	print_helper(a_i + b_uc);
+	print_helper(a_i - b_uc);
+	print_helper(a_i * b_uc);
+	print_helper(a_i / b_uc);
+	print_helper(a_i % b_uc);
+	print_helper(a_i & b_uc);
+	print_helper(a_i | b_uc);
+	print_helper(a_i ^ b_uc);
+	print_helper(a_i == b_uc);
+	print_helper(a_i != b_uc);
+	print_helper(a_i < b_uc);
+	print_helper(a_i <= b_uc);
+	print_helper(a_i > b_uc);
+	print_helper(a_i >= b_uc);
+	print_helper(a_i + b_s);
+	print_helper(a_i - b_s);
+	print_helper(a_i * b_s);
+	print_helper(a_i / b_s);
+	print_helper(a_i % b_s);
+	print_helper(a_i & b_s);
+	print_helper(a_i | b_s);
+	print_helper(a_i ^ b_s);
+	print_helper(a_i == b_s);
+	print_helper(a_i != b_s);
+	print_helper(a_i < b_s);
+	print_helper(a_i <= b_s);
+	print_helper(a_i > b_s);
+	print_helper(a_i >= b_s);
+	print_helper(a_i + b_us);
+	print_helper(a_i - b_us);
+	print_helper(a_i * b_us);
+	print_helper(a_i / b_us);
+	print_helper(a_i % b_us);
+	print_helper(a_i & b_us);
+	print_helper(a_i | b_us);
+	print_helper(a_i ^ b_us);
+	print_helper(a_i == b_us);
+	print_helper(a_i != b_us);
+	print_helper(a_i < b_us);
+	print_helper(a_i <= b_us);
+	print_helper(a_i > b_us);
+	print_helper(a_i >= b_us);
+	print_helper(a_i + b_i);
+	print_helper(a_i - b_i);
+	print_helper(a_i * b_i);
+	print_helper(a_i / b_i);
+	print_helper(a_i % b_i);
+	print_helper(a_i & b_i);
+	print_helper(a_i | b_i);
+	print_helper(a_i ^ b_i);
+	print_helper(a_i == b_i);
+	print_helper(a_i != b_i);
+	print_helper(a_i < b_i);
+	print_helper(a_i <= b_i);
+	print_helper(a_i > b_i);
+	print_helper(a_i >= b_i);
+	print_helper(a_i + b_ui);
+	print_helper(a_i - b_ui);
+	print_helper(a_i * b_ui);
+	print_helper(a_i / b_ui);
+	print_helper(a_i % b_ui);
+	print_helper(a_i & b_ui);
+	print_helper(a_i | b_ui);
+	print_helper(a_i ^ b_ui);
+	print_helper(a_i == b_ui);
+	print_helper(a_i != b_ui);
+	print_helper(a_i < b_ui);
+	print_helper(a_i <= b_ui);
+	print_helper(a_i > b_ui);
+	print_helper(a_i >= b_ui);
+	print_helper(a_i + b_l);
+	print_helper(a_i - b_l);
+	print_helper(a_i * b_l);
+	print_helper(a_i / b_l);
+	print_helper(a_i % b_l);
+	print_helper(a_i & b_l);
+	print_helper(a_i | b_l);
+	print_helper(a_i ^ b_l);
+	print_helper(a_i == b_l);
+	print_helper(a_i != b_l);
+	print_helper(a_i < b_l);
+	print_helper(a_i <= b_l);
+	print_helper(a_i > b_l);
+	print_helper(a_i >= b_l);
+	print_helper(a_i + b_ul);
+	print_helper(a_i - b_ul);
+	print_helper(a_i * b_ul);
+	print_helper(a_i / b_ul);
+	print_helper(a_i % b_ul);
+	print_helper(a_i & b_ul);
+	print_helper(a_i | b_ul);
+	print_helper(a_i ^ b_ul);
+	print_helper(a_i == b_ul);
+	print_helper(a_i != b_ul);
+	print_helper(a_i < b_ul);
+	print_helper(a_i <= b_ul);
+	print_helper(a_i > b_ul);
+	print_helper(a_i >= b_ul);
+	print_helper(a_ui + b_c);
+	print_helper(a_ui - b_c);
+	print_helper(a_ui * b_c);
+	print_helper(a_ui / b_c);
+	print_helper(a_ui % b_c);
+	print_helper(a_ui & b_c);
+	print_helper(a_ui | b_c);
+	print_helper(a_ui ^ b_c);
+	print_helper(a_ui == b_c);
+	print_helper(a_ui != b_c);
+	print_helper(a_ui < b_c);
+	print_helper(a_ui <= b_c);
+	print_helper(a_ui > b_c);
+	print_helper(a_ui >= b_c);
+	print_helper(a_ui + b_uc);
+	print_helper(a_ui - b_uc);
+	print_helper(a_ui * b_uc);
+	print_helper(a_ui / b_uc);
+	print_helper(a_ui % b_uc);
+	print_helper(a_ui & b_uc);
+	print_helper(a_ui | b_uc);
+	print_helper(a_ui ^ b_uc);
+	print_helper(a_ui == b_uc);
+	print_helper(a_ui != b_uc);
+	print_helper(a_ui < b_uc);
+	print_helper(a_ui <= b_uc);
+	print_helper(a_ui > b_uc);
+	print_helper(a_ui >= b_uc);
+	print_helper(a_ui + b_s);
+	print_helper(a_ui - b_s);
+	print_helper(a_ui * b_s);
+	print_helper(a_ui / b_s);
+	print_helper(a_ui % b_s);
+	print_helper(a_ui & b_s);
+	print_helper(a_ui | b_s);
+	print_helper(a_ui ^ b_s);
+	print_helper(a_ui == b_s);
+	print_helper(a_ui != b_s);
+	print_helper(a_ui < b_s);
+	print_helper(a_ui <= b_s);
+	print_helper(a_ui > b_s);
+	print_helper(a_ui >= b_s);
+	print_helper(a_ui + b_us);
+	print_helper(a_ui - b_us);
+	print_helper(a_ui * b_us);
+	print_helper(a_ui / b_us);
+	print_helper(a_ui % b_us);
+	print_helper(a_ui & b_us);
+	print_helper(a_ui | b_us);
+	print_helper(a_ui ^ b_us);
+	print_helper(a_ui == b_us);
+	print_helper(a_ui != b_us);
+	print_helper(a_ui < b_us);
+	print_helper(a_ui <= b_us);
+	print_helper(a_ui > b_us);
+	print_helper(a_ui >= b_us);
+	print_helper(a_ui + b_i);
+	print_helper(a_ui - b_i);
+	print_helper(a_ui * b_i);
+	print_helper(a_ui / b_i);
+	print_helper(a_ui % b_i);
+	print_helper(a_ui & b_i);
+	print_helper(a_ui | b_i);
+	print_helper(a_ui ^ b_i);
+	print_helper(a_ui == b_i);
+	print_helper(a_ui != b_i);
+	print_helper(a_ui < b_i);
+	print_helper(a_ui <= b_i);
+	print_helper(a_ui > b_i);
+	print_helper(a_ui >= b_i);
+	print_helper(a_ui + b_ui);
+	print_helper(a_ui - b_ui);
+	print_helper(a_ui * b_ui);
+	print_helper(a_ui / b_ui);
+	print_helper(a_ui % b_ui);
+	print_helper(a_ui & b_ui);
+	print_helper(a_ui | b_ui);
+	print_helper(a_ui ^ b_ui);
+	print_helper(a_ui == b_ui);
+	print_helper(a_ui != b_ui);
+	print_helper(a_ui < b_ui);
+	print_helper(a_ui <= b_ui);
+	print_helper(a_ui > b_ui);
+	print_helper(a_ui >= b_ui);
+	print_helper(a_ui + b_l);
+	print_helper(a_ui - b_l);
+	print_helper(a_ui * b_l);
+	print_helper(a_ui / b_l);
+	print_helper(a_ui % b_l);
+	print_helper(a_ui & b_l);
+	print_helper(a_ui | b_l);
+	print_helper(a_ui ^ b_l);
+	print_helper(a_ui == b_l);
+	print_helper(a_ui != b_l);
+	print_helper(a_ui < b_l);
+	print_helper(a_ui <= b_l);
+	print_helper(a_ui > b_l);
+	print_helper(a_ui >= b_l);
+	print_helper(a_ui + b_ul);
+	print_helper(a_ui - b_ul);
+	print_helper(a_ui * b_ul);
+	print_helper(a_ui / b_ul);
+	print_helper(a_ui % b_ul);
+	print_helper(a_ui & b_ul);
+	print_helper(a_ui | b_ul);
+	print_helper(a_ui ^ b_ul);
+	print_helper(a_ui == b_ul);
+	print_helper(a_ui != b_ul);
+	print_helper(a_ui < b_ul);
+	print_helper(a_ui <= b_ul);
+	print_helper(a_ui > b_ul);
+	print_helper(a_ui >= b_ul);
+	print_helper(a_l + b_c);
+	print_helper(a_l - b_c);
+	print_helper(a_l * b_c);
+	print_helper(a_l / b_c);
+	print_helper(a_l % b_c);
+	print_helper(a_l & b_c);
+	print_helper(a_l | b_c);
+	print_helper(a_l ^ b_c);
+	print_helper(a_l == b_c);
+	print_helper(a_l != b_c);
+	print_helper(a_l < b_c);
+	print_helper(a_l <= b_c);
+	print_helper(a_l > b_c);
+	print_helper(a_l >= b_c);
+	print_helper(a_l + b_uc);
+	print_helper(a_l - b_uc);
+	print_helper(a_l * b_uc);
+	print_helper(a_l / b_uc);
+	print_helper(a_l % b_uc);
+	print_helper(a_l & b_uc);
+	print_helper(a_l | b_uc);
+	print_helper(a_l ^ b_uc);
+	print_helper(a_l == b_uc);
+	print_helper(a_l != b_uc);
+	print_helper(a_l < b_uc);
+	print_helper(a_l <= b_uc);
+	print_helper(a_l > b_uc);
+	print_helper(a_l >= b_uc);
+	print_helper(a_l + b_s);
+	print_helper(a_l - b_s);
+	print_helper(a_l * b_s);
+	print_helper(a_l / b_s);
+	print_helper(a_l % b_s);
+	print_helper(a_l & b_s);
+	print_helper(a_l | b_s);
+	print_helper(a_l ^ b_s);
+	print_helper(a_l == b_s);
+	print_helper(a_l != b_s);
+	print_helper(a_l < b_s);
+	print_helper(a_l <= b_s);
+	print_helper(a_l > b_s);
+	print_helper(a_l >= b_s);
+	print_helper(a_l + b_us);
+	print_helper(a_l - b_us);
+	print_helper(a_l * b_us);
+	print_helper(a_l / b_us);
+	print_helper(a_l % b_us);
+	print_helper(a_l & b_us);
+	print_helper(a_l | b_us);
+	print_helper(a_l ^ b_us);
+	print_helper(a_l == b_us);
+	print_helper(a_l != b_us);
+	print_helper(a_l < b_us);
+	print_helper(a_l <= b_us);
+	print_helper(a_l > b_us);
+	print_helper(a_l >= b_us);
+	print_helper(a_l + b_i);
+	print_helper(a_l - b_i);
+	print_helper(a_l * b_i);
+	print_helper(a_l / b_i);
+	print_helper(a_l % b_i);
+	print_helper(a_l & b_i);
+	print_helper(a_l | b_i);
+	print_helper(a_l ^ b_i);
+	print_helper(a_l == b_i);
+	print_helper(a_l != b_i);
+	print_helper(a_l < b_i);
+	print_helper(a_l <= b_i);
+	print_helper(a_l > b_i);
+	print_helper(a_l >= b_i);
+	print_helper(a_l + b_ui);
+	print_helper(a_l - b_ui);
+	print_helper(a_l * b_ui);
+	print_helper(a_l / b_ui);
+	print_helper(a_l % b_ui);
+	print_helper(a_l & b_ui);
+	print_helper(a_l | b_ui);
+	print_helper(a_l ^ b_ui);
+	print_helper(a_l == b_ui);
+	print_helper(a_l != b_ui);
+	print_helper(a_l < b_ui);
+	print_helper(a_l <= b_ui);
+	print_helper(a_l > b_ui);
+	print_helper(a_l >= b_ui);
+	print_helper(a_l + b_l);
+	print_helper(a_l - b_l);
+	print_helper(a_l * b_l);
+	print_helper(a_l / b_l);
+	print_helper(a_l % b_l);
+	print_helper(a_l & b_l);
+	print_helper(a_l | b_l);
+	print_helper(a_l ^ b_l);
+	print_helper(a_l == b_l);
+	print_helper(a_l != b_l);
+	print_helper(a_l < b_l);
+	print_helper(a_l <= b_l);
+	print_helper(a_l > b_l);
+	print_helper(a_l >= b_l);
+	print_helper(a_l + b_ul);
+	print_helper(a_l - b_ul);
+	print_helper(a_l * b_ul);
+	print_helper(a_l / b_ul);
+	print_helper(a_l % b_ul);
+	print_helper(a_l & b_ul);
+	print_helper(a_l | b_ul);
+	print_helper(a_l ^ b_ul);
+	print_helper(a_l == b_ul);
+	print_helper(a_l != b_ul);
+	print_helper(a_l < b_ul);
+	print_helper(a_l <= b_ul);
+	print_helper(a_l > b_ul);
+	print_helper(a_l >= b_ul);
+	print_helper(a_ul + b_c);
+	print_helper(a_ul - b_c);
+	print_helper(a_ul * b_c);
+	print_helper(a_ul / b_c);
+	print_helper(a_ul % b_c);
+	print_helper(a_ul & b_c);
+	print_helper(a_ul | b_c);
+	print_helper(a_ul ^ b_c);
+	print_helper(a_ul == b_c);
+	print_helper(a_ul != b_c);
+	print_helper(a_ul < b_c);
+	print_helper(a_ul <= b_c);
+	print_helper(a_ul > b_c);
+	print_helper(a_ul >= b_c);
+	print_helper(a_ul + b_uc);
+	print_helper(a_ul - b_uc);
+	print_helper(a_ul * b_uc);
+	print_helper(a_ul / b_uc);
+	print_helper(a_ul % b_uc);
+	print_helper(a_ul & b_uc);
+	print_helper(a_ul | b_uc);
+	print_helper(a_ul ^ b_uc);
+	print_helper(a_ul == b_uc);
+	print_helper(a_ul != b_uc);
+	print_helper(a_ul < b_uc);
+	print_helper(a_ul <= b_uc);
+	print_helper(a_ul > b_uc);
+	print_helper(a_ul >= b_uc);
+	print_helper(a_ul + b_s);
+	print_helper(a_ul - b_s);
+	print_helper(a_ul * b_s);
+	print_helper(a_ul / b_s);
+	print_helper(a_ul % b_s);
+	print_helper(a_ul & b_s);
+	print_helper(a_ul | b_s);
+	print_helper(a_ul ^ b_s);
+	print_helper(a_ul == b_s);
+	print_helper(a_ul != b_s);
+	print_helper(a_ul < b_s);
+	print_helper(a_ul <= b_s);
+	print_helper(a_ul > b_s);
+	print_helper(a_ul >= b_s);
+	print_helper(a_ul + b_us);
+	print_helper(a_ul - b_us);
+	print_helper(a_ul * b_us);
+	print_helper(a_ul / b_us);
+	print_helper(a_ul % b_us);
+	print_helper(a_ul & b_us);
+	print_helper(a_ul | b_us);
+	print_helper(a_ul ^ b_us);
+	print_helper(a_ul == b_us);
+	print_helper(a_ul != b_us);
+	print_helper(a_ul < b_us);
+	print_helper(a_ul <= b_us);
+	print_helper(a_ul > b_us);
+	print_helper(a_ul >= b_us);
+	print_helper(a_ul + b_i);
+	print_helper(a_ul - b_i);
+	print_helper(a_ul * b_i);
+	print_helper(a_ul / b_i);
+	print_helper(a_ul % b_i);
+	print_helper(a_ul & b_i);
+	print_helper(a_ul | b_i);
+	print_helper(a_ul ^ b_i);
+	print_helper(a_ul == b_i);
+	print_helper(a_ul != b_i);
+	print_helper(a_ul < b_i);
+	print_helper(a_ul <= b_i);
+	print_helper(a_ul > b_i);
+	print_helper(a_ul >= b_i);
+	print_helper(a_ul + b_ui);
+	print_helper(a_ul - b_ui);
+	print_helper(a_ul * b_ui);
+	print_helper(a_ul / b_ui);
+	print_helper(a_ul % b_ui);
+	print_helper(a_ul & b_ui);
+	print_helper(a_ul | b_ui);
+	print_helper(a_ul ^ b_ui);
+	print_helper(a_ul == b_ui);
+	print_helper(a_ul != b_ui);
+	print_helper(a_ul < b_ui);
+	print_helper(a_ul <= b_ui);
+	print_helper(a_ul > b_ui);
+	print_helper(a_ul >= b_ui);
+	print_helper(a_ul + b_l);
+	print_helper(a_ul - b_l);
+	print_helper(a_ul * b_l);
+	print_helper(a_ul / b_l);
+	print_helper(a_ul % b_l);
+	print_helper(a_ul & b_l);
+	print_helper(a_ul | b_l);
+	print_helper(a_ul ^ b_l);
+	print_helper(a_ul == b_l);
+	print_helper(a_ul != b_l);
+	print_helper(a_ul < b_l);
+	print_helper(a_ul <= b_l);
+	print_helper(a_ul > b_l);
+	print_helper(a_ul >= b_l);
+	print_helper(a_ul + b_ul);
+	print_helper(a_ul - b_ul);
+	print_helper(a_ul * b_ul);
+	print_helper(a_ul / b_ul);
+	print_helper(a_ul % b_ul);
+	print_helper(a_ul & b_ul);
+	print_helper(a_ul | b_ul);
+	print_helper(a_ul ^ b_ul);
+	print_helper(a_ul == b_ul);
+	print_helper(a_ul != b_ul);
+	print_helper(a_ul < b_ul);
+	print_helper(a_ul <= b_ul);
+	print_helper(a_ul > b_ul);
+	print_helper(a_ul >= b_ul);
+	return 0;
+}
+
+int shift() {
+	char a_c;
+	char b_c;
+	a_c = -100;
+	b_c = 3;
+	unsigned char a_uc;
+	unsigned char b_uc;
+	a_uc = -100;
+	b_uc = 3;
+	short a_s;
+	short b_s;
+	a_s = -100;
+	b_s = 3;
+	unsigned short a_us;
+	unsigned short b_us;
+	a_us = -100;
+	b_us = 3;
+	int a_i;
+	int b_i;
+	a_i = -100;
+	b_i = 3;
+	unsigned int a_ui;
+	unsigned int b_ui;
+	a_ui = -100;
+	b_ui = 3;
+	long a_l;
+	long b_l;
+	a_l = -100;
+	b_l = 3;
+	unsigned long a_ul;
+	unsigned long b_ul;
+	a_ul = -100;
+	b_ul = 3;
+	print_helper(a_c << b_c);
+	print_helper(a_c >> b_c);
+	print_helper(a_c << b_uc);
+	print_helper(a_c >> b_uc);
+	print_helper(a_c << b_s);
+	print_helper(a_c >> b_s);
+	print_helper(a_c << b_us);
+	print_helper(a_c >> b_us);
+	print_helper(a_c << b_i);
+	print_helper(a_c >> b_i);
+	print_helper(a_c << b_ui);
+	print_helper(a_c >> b_ui);
+	print_helper(a_c << b_l);
+	print_helper(a_c >> b_l);
+	print_helper(a_c << b_ul);
+	print_helper(a_c >> b_ul);
+	print_helper(a_uc << b_c);
+	print_helper(a_uc >> b_c);
+	print_helper(a_uc << b_uc);
+	print_helper(a_uc >> b_uc);
+	print_helper(a_uc << b_s);
+	print_helper(a_uc >> b_s);
+	print_helper(a_uc << b_us);
+	print_helper(a_uc >> b_us);
+	print_helper(a_uc << b_i);
+	print_helper(a_uc >> b_i);
+	print_helper(a_uc << b_ui);
+	print_helper(a_uc >> b_ui);
+	print_helper(a_uc << b_l);
+	print_helper(a_uc >> b_l);
+	print_helper(a_uc << b_ul);
+	print_helper(a_uc >> b_ul);
+	print_helper(a_s << b_c);
+	print_helper(a_s >> b_c);
+	print_helper(a_s << b_uc);
+	print_helper(a_s >> b_uc);
+	print_helper(a_s << b_s);
+	print_helper(a_s >> b_s);
+	print_helper(a_s << b_us);
+	print_helper(a_s >> b_us);
+	print_helper(a_s << b_i);
+	print_helper(a_s >> b_i);
+	print_helper(a_s << b_ui);
+	print_helper(a_s >> b_ui);
+	print_helper(a_s << b_l);
+	print_helper(a_s >> b_l);
+	print_helper(a_s << b_ul);
+	print_helper(a_s >> b_ul);
+	print_helper(a_us << b_c);
+	print_helper(a_us >> b_c);
+	print_helper(a_us << b_uc);
+	print_helper(a_us >> b_uc);
+	print_helper(a_us << b_s);
+	print_helper(a_us >> b_s);
+	print_helper(a_us << b_us);
+	print_helper(a_us >> b_us);
+	print_helper(a_us << b_i);
+	print_helper(a_us >> b_i);
+	print_helper(a_us << b_ui);
+	print_helper(a_us >> b_ui);
+	print_helper(a_us << b_l);
+	print_helper(a_us >> b_l);
+	print_helper(a_us << b_ul);
+	print_helper(a_us >> b_ul);
+	print_helper(a_i << b_c);
+	print_helper(a_i >> b_c);
+	print_helper(a_i << b_uc);
+	print_helper(a_i >> b_uc);
+	print_helper(a_i << b_s);
+	print_helper(a_i >> b_s);
+	print_helper(a_i << b_us);
+	print_helper(a_i >> b_us);
+	print_helper(a_i << b_i);
+	print_helper(a_i >> b_i);
+	print_helper(a_i << b_ui);
+	print_helper(a_i >> b_ui);
+	print_helper(a_i << b_l);
+	print_helper(a_i >> b_l);
+	print_helper(a_i << b_ul);
+	print_helper(a_i >> b_ul);
+	print_helper(a_ui << b_c);
+	print_helper(a_ui >> b_c);
+	print_helper(a_ui << b_uc);
+	print_helper(a_ui >> b_uc);
+	print_helper(a_ui << b_s);
+	print_helper(a_ui >> b_s);
+	print_helper(a_ui << b_us);
+	print_helper(a_ui >> b_us);
+	print_helper(a_ui << b_i);
+	print_helper(a_ui >> b_i);
+	print_helper(a_ui << b_ui);
+	print_helper(a_ui >> b_ui);
+	print_helper(a_ui << b_l);
+	print_helper(a_ui >> b_l);
+	print_helper(a_ui << b_ul);
+	print_helper(a_ui >> b_ul);
+	print_helper(a_l << b_c);
+	print_helper(a_l >> b_c);
+	print_helper(a_l << b_uc);
+	print_helper(a_l >> b_uc);
+	print_helper(a_l << b_s);
+	print_helper(a_l >> b_s);
+	print_helper(a_l << b_us);
+	print_helper(a_l >> b_us);
+	print_helper(a_l << b_i);
+	print_helper(a_l >> b_i);
+	print_helper(a_l << b_ui);
+	print_helper(a_l >> b_ui);
+	print_helper(a_l << b_l);
+	print_helper(a_l >> b_l);
+	print_helper(a_l << b_ul);
+	print_helper(a_l >> b_ul);
+	print_helper(a_ul << b_c);
+	print_helper(a_ul >> b_c);
+	print_helper(a_ul << b_uc);
+	print_helper(a_ul >> b_uc);
+	print_helper(a_ul << b_s);
+	print_helper(a_ul >> b_s);
+	print_helper(a_ul << b_us);
+	print_helper(a_ul >> b_us);
+	print_helper(a_ul << b_i);
+	print_helper(a_ul >> b_i);
+	print_helper(a_ul << b_ui);
+	print_helper(a_ul >> b_ui);
+	print_helper(a_ul << b_l);
+	print_helper(a_ul >> b_l);
+	print_helper(a_ul << b_ul);
+	print_helper(a_ul >> b_ul);
+	return 0;
+}
+
+int unary() {
+	char a_c;
+	a_c = -100;
+	print_helper(-a_c);
+	print_helper(+a_c);
+	print_helper(~a_c);
+	print_helper(!a_c);
+	print_helper(sizeof(a_c));
+	print_helper(sizeof(+a_c));
+	print_helper(sizeof(~a_c));
+	unsigned char a_uc;
+	a_uc = -100;
+	print_helper(-a_uc);
+	print_helper(+a_uc);
+	print_helper(~a_uc);
+	print_helper(!a_uc);
+	print_helper(sizeof(a_uc));
+	print_helper(sizeof(+a_uc));
+	print_helper(sizeof(~a_uc));
+	short a_s;
+	a_s = -100;
+	print_helper(-a_s);
+	print_helper(+a_s);
+	print_helper(~a_s);
+	print_helper(!a_s);
+	print_helper(sizeof(a_s));
+	print_helper(sizeof(+a_s));
+	print_helper(sizeof(~a_s));
+	unsigned short a_us;
+	a_us = -100;
+	print_helper(-a_us);
+	print_helper(+a_us);
+	print_helper(~a_us);
+	print_helper(!a_us);
+	print_helper(sizeof(a_us));
+	print_helper(sizeof(+a_us));
+	print_helper(sizeof(~a_us));
+	int a_i;
+	a_i = -100;
+	print_helper(-a_i);
+	print_helper(+a_i);
+	print_helper(~a_i);
+	print_helper(!a_i);
+	print_helper(sizeof(a_i));
+	print_helper(sizeof(+a_i));
+	print_helper(sizeof(~a_i));
+	unsigned int a_ui;
+	a_ui = -100;
+	print_helper(-a_ui);
+	print_helper(+a_ui);
+	print_helper(~a_ui);
+	print_helper(!a_ui);
+	print_helper(sizeof(a_ui));
+	print_helper(sizeof(+a_ui));
+	print_helper(sizeof(~a_ui));
+	long a_l;
+	a_l = -100;
+	print_helper(-a_l);
+	print_helper(+a_l);
+	print_helper(~a_l);
+	print_helper(!a_l);
+	print_helper(sizeof(a_l));
+	print_helper(sizeof(+a_l));
+	print_helper(sizeof(~a_l));
+	unsigned long a_ul;
+	a_ul = -100;
+	print_helper(-a_ul);
+	print_helper(+a_ul);
+	print_helper(~a_ul);
+	print_helper(!a_ul);
+	print_helper(sizeof(a_ul));
+	print_helper(sizeof(+a_ul));
+	print_helper(sizeof(~a_ul));
+	return 0;
+}
+
+int assign() {
+	char a_c;
+	char b_c;
+	unsigned char a_uc;
+	unsigned char b_uc;
+	short a_s;
+	short b_s;
+	unsigned short a_us;
+	unsigned short b_us;
+	int a_i;
+	int b_i;
+	unsigned int a_ui;
+	unsigned int b_ui;
+	long a_l;
+	long b_l;
+	unsigned long a_ul;
+	unsigned long b_ul;
+	a_c = 300000; b_c = -77777; a_c = b_c; print_helper(a_c);
+	a_c = 1234567; b_c = -3; a_c += b_c; print_helper(a_c);
+	a_c = 1234567; b_c = -3; a_c -= b_c; print_helper(a_c);
+	a_c = 1234567; b_c = -3; a_c *= b_c; print_helper(a_c);
+	a_c = 1234567; b_c = -3; a_c /= b_c; print_helper(a_c);
+	a_c = 1234567; b_c = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_uc = -77777; a_c = b_uc; print_helper(a_c);
+	a_c = 1234567; b_uc = -3; a_c += b_uc; print_helper(a_c);
+	a_c = 1234567; b_uc = -3; a_c -= b_uc; print_helper(a_c);
+	a_c = 1234567; b_uc = -3; a_c *= b_uc; print_helper(a_c);
+	a_c = 1234567; b_uc = -3; a_c /= b_uc; print_helper(a_c);
+	a_c = 1234567; b_uc = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_s = -77777; a_c = b_s; print_helper(a_c);
+	a_c = 1234567; b_s = -3; a_c += b_s; print_helper(a_c);
+	a_c = 1234567; b_s = -3; a_c -= b_s; print_helper(a_c);
+	a_c = 1234567; b_s = -3; a_c *= b_s; print_helper(a_c);
+	a_c = 1234567; b_s = -3; a_c /= b_s; print_helper(a_c);
+	a_c = 1234567; b_s = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_us = -77777; a_c = b_us; print_helper(a_c);
+	a_c = 1234567; b_us = -3; a_c += b_us; print_helper(a_c);
+	a_c = 1234567; b_us = -3; a_c -= b_us; print_helper(a_c);
+	a_c = 1234567; b_us = -3; a_c *= b_us; print_helper(a_c);
+	a_c = 1234567; b_us = -3; a_c /= b_us; print_helper(a_c);
+	a_c = 1234567; b_us = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_i = -77777; a_c = b_i; print_helper(a_c);
+	a_c = 1234567; b_i = -3; a_c += b_i; print_helper(a_c);
+	a_c = 1234567; b_i = -3; a_c -= b_i; print_helper(a_c);
+	a_c = 1234567; b_i = -3; a_c *= b_i; print_helper(a_c);
+	a_c = 1234567; b_i = -3; a_c /= b_i; print_helper(a_c);
+	a_c = 1234567; b_i = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_ui = -77777; a_c = b_ui; print_helper(a_c);
+	a_c = 1234567; b_ui = -3; a_c += b_ui; print_helper(a_c);
+	a_c = 1234567; b_ui = -3; a_c -= b_ui; print_helper(a_c);
+	a_c = 1234567; b_ui = -3; a_c *= b_ui; print_helper(a_c);
+	a_c = 1234567; b_ui = -3; a_c /= b_ui; print_helper(a_c);
+	a_c = 1234567; b_ui = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_l = -77777; a_c = b_l; print_helper(a_c);
+	a_c = 1234567; b_l = -3; a_c += b_l; print_helper(a_c);
+	a_c = 1234567; b_l = -3; a_c -= b_l; print_helper(a_c);
+	a_c = 1234567; b_l = -3; a_c *= b_l; print_helper(a_c);
+	a_c = 1234567; b_l = -3; a_c /= b_l; print_helper(a_c);
+	a_c = 1234567; b_l = -3; a_c <<= 2; print_helper(a_c);
+	a_c = 300000; b_ul = -77777; a_c = b_ul; print_helper(a_c);
+	a_c = 1234567; b_ul = -3; a_c += b_ul; print_helper(a_c);
+	a_c = 1234567; b_ul = -3; a_c -= b_ul; print_helper(a_c);
+	a_c = 1234567; b_ul = -3; a_c *= b_ul; print_helper(a_c);
+	a_c = 1234567; b_ul = -3; a_c /= b_ul; print_helper(a_c);
+	a_c = 1234567; b_ul = -3; a_c <<= 2; print_helper(a_c);
+	a_uc = 300000; b_c = -77777; a_uc = b_c; print_helper(a_uc);
+	a_uc = 1234567; b_c = -3; a_uc += b_c; print_helper(a_uc);
+	a_uc = 1234567; b_c = -3; a_uc -= b_c; print_helper(a_uc);
+	a_uc = 1234567; b_c = -3; a_uc *= b_c; print_helper(a_uc);
+	a_uc = 1234567; b_c = -3; a_uc /= b_c; print_helper(a_uc);
+	a_uc = 1234567; b_c = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_uc = -77777; a_uc = b_uc; print_helper(a_uc);
+	a_uc = 1234567; b_uc = -3; a_uc += b_uc; print_helper(a_uc);
+	a_uc = 1234567; b_uc = -3; a_uc -= b_uc; print_helper(a_uc);
+	a_uc = 1234567; b_uc = -3; a_uc *= b_uc; print_helper(a_uc);
+	a_uc = 1234567; b_uc = -3; a_uc /= b_uc; print_helper(a_uc);
+	a_uc = 1234567; b_uc = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_s = -77777; a_uc = b_s; print_helper(a_uc);
+	a_uc = 1234567; b_s = -3; a_uc += b_s; print_helper(a_uc);
+	a_uc = 1234567; b_s = -3; a_uc -= b_s; print_helper(a_uc);
+	a_uc = 1234567; b_s = -3; a_uc *= b_s; print_helper(a_uc);
+	a_uc = 1234567; b_s = -3; a_uc /= b_s; print_helper(a_uc);
+	a_uc = 1234567; b_s = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_us = -77777; a_uc = b_us; print_helper(a_uc);
+	a_uc = 1234567; b_us = -3; a_uc += b_us; print_helper(a_uc);
+	a_uc = 1234567; b_us = -3; a_uc -= b_us; print_helper(a_uc);
+	a_uc = 1234567; b_us = -3; a_uc *= b_us; print_helper(a_uc);
+	a_uc = 1234567; b_us = -3; a_uc /= b_us; print_helper(a_uc);
+	a_uc = 1234567; b_us = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_i = -77777; a_uc = b_i; print_helper(a_uc);
+	a_uc = 1234567; b_i = -3; a_uc += b_i; print_helper(a_uc);
+	a_uc = 1234567; b_i = -3; a_uc -= b_i; print_helper(a_uc);
+	a_uc = 1234567; b_i = -3; a_uc *= b_i; print_helper(a_uc);
+	a_uc = 1234567; b_i = -3; a_uc /= b_i; print_helper(a_uc);
+	a_uc = 1234567; b_i = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_ui = -77777; a_uc = b_ui; print_helper(a_uc);
+	a_uc = 1234567; b_ui = -3; a_uc += b_ui; print_helper(a_uc);
+	a_uc = 1234567; b_ui = -3; a_uc -= b_ui; print_helper(a_uc);
+	a_uc = 1234567; b_ui = -3; a_uc *= b_ui; print_helper(a_uc);
+	a_uc = 1234567; b_ui = -3; a_uc /= b_ui; print_helper(a_uc);
+	a_uc = 1234567; b_ui = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_l = -77777; a_uc = b_l; print_helper(a_uc);
+	a_uc = 1234567; b_l = -3; a_uc += b_l; print_helper(a_uc);
+	a_uc = 1234567; b_l = -3; a_uc -= b_l; print_helper(a_uc);
+	a_uc = 1234567; b_l = -3; a_uc *= b_l; print_helper(a_uc);
+	a_uc = 1234567; b_l = -3; a_uc /= b_l; print_helper(a_uc);
+	a_uc = 1234567; b_l = -3; a_uc <<= 2; print_helper(a_uc);
+	a_uc = 300000; b_ul = -77777; a_uc = b_ul; print_helper(a_uc);
+	a_uc = 1234567; b_ul = -3; a_uc += b_ul; print_helper(a_uc);
+	a_uc = 1234567; b_ul = -3; a_uc -= b_ul; print_helper(a_uc);
+	a_uc = 1234567; b_ul = -3; a_uc *= b_ul; print_helper(a_uc);
+	a_uc = 1234567; b_ul = -3; a_uc /= b_ul; print_helper(a_uc);
+	a_uc = 1234567; b_ul = -3; a_uc <<= 2; print_helper(a_uc);
+	a_s = 300000; b_c = -77777; a_s = b_c; print_helper(a_s);
+	a_s = 1234567; b_c = -3; a_s += b_c; print_helper(a_s);
+	a_s = 1234567; b_c = -3; a_s -= b_c; print_helper(a_s);
+	a_s = 1234567; b_c = -3; a_s *= b_c; print_helper(a_s);
+	a_s = 1234567; b_c = -3; a_s /= b_c; print_helper(a_s);
+	a_s = 1234567; b_c = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_uc = -77777; a_s = b_uc; print_helper(a_s);
+	a_s = 1234567; b_uc = -3; a_s += b_uc; print_helper(a_s);
+	a_s = 1234567; b_uc = -3; a_s -= b_uc; print_helper(a_s);
+	a_s = 1234567; b_uc = -3; a_s *= b_uc; print_helper(a_s);
+	a_s = 1234567; b_uc = -3; a_s /= b_uc; print_helper(a_s);
+	a_s = 1234567; b_uc = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_s = -77777; a_s = b_s; print_helper(a_s);
+	a_s = 1234567; b_s = -3; a_s += b_s; print_helper(a_s);
+	a_s = 1234567; b_s = -3; a_s -= b_s; print_helper(a_s);
+	a_s = 1234567; b_s = -3; a_s *= b_s; print_helper(a_s);
+	a_s = 1234567; b_s = -3; a_s /= b_s; print_helper(a_s);
+	a_s = 1234567; b_s = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_us = -77777; a_s = b_us; print_helper(a_s);
+	a_s = 1234567; b_us = -3; a_s += b_us; print_helper(a_s);
+	a_s = 1234567; b_us = -3; a_s -= b_us; print_helper(a_s);
+	a_s = 1234567; b_us = -3; a_s *= b_us; print_helper(a_s);
+	a_s = 1234567; b_us = -3; a_s /= b_us; print_helper(a_s);
+	a_s = 1234567; b_us = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_i = -77777; a_s = b_i; print_helper(a_s);
+	a_s = 1234567; b_i = -3; a_s += b_i; print_helper(a_s);
+	a_s = 1234567; b_i = -3; a_s -= b_i; print_helper(a_s);
+	a_s = 1234567; b_i = -3; a_s *= b_i; print_helper(a_s);
+	a_s = 1234567; b_i = -3; a_s /= b_i; print_helper(a_s);
+	a_s = 1234567; b_i = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_ui = -77777; a_s = b_ui; print_helper(a_s);
+	a_s = 1234567; b_ui = -3; a_s += b_ui; print_helper(a_s);
+	a_s = 1234567; b_ui = -3; a_s -= b_ui; print_helper(a_s);
+	a_s = 1234567; b_ui = -3; a_s *= b_ui; print_helper(a_s);
+	a_s = 1234567; b_ui = -3; a_s /= b_ui; print_helper(a_s);
+	a_s = 1234567; b_ui = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_l = -77777; a_s = b_l; print_helper(a_s);
+	a_s = 1234567; b_l = -3; a_s += b_l; print_helper(a_s);
+	a_s = 1234567; b_l = -3; a_s -= b_l; print_helper(a_s);
+	a_s = 1234567; b_l = -3; a_s *= b_l; print_helper(a_s);
+	a_s = 1234567; b_l = -3; a_s /= b_l; print_helper(a_s);
+	a_s = 1234567; b_l = -3; a_s <<= 2; print_helper(a_s);
+	a_s = 300000; b_ul = -77777; a_s = b_ul; print_helper(a_s);
+	a_s = 1234567; b_ul = -3; a_s += b_ul; print_helper(a_s);
+	a_s = 1234567; b_ul = -3; a_s -= b_ul; print_helper(a_s);
+	a_s = 1234567; b_ul = -3; a_s *= b_ul; print_helper(a_s);
+	a_s = 1234567; b_ul = -3; a_s /= b_ul; print_helper(a_s);
+	a_s = 1234567; b_ul = -3; a_s <<= 2; print_helper(a_s);
+	a_us = 300000; b_c = -77777; a_us = b_c; print_helper(a_us);
+	a_us = 1234567; b_c = -3; a_us += b_c; print_helper(a_us);
+	a_us = 1234567; b_c = -3; a_us -= b_c; print_helper(a_us);
+	a_us = 1234567; b_c = -3; a_us *= b_c; print_helper(a_us);
+	a_us = 1234567; b_c = -3; a_us /= b_c; print_helper(a_us);
+	a_us = 1234567; b_c = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_uc = -77777; a_us = b_uc; print_helper(a_us);
+	a_us = 1234567; b_uc = -3; a_us += b_uc; print_helper(a_us);
+	a_us = 1234567; b_uc = -3; a_us -= b_uc; print_helper(a_us);
+	a_us = 1234567; b_uc = -3; a_us *= b_uc; print_helper(a_us);
+	a_us = 1234567; b_uc = -3; a_us /= b_uc; print_helper(a_us);
+	a_us = 1234567; b_uc = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_s = -77777; a_us = b_s; print_helper(a_us);
+	a_us = 1234567; b_s = -3; a_us += b_s; print_helper(a_us);
+	a_us = 1234567; b_s = -3; a_us -= b_s; print_helper(a_us);
+	a_us = 1234567; b_s = -3; a_us *= b_s; print_helper(a_us);
+	a_us = 1234567; b_s = -3; a_us /= b_s; print_helper(a_us);
+	a_us = 1234567; b_s = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_us = -77777; a_us = b_us; print_helper(a_us);
+	a_us = 1234567; b_us = -3; a_us += b_us; print_helper(a_us);
+	a_us = 1234567; b_us = -3; a_us -= b_us; print_helper(a_us);
+	a_us = 1234567; b_us = -3; a_us *= b_us; print_helper(a_us);
+	a_us = 1234567; b_us = -3; a_us /= b_us; print_helper(a_us);
+	a_us = 1234567; b_us = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_i = -77777; a_us = b_i; print_helper(a_us);
+	a_us = 1234567; b_i = -3; a_us += b_i; print_helper(a_us);
+	a_us = 1234567; b_i = -3; a_us -= b_i; print_helper(a_us);
+	a_us = 1234567; b_i = -3; a_us *= b_i; print_helper(a_us);
+	a_us = 1234567; b_i = -3; a_us /= b_i; print_helper(a_us);
+	a_us = 1234567; b_i = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_ui = -77777; a_us = b_ui; print_helper(a_us);
+	a_us = 1234567; b_ui = -3; a_us += b_ui; print_helper(a_us);
+	a_us = 1234567; b_ui = -3; a_us -= b_ui; print_helper(a_us);
+	a_us = 1234567; b_ui = -3; a_us *= b_ui; print_helper(a_us);
+	a_us = 1234567; b_ui = -3; a_us /= b_ui; print_helper(a_us);
+	a_us = 1234567; b_ui = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_l = -77777; a_us = b_l; print_helper(a_us);
+	a_us = 1234567; b_l = -3; a_us += b_l; print_helper(a_us);
+	a_us = 1234567; b_l = -3; a_us -= b_l; print_helper(a_us);
+	a_us = 1234567; b_l = -3; a_us *= b_l; print_helper(a_us);
+	a_us = 1234567; b_l = -3; a_us /= b_l; print_helper(a_us);
+	a_us = 1234567; b_l = -3; a_us <<= 2; print_helper(a_us);
+	a_us = 300000; b_ul = -77777; a_us = b_ul; print_helper(a_us);
+	a_us = 1234567; b_ul = -3; a_us += b_ul; print_helper(a_us);
+	a_us = 1234567; b_ul = -3; a_us -= b_ul; print_helper(a_us);
+	a_us = 1234567; b_ul = -3; a_us *= b_ul; print_helper(a_us);
+	a_us = 1234567; b_ul = -3; a_us /= b_ul; print_helper(a_us);
+	a_us = 1234567; b_ul = -3; a_us <<= 2; print_helper(a_us);
+	a_i = 300000; b_c = -77777; a_i = b_c; print_helper(a_i);
+	a_i = 1234567; b_c = -3; a_i += b_c; print_helper(a_i);
+	a_i = 1234567; b_c = -3; a_i -= b_c; print_helper(a_i);
+	a_i = 1234567; b_c = -3; a_i *= b_c; print_helper(a_i);
+	a_i = 1234567; b_c = -3; a_i /= b_c; print_helper(a_i);
+	a_i = 1234567; b_c = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_uc = -77777; a_i = b_uc; print_helper(a_i);
+	a_i = 1234567; b_uc = -3; a_i += b_uc; print_helper(a_i);
+	a_i = 1234567; b_uc = -3; a_i -= b_uc; print_helper(a_i);
+	a_i = 1234567; b_uc = -3; a_i *= b_uc; print_helper(a_i);
+	a_i = 1234567; b_uc = -3; a_i /= b_uc; print_helper(a_i);
+	a_i = 1234567; b_uc = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_s = -77777; a_i = b_s; print_helper(a_i);
+	a_i = 1234567; b_s = -3; a_i += b_s; print_helper(a_i);
+	a_i = 1234567; b_s = -3; a_i -= b_s; print_helper(a_i);
+	a_i = 1234567; b_s = -3; a_i *= b_s; print_helper(a_i);
+	a_i = 1234567; b_s = -3; a_i /= b_s; print_helper(a_i);
+	a_i = 1234567; b_s = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_us = -77777; a_i = b_us; print_helper(a_i);
+	a_i = 1234567; b_us = -3; a_i += b_us; print_helper(a_i);
+	a_i = 1234567; b_us = -3; a_i -= b_us; print_helper(a_i);
+	a_i = 1234567; b_us = -3; a_i *= b_us; print_helper(a_i);
+	a_i = 1234567; b_us = -3; a_i /= b_us; print_helper(a_i);
+	a_i = 1234567; b_us = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_i = -77777; a_i = b_i; print_helper(a_i);
+	a_i = 1234567; b_i = -3; a_i += b_i; print_helper(a_i);
+	a_i = 1234567; b_i = -3; a_i -= b_i; print_helper(a_i);
+	a_i = 1234567; b_i = -3; a_i *= b_i; print_helper(a_i);
+	a_i = 1234567; b_i = -3; a_i /= b_i; print_helper(a_i);
+	a_i = 1234567; b_i = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_ui = -77777; a_i = b_ui; print_helper(a_i);
+	a_i = 1234567; b_ui = -3; a_i += b_ui; print_helper(a_i);
+	a_i = 1234567; b_ui = -3; a_i -= b_ui; print_helper(a_i);
+	a_i = 1234567; b_ui = -3; a_i *= b_ui; print_helper(a_i);
+	a_i = 1234567; b_ui = -3; a_i /= b_ui; print_helper(a_i);
+	a_i = 1234567; b_ui = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_l = -77777; a_i = b_l; print_helper(a_i);
+	a_i = 1234567; b_l = -3; a_i += b_l; print_helper(a_i);
+	a_i = 1234567; b_l = -3; a_i -= b_l; print_helper(a_i);
+	a_i = 1234567; b_l = -3; a_i *= b_l; print_helper(a_i);
+	a_i = 1234567; b_l = -3; a_i /= b_l; print_helper(a_i);
+	a_i = 1234567; b_l = -3; a_i <<= 2; print_helper(a_i);
+	a_i = 300000; b_ul = -77777; a_i = b_ul; print_helper(a_i);
+	a_i = 1234567; b_ul = -3; a_i += b_ul; print_helper(a_i);
+	a_i = 1234567; b_ul = -3; a_i -= b_ul; print_helper(a_i);
+	a_i = 1234567; b_ul = -3; a_i *= b_ul; print_helper(a_i);
+	a_i = 1234567; b_ul = -3; a_i /= b_ul; print_helper(a_i);
+	a_i = 1234567; b_ul = -3; a_i <<= 2; print_helper(a_i);
+	a_ui = 300000; b_c = -77777; a_ui = b_c; print_helper(a_ui);
+	a_ui = 1234567; b_c = -3; a_ui += b_c; print_helper(a_ui);
+	a_ui = 1234567; b_c = -3; a_ui -= b_c; print_helper(a_ui);
+	a_ui = 1234567; b_c = -3; a_ui *= b_c; print_helper(a_ui);
+	a_ui = 1234567; b_c = -3; a_ui /= b_c; print_helper(a_ui);
+	a_ui = 1234567; b_c = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_uc = -77777; a_ui = b_uc; print_helper(a_ui);
+	a_ui = 1234567; b_uc = -3; a_ui += b_uc; print_helper(a_ui);
+	a_ui = 1234567; b_uc = -3; a_ui -= b_uc; print_helper(a_ui);
+	a_ui = 1234567; b_uc = -3; a_ui *= b_uc; print_helper(a_ui);
+	a_ui = 1234567; b_uc = -3; a_ui /= b_uc; print_helper(a_ui);
+	a_ui = 1234567; b_uc = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_s = -77777; a_ui = b_s; print_helper(a_ui);
+	a_ui = 1234567; b_s = -3; a_ui += b_s; print_helper(a_ui);
+	a_ui = 1234567; b_s = -3; a_ui -= b_s; print_helper(a_ui);
+	a_ui = 1234567; b_s = -3; a_ui *= b_s; print_helper(a_ui);
+	a_ui = 1234567; b_s = -3; a_ui /= b_s; print_helper(a_ui);
+	a_ui = 1234567; b_s = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_us = -77777; a_ui = b_us; print_helper(a_ui);
+	a_ui = 1234567; b_us = -3; a_ui += b_us; print_helper(a_ui);
+	a_ui = 1234567; b_us = -3; a_ui -= b_us; print_helper(a_ui);
+	a_ui = 1234567; b_us = -3; a_ui *= b_us; print_helper(a_ui);
+	a_ui = 1234567; b_us = -3; a_ui /= b_us; print_helper(a_ui);
+	a_ui = 1234567; b_us = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_i = -77777; a_ui = b_i; print_helper(a_ui);
+	a_ui = 1234567; b_i = -3; a_ui += b_i; print_helper(a_ui);
+	a_ui = 1234567; b_i = -3; a_ui -= b_i; print_helper(a_ui);
+	a_ui = 1234567; b_i = -3; a_ui *= b_i; print_helper(a_ui);
+	a_ui = 1234567; b_i = -3; a_ui /= b_i; print_helper(a_ui);
+	a_ui = 1234567; b_i = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_ui = -77777; a_ui = b_ui; print_helper(a_ui);
+	a_ui = 1234567; b_ui = -3; a_ui += b_ui; print_helper(a_ui);
+	a_ui = 1234567; b_ui = -3; a_ui -= b_ui; print_helper(a_ui);
+	a_ui = 1234567; b_ui = -3; a_ui *= b_ui; print_helper(a_ui);
+	a_ui = 1234567; b_ui = -3; a_ui /= b_ui; print_helper(a_ui);
+	a_ui = 1234567; b_ui = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_l = -77777; a_ui = b_l; print_helper(a_ui);
+	a_ui = 1234567; b_l = -3; a_ui += b_l; print_helper(a_ui);
+	a_ui = 1234567; b_l = -3; a_ui -= b_l; print_helper(a_ui);
+	a_ui = 1234567; b_l = -3; a_ui *= b_l; print_helper(a_ui);
+	a_ui = 1234567; b_l = -3; a_ui /= b_l; print_helper(a_ui);
+	a_ui = 1234567; b_l = -3; a_ui <<= 2; print_helper(a_ui);
+	a_ui = 300000; b_ul = -77777; a_ui = b_ul; print_helper(a_ui);
+	a_ui = 1234567; b_ul = -3; a_ui += b_ul; print_helper(a_ui);
+	a_ui = 1234567; b_ul = -3; a_ui -= b_ul; print_helper(a_ui);
+	a_ui = 1234567; b_ul = -3; a_ui *= b_ul; print_helper(a_ui);
+	a_ui = 1234567; b_ul = -3; a_ui /= b_ul; print_helper(a_ui);
+	a_ui = 1234567; b_ul = -3; a_ui <<= 2; print_helper(a_ui);
+	a_l = 300000; b_c = -77777; a_l = b_c; print_helper(a_l);
+	a_l = 1234567; b_c = -3; a_l += b_c; print_helper(a_l);
+	a_l = 1234567; b_c = -3; a_l -= b_c; print_helper(a_l);
+	a_l = 1234567; b_c = -3; a_l *= b_c; print_helper(a_l);
+	a_l = 1234567; b_c = -3; a_l /= b_c; print_helper(a_l);
+	a_l = 1234567; b_c = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_uc = -77777; a_l = b_uc; print_helper(a_l);
+	a_l = 1234567; b_uc = -3; a_l += b_uc; print_helper(a_l);
+	a_l = 1234567; b_uc = -3; a_l -= b_uc; print_helper(a_l);
+	a_l = 1234567; b_uc = -3; a_l *= b_uc; print_helper(a_l);
+	a_l = 1234567; b_uc = -3; a_l /= b_uc; print_helper(a_l);
+	a_l = 1234567; b_uc = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_s = -77777; a_l = b_s; print_helper(a_l);
+	a_l = 1234567; b_s = -3; a_l += b_s; print_helper(a_l);
+	a_l = 1234567; b_s = -3; a_l -= b_s; print_helper(a_l);
+	a_l = 1234567; b_s = -3; a_l *= b_s; print_helper(a_l);
+	a_l = 1234567; b_s = -3; a_l /= b_s; print_helper(a_l);
+	a_l = 1234567; b_s = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_us = -77777; a_l = b_us; print_helper(a_l);
+	a_l = 1234567; b_us = -3; a_l += b_us; print_helper(a_l);
+	a_l = 1234567; b_us = -3; a_l -= b_us; print_helper(a_l);
+	a_l = 1234567; b_us = -3; a_l *= b_us; print_helper(a_l);
+	a_l = 1234567; b_us = -3; a_l /= b_us; print_helper(a_l);
+	a_l = 1234567; b_us = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_i = -77777; a_l = b_i; print_helper(a_l);
+	a_l = 1234567; b_i = -3; a_l += b_i; print_helper(a_l);
+	a_l = 1234567; b_i = -3; a_l -= b_i; print_helper(a_l);
+	a_l = 1234567; b_i = -3; a_l *= b_i; print_helper(a_l);
+	a_l = 1234567; b_i = -3; a_l /= b_i; print_helper(a_l);
+	a_l = 1234567; b_i = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_ui = -77777; a_l = b_ui; print_helper(a_l);
+	a_l = 1234567; b_ui = -3; a_l += b_ui; print_helper(a_l);
+	a_l = 1234567; b_ui = -3; a_l -= b_ui; print_helper(a_l);
+	a_l = 1234567; b_ui = -3; a_l *= b_ui; print_helper(a_l);
+	a_l = 1234567; b_ui = -3; a_l /= b_ui; print_helper(a_l);
+	a_l = 1234567; b_ui = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_l = -77777; a_l = b_l; print_helper(a_l);
+	a_l = 1234567; b_l = -3; a_l += b_l; print_helper(a_l);
+	a_l = 1234567; b_l = -3; a_l -= b_l; print_helper(a_l);
+	a_l = 1234567; b_l = -3; a_l *= b_l; print_helper(a_l);
+	a_l = 1234567; b_l = -3; a_l /= b_l; print_helper(a_l);
+	a_l = 1234567; b_l = -3; a_l <<= 2; print_helper(a_l);
+	a_l = 300000; b_ul = -77777; a_l = b_ul; print_helper(a_l);
+	a_l = 1234567; b_ul = -3; a_l += b_ul; print_helper(a_l);
+	a_l = 1234567; b_ul = -3; a_l -= b_ul; print_helper(a_l);
+	a_l = 1234567; b_ul = -3; a_l *= b_ul; print_helper(a_l);
+	a_l = 1234567; b_ul = -3; a_l /= b_ul; print_helper(a_l);
+	a_l = 1234567; b_ul = -3; a_l <<= 2; print_helper(a_l);
+	a_ul = 300000; b_c = -77777; a_ul = b_c; print_helper(a_ul);
+	a_ul = 1234567; b_c = -3; a_ul += b_c; print_helper(a_ul);
+	a_ul = 1234567; b_c = -3; a_ul -= b_c; print_helper(a_ul);
+	a_ul = 1234567; b_c = -3; a_ul *= b_c; print_helper(a_ul);
+	a_ul = 1234567; b_c = -3; a_ul /= b_c; print_helper(a_ul);
+	a_ul = 1234567; b_c = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_uc = -77777; a_ul = b_uc; print_helper(a_ul);
+	a_ul = 1234567; b_uc = -3; a_ul += b_uc; print_helper(a_ul);
+	a_ul = 1234567; b_uc = -3; a_ul -= b_uc; print_helper(a_ul);
+	a_ul = 1234567; b_uc = -3; a_ul *= b_uc; print_helper(a_ul);
+	a_ul = 1234567; b_uc = -3; a_ul /= b_uc; print_helper(a_ul);
+	a_ul = 1234567; b_uc = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_s = -77777; a_ul = b_s; print_helper(a_ul);
+	a_ul = 1234567; b_s = -3; a_ul += b_s; print_helper(a_ul);
+	a_ul = 1234567; b_s = -3; a_ul -= b_s; print_helper(a_ul);
+	a_ul = 1234567; b_s = -3; a_ul *= b_s; print_helper(a_ul);
+	a_ul = 1234567; b_s = -3; a_ul /= b_s; print_helper(a_ul);
+	a_ul = 1234567; b_s = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_us = -77777; a_ul = b_us; print_helper(a_ul);
+	a_ul = 1234567; b_us = -3; a_ul += b_us; print_helper(a_ul);
+	a_ul = 1234567; b_us = -3; a_ul -= b_us; print_helper(a_ul);
+	a_ul = 1234567; b_us = -3; a_ul *= b_us; print_helper(a_ul);
+	a_ul = 1234567; b_us = -3; a_ul /= b_us; print_helper(a_ul);
+	a_ul = 1234567; b_us = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_i = -77777; a_ul = b_i; print_helper(a_ul);
+	a_ul = 1234567; b_i = -3; a_ul += b_i; print_helper(a_ul);
+	a_ul = 1234567; b_i = -3; a_ul -= b_i; print_helper(a_ul);
+	a_ul = 1234567; b_i = -3; a_ul *= b_i; print_helper(a_ul);
+	a_ul = 1234567; b_i = -3; a_ul /= b_i; print_helper(a_ul);
+	a_ul = 1234567; b_i = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_ui = -77777; a_ul = b_ui; print_helper(a_ul);
+	a_ul = 1234567; b_ui = -3; a_ul += b_ui; print_helper(a_ul);
+	a_ul = 1234567; b_ui = -3; a_ul -= b_ui; print_helper(a_ul);
+	a_ul = 1234567; b_ui = -3; a_ul *= b_ui; print_helper(a_ul);
+	a_ul = 1234567; b_ui = -3; a_ul /= b_ui; print_helper(a_ul);
+	a_ul = 1234567; b_ui = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_l = -77777; a_ul = b_l; print_helper(a_ul);
+	a_ul = 1234567; b_l = -3; a_ul += b_l; print_helper(a_ul);
+	a_ul = 1234567; b_l = -3; a_ul -= b_l; print_helper(a_ul);
+	a_ul = 1234567; b_l = -3; a_ul *= b_l; print_helper(a_ul);
+	a_ul = 1234567; b_l = -3; a_ul /= b_l; print_helper(a_ul);
+	a_ul = 1234567; b_l = -3; a_ul <<= 2; print_helper(a_ul);
+	a_ul = 300000; b_ul = -77777; a_ul = b_ul; print_helper(a_ul);
+	a_ul = 1234567; b_ul = -3; a_ul += b_ul; print_helper(a_ul);
+	a_ul = 1234567; b_ul = -3; a_ul -= b_ul; print_helper(a_ul);
+	a_ul = 1234567; b_ul = -3; a_ul *= b_ul; print_helper(a_ul);
+	a_ul = 1234567; b_ul = -3; a_ul /= b_ul; print_helper(a_ul);
+	a_ul = 1234567; b_ul = -3; a_ul <<= 2; print_helper(a_ul);
+	return 0;
+}
+
+long ret_long(int x) {
+	return x;
+}
+
+unsigned char ret_uchar(int x) {
+	return x;
+}
+
+int ret_int(unsigned long x) {
+	return x;
+}
+
+long arg_long(long x) {
+	return x;
+}
+
+int literal() {
+	print_helper(2147483647);
+	print_helper(sizeof(2147483647));
+	print_helper(2147483647 + 1);
+	print_helper(-1 < 2147483647);
+	print_helper(2147483648);
+	print_helper(sizeof(2147483648));
+	print_helper(2147483648 + 1);
+	print_helper(-1 < 2147483648);
+	print_helper(4294967295u);
+	print_helper(sizeof(4294967295u));
+	print_helper(4294967295u + 1);
+	print_helper(-1 < 4294967295u);
+	print_helper(4294967296u);
+	print_helper(sizeof(4294967296u));
+	print_helper(4294967296u + 1);
+	print_helper(-1 < 4294967296u);
+	print_helper(1l);
+	print_helper(sizeof(1l));
+	print_helper(1l + 1);
+	print_helper(-1 < 1l);
+	print_helper(1ul);
+	print_helper(sizeof(1ul));
+	print_helper(1ul + 1);
+	print_helper(-1 < 1ul);
+	print_helper(9223372036854775807);
+	print_helper(sizeof(9223372036854775807));
+	print_helper(9223372036854775807 + 1);
+	print_helper(-1 < 9223372036854775807);
+	print_helper(18446744073709551615u);
+	print_helper(sizeof(18446744073709551615u));
+	print_helper(18446744073709551615u + 1);
+	print_helper(-1 < 18446744073709551615u);
+	print_helper(ret_long(-5));
+	print_helper(ret_uchar(-5));
+	print_helper(ret_int(-5));
+	print_helper(arg_long(-5));
+	print_helper(-1 < 1u);
+	print_helper(-1 < 1l);
+	print_helper(-1l < 1u);
+	print_helper(-1 > sizeof(int));
+	return 0;
+}
+
+int main() {
+	binary0();
+	binary1();
+	binary2();
+	shift();
+	unary();
+	assign();
+	literal();
+	return 0;
+}
@@ -0,0 +1,64 @@
+/*
+ * キャスト式のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+
+long g_addr;
+int g_arr[4] = {10, 20, 30, 40};
+
+int id(int x) { return x; }
+
+int main() {
+	int x = 300;
+	print_helper((char)x);
+	print_helper((unsigned char)x);
+	print_helper((short)70000);
+	print_helper((unsigned short)-1);
+	print_helper((unsigned)-1);
+	print_helper((long)(unsigned)-1);
+	print_helper((long long)-1);
+	print_helper((unsigned long)-1 > 0);
+	print_helper((_Bool)256);
+	print_helper((_Bool)0.5);
+	print_helper((int)3.99);
+	print_helper((int)-3.99);
+	print_helper((unsigned char)200.5);
+	print_double((double)7 / 2);
+	print_double((float)0.1);
+	print_double((double)(long double)0.1);
+	print_double((double)(float)16777217);
+	print_double(-(double)x);
+
+	int *p = &x;
+	long addr = (long)p;
+	int *q = (int *)addr;
+	print_helper(*q);
+	print_helper((long)&g_arr[2] - (long)&g_arr[0]);
+	print_helper(*(int *)((char *)g_arr + 8));
+	char *c = (char *)&x;
+	print_helper(c[0] + c[1] * 256);
+	void *vp = (void *)p;
+	print_helper(*(int *)vp);
+	print_helper((unsigned long)(void *)0);
+	print_helper((_Bool)p);
+	print_helper((int (*)[2])g_arr == (int (*)[2])&g_arr[0]);
+	int (*rows)[2] = (int (*)[2])g_arr;
+	print_helper(rows[1][1]);
+	int (*fp)(int) = (int (*)(int))id;
+	print_helper(fp(5));
+	long lf = (long)id;
+	print_helper(((int (*)(int))lf)(6));
+	print_helper((const int)x + 1);
+	const int cx = 4;
+	int *wp = (int *)&cx;
+	print_helper(*wp);
+	(void)x;
+	(void)id(1);
+	print_helper(-(long)x * 2);
+	print_helper(-g_arr[1]);
+	print_helper(sizeof((short)x + (short)x));
+	g_addr = (long)&g_addr;
+	print_helper(g_addr == (long)&g_addr);
+	return (unsigned char)x;
+}
@@ -0,0 +1,23 @@
+#!/bin/zsh
+
+# rscc の出力したアセンブリと gcc でコンパイルした同じソースの実行結果を比較する
+MOUNT_PATH=$(pwd)
+ASM_SRC=$1
+C_SRC=$2
+LINK_SRC="tests/utils/helper.c"
+
+# c_exec はイメージ名
+docker run --rm \
+    -v $MOUNT_PATH:$MOUNT_PATH \
+    --platform linux/amd64 \
+	c_exec \
+    /bin/bash -c \
+    "gcc -o /tmp/rscc ${MOUNT_PATH}/${ASM_SRC} ${MOUNT_PATH}/${LINK_SRC};
+    gcc -w -o /tmp/gcc ${MOUNT_PATH}/${C_SRC} ${MOUNT_PATH}/${LINK_SRC};
+    /tmp/rscc > /tmp/rscc.out; echo \$? >> /tmp/rscc.out;
+    /tmp/gcc > /tmp/gcc.out; echo \$? >> /tmp/gcc.out;
+    diff /tmp/rscc.out /tmp/gcc.out;
+    STATUS=\$?;
+    rm /tmp/rscc /tmp/gcc /tmp/rscc.out /tmp/gcc.out;
+    exit \$STATUS;
+    "
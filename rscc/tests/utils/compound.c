@@ -0,0 +1,45 @@
+/*
+ * 複合リテラルのテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int *gp = (int[]){10, 20, 30};
+int *gq = (int[4]){[2] = 7};
+int sum(int *a, int n) {
+	int s = 0;
+	for (int i = 0; i < n; i++)
+		s += a[i];
+	return s;
+}
+int twice(int i) {
+	int *p = (int[]){i, i + 1};
+	p[0] += 10;
+	return p[0] + p[1];
+}
+int main() {
+	print_helper(twice(1));
+	print_helper(twice(1));
+	print_helper(sum((int[]){1, 2, 3, 4}, 4));
+	print_helper((int[]){5, 6, 7}[1]);
+	print_helper(sizeof((int[]){1, 2, 3}));
+	print_helper(sizeof(int));
+	int *p = &(int){42};
+	print_helper(*p);
+	(int){3}++;
+	int *q = (int[2]){0};
+	q[1] = 9;
+	print_helper(q[0] + q[1]);
+	for (int i = 0; i < 3; i++) {
+		int *r = (int[]){i, i * 2};
+		print_helper(r[0] + r[1]);
+	}
+	print_helper(gp[0] + gp[1] + gp[2]);
+	print_helper(gq[2] + gq[3]);
+	char *s = (char[]){"hello"};
+	print_helper(s[1]);
+	print_helper(sizeof (char[]){"hello"});
+	double d = (double){1.5} * 2;
+	print_helper(d);
+	long (*m)[2] = (long[][2]){{1, 2}, {3, 4}};
+	print_helper(m[1][0]);
+	return 0;
+}
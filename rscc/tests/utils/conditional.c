@@ -0,0 +1,86 @@
+/*
+ * 条件演算子のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+
+int g_count = 0;
+int count() {
+	g_count++;
+	return g_count;
+}
+
+void say(int x) {
+	print_helper(x * 1000);
+}
+
+int gx = 3;
+int garr[4] = {1 ? 10 : 20, 0 ? 10 : 20, 2 > 1 ? 2 < 1 ? 5 : 6 : 7, 0 ?: 9};
+int *gp = 1 ? &gx : 0;
+double gd = 0 ? 1 : 2.5;
+long gl = 5 ?: 8;
+int gsize = sizeof(1 ? (char)1 : (char)2);
+
+int max(int a, int b) {
+	return a > b ? a : b;
+}
+
+int main() {
+	for (int i = 0; i < 4; i++)
+		print_helper(garr[i]);
+	print_helper(*gp);
+	print_double(gd);
+	print_helper(gl);
+	print_helper(gsize);
+
+	print_helper(max(3, 9));
+	print_helper(max(-3, -9));
+
+	/* 選ばれなかった方は評価されない */
+	int x = 1 ? count() : count() * 100;
+	print_helper(x);
+	print_helper(g_count);
+
+	/* 通常の算術型変換 */
+	print_double(1 ? 1 : 2.5);
+	unsigned u = 1;
+	print_helper((0 ? u : -1) > 0);
+	print_helper(sizeof(0 ? (char)1 : (long)2));
+
+	/* ポインタと空ポインタ定数 */
+	int arr[3] = {7, 8, 9};
+	int *p = x ? arr : 0;
+	print_helper(p[2]);
+	char *s = x > 5 ? "big" : "small";
+	print_helper(s[0]);
+	const int *cp = x ? &arr[1] : (const int *)0;
+	print_helper(*cp);
+	void *vp = x ? (void *)arr : &arr[1];
+	print_helper(*(int *)vp);
+
+	/* void */
+	x ? say(1) : say(2);
+	!x ? say(3) : say(4);
+
+	/* GNU 拡張の a ?: b は a を1度だけ評価する */
+	g_count = 0;
+	int y = count() ?: 100;
+	print_helper(y);
+	print_helper(g_count);
+	int z = 0;
+	print_helper(z ?: 42);
+	double dd = 0.0;
+	print_double(dd ?: 1.25);
+
+	/* 入れ子と結合 */
+	for (int i = 0; i < 5; i++)
+		print_helper(i == 0 ? 100 : i == 1 ? 200 : i == 2 ? 300 : 400);
+	int a = 0, b = 0;
+	*(x ? &a : &b) = 5;
+	print_helper(a);
+	long double ld = x ? 1.5L : 2;
+	print_double(ld);
+	float f = x ? 0.25f : 1;
+	print_double(f);
+	return 0;
+}
@@ -0,0 +1,71 @@
+/*
+ * 宣言子(ポインタへの配列、関数ポインタの配列、括弧で囲まれた宣言子、抽象宣言子)のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+int g_arr[3][10];
+int (*g_rows)[10] = &g_arr[0];
+char *(*g_table[4])(void);
+int (*g_fp)(int, char);
+
+int *(*make(void))[4] {
+	return 0;
+}
+
+int (sum)(int (*rows)[10], int n) {
+	int s = 0;
+	for (int i = 0; i < n; i++)
+		for (int j = 0; j < 10; j++)
+			s += rows[i][j];
+	return s;
+}
+
+int main2(void);
+
+int main() {
+	int a[5][10];
+	int (*p)[10] = a;
+	for (int i = 0; i < 5; i++)
+		for (int j = 0; j < 10; j++)
+			a[i][j] = i * 10 + j;
+	print_helper((*(p + 2))[3]);
+	print_helper(p[4][9]);
+	p++;
+	print_helper(**p);
+	print_helper(sizeof(p));
+	print_helper(sizeof(*p));
+	print_helper(sizeof(int (*)[10]));
+	print_helper(sizeof(int *[10]));
+	print_helper(sizeof(int (*)(int, char)));
+	print_helper(sizeof(char *(*[4])(void)));
+	print_helper(sizeof(g_table));
+	print_helper(sizeof(int[3][4]));
+	print_helper(sizeof(long double (*)[2]));
+	print_helper(sum(a, 5));
+	g_rows[1][2] = 7;
+	print_helper(g_arr[1][2]);
+	int *(*q)[4];
+	q = make();
+	print_helper(q == 0);
+	int (x) = 3, ((y)) = 4;
+	print_helper(x * y);
+	g_arr[0][2] = 5;
+	main2();
+	return 0;
+}
+
+int proto(int (*)[10], char *(*)(void), int n);
+
+int proto(int (*rows)[10], char *(*fn)(void), int n) {
+	int (*fp)(int n, int rows);
+	int a = n;
+	char *(*tbl[2])(void);
+	return rows[0][n] + a + sizeof(tbl) + sizeof(fp);
+}
+
+int main2(void) {
+	print_helper(proto(g_arr, 0, 2));
+	print_helper(sizeof(int (*(*)[3])(void)));
+	print_helper("abc"[1]);
+	return 0;
+}
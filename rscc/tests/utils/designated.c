@@ -0,0 +1,100 @@
+/*
+ * 配列の designator による初期化のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+
+int g1[5] = {[2] = 3, 4, [0] = 1};
+int g2[] = {[4] = 5, [1] = 2};
+long g3[8] = {[1 ... 3] = 7, 9, [6 ... 7] = -1};
+int g4[3][2] = {[1] = {1, 2}, [0] = {0, 7}};
+char g5[][4] = {[2] = "ab", "xyz", [0] = "c"};
+int g6[2][3] = {1, 2, [1] = 3, 4};
+double g7[4] = {[3] = 1.5, [1] = 2.5};
+int g8[] = {1, 2, [1] = 8, 9, 10};
+int g9[3][3] = {[1] = {1, 2, 3}, [1][1] = 9, 8};
+int g10[2][2][2] = {[0][1][0] = 1, 2, 3};
+int g11[3][3] = {[0 ... 2][1] = 5, 6};
+
+int counter = 0;
+int next() {
+	counter = counter + 1;
+	return counter;
+}
+
+void print_str(char *s) {
+	for (int i = 0; s[i]; i++)
+		print_helper(s[i]);
+	print_helper(-1);
+}
+
+int main() {
+	int l1[6] = {1, [3] = 4, 5, [1 ... 2] = 9};
+	int l2[] = {[0 ... 2] = 3, [7] = 8};
+	char l3[3][3] = {[1] = "hi", [2] = {'o', 'k'}};
+	int l4[3][2] = {1, [2] = 5, 6};
+	short l5[10] = {[9] = 1, [5 ... 8] = 2, [2] = 3};
+	int l6[4] = {[2] = next(), [0] = next()};
+	int l7[] = {[3] = 1, [1] = 2, 3, 4, 5};
+	int n = 0;
+	int l8[4] = {[0 ... 3] = n++};
+	int l9[2][3] = {[0 ... 1][1 ... 2] = next()};
+	int l10[3][3] = {1, 2, 3, 4, 5, 6, [1][1] = 9};
+	int l11[][2] = {[2][1] = 7, [0][0] = 1, 2};
+
+	for (int i = 0; i < 5; i++)
+		print_helper(g1[i]);
+	print_helper(sizeof(g2));
+	for (int i = 0; i < 5; i++)
+		print_helper(g2[i]);
+	for (int i = 0; i < 8; i++)
+		print_helper(g3[i]);
+	for (int i = 0; i < 3; i++)
+		for (int j = 0; j < 2; j++)
+			print_helper(g4[i][j]);
+	print_helper(sizeof(g5));
+	for (int i = 0; i < 4; i++)
+		print_str(g5[i]);
+	for (int i = 0; i < 2; i++)
+		for (int j = 0; j < 3; j++)
+			print_helper(g6[i][j]);
+	for (int i = 0; i < 4; i++)
+		print_double(g7[i]);
+	print_helper(sizeof(g8));
+	for (int i = 0; i < 4; i++)
+		print_helper(g8[i]);
+
+	for (int i = 0; i < 6; i++)
+		print_helper(l1[i]);
+	print_helper(sizeof(l2));
+	for (int i = 0; i < 8; i++)
+		print_helper(l2[i]);
+	for (int i = 0; i < 3; i++)
+		print_str(l3[i]);
+	for (int i = 0; i < 3; i++)
+		for (int j = 0; j < 2; j++)
+			print_helper(l4[i][j]);
+	for (int i = 0; i < 10; i++)
+		print_helper(l5[i]);
+	print_helper(l6[0] + l6[2]);
+	print_helper(l6[1] + l6[3]);
+	print_helper(sizeof(l7));
+	for (int i = 0; i < 6; i++)
+		print_helper(l7[i]);
+	print_helper(n);
+	for (int i = 0; i < 4; i++)
+		print_helper(l8[i]);
+	print_helper(counter);
+	for (int i = 0; i < 2; i++)
+		for (int j = 0; j < 3; j++)
+			print_helper(l9[i][j]);
+	for (int i = 0; i < 3; i++)
+		for (int j = 0; j < 3; j++)
+			print_helper(l10[i][j] + 10 * g9[i][j] + 100 * g11[i][j]);
+	for (int i = 0; i < 8; i++)
+		print_helper(g10[i / 4][i / 2 % 2][i % 2]);
+	print_helper(sizeof(l11));
+	for (int i = 0; i < 3; i++)
+		print_helper(l11[i][0] * 10 + l11[i][1]);
+	return 0;
+}
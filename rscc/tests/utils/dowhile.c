@@ -0,0 +1,56 @@
+/*
+ * do-while 文のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+int digits(long n) {
+	int count = 0;
+	do {
+		count++;
+		n /= 10;
+	} while (n);
+	return count;
+}
+
+int main() {
+	print_helper(digits(0));
+	print_helper(digits(12345));
+
+	int i = 100;
+	do
+		i++;
+	while (i < 10);
+	print_helper(i);
+
+	int s = 0;
+	i = 0;
+	do {
+		i++;
+		if (i % 2)
+			continue;
+		if (i > 12)
+			break;
+		s += i;
+	} while (i < 20);
+	print_helper(s);
+	print_helper(i);
+
+	double d = 1.0;
+	do
+		d /= 2;
+	while (d > 0.01);
+	print_helper(d * 1024);
+
+	int n = 0;
+	do {
+		int j = 0;
+		do {
+			j++;
+			if (j == 3)
+				break;
+		} while (1);
+		n += j;
+	} while (n < 10);
+	print_helper(n);
+	return 0;
+}
@@ -0,0 +1,257 @@
+/*
+ * 浮動小数点数のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+
+double g_d = 1.5;
+float g_f = 2.25f;
+double g_i2d = 3;
+int g_d2i = 7.9;
+double g_expr = 1.0 / 3 + 2;
+float g_arr[3] = {1.5f, -2, 0.1f};
+double g_zero;
+double g_negzero = -0.0;
+float g_fnegzero = -0.0f;
+double g_plus = +-0.0;
+
+double add_d(double a, double b) {
+	return a + b;
+}
+
+float mul_f(float a, float b) {
+	return a * b;
+}
+
+double mixed(int a, double b, long c, float d, unsigned e, double f) {
+	return a + b * c - d / e + f;
+}
+
+int to_int(double x) {
+	return x;
+}
+
+double from_int(int x) {
+	return x;
+}
+
+double many(double a, double b, double c, double d, double e, double f, double g, double h) {
+	return a - b + c - d + e - f + g - h;
+}
+
+int literals() {
+	print_double(1.5);
+	print_double(.25);
+	print_double(3.);
+	print_double(1e3);
+	print_double(2.5e-3);
+	print_double(1.5E+2);
+	print_double(0.1f);
+	print_double(123.456L);
+	return 0;
+}
+
+int arith() {
+	double a;
+	double b;
+	a = 7.5;
+	b = -2.25;
+	print_double(a + b);
+	print_double(a - b);
+	print_double(a * b);
+	print_double(a / b);
+	print_double(-a);
+	print_double(a + 1);
+	print_double(3 / a);
+	float f;
+	f = 1.1f;
+	print_double(f * 3);
+	print_double(f + a);
+	print_double(f / 3);
+	a += 1;
+	print_double(a);
+	a -= 0.5;
+	print_double(a);
+	a *= b;
+	print_double(a);
+	a /= 4;
+	print_double(a);
+	a++;
+	print_double(a);
+	--a;
+	print_double(a);
+	int i;
+	i = 10;
+	i += 2.7;
+	print_helper(i);
+	i *= 1.5;
+	print_helper(i);
+	return 0;
+}
+
+int convert() {
+	double d;
+	d = -3.99;
+	print_helper(to_int(d));
+	char c;
+	c = d;
+	print_helper(c);
+	unsigned char uc;
+	uc = 200.7;
+	print_helper(uc);
+	short s;
+	s = -1234.5;
+	print_helper(s);
+	unsigned int ui;
+	ui = 4000000000.5;
+	print_helper(ui);
+	long l;
+	l = -9000000000000.75;
+	print_helper(l);
+	unsigned long ul;
+	ul = 18000000000000000000.0;
+	print_helper(ul / 1000);
+	ul = 12345.9;
+	print_helper(ul);
+	d = ul;
+	print_double(d);
+	ul = 18446744073709551615ul;
+	d = ul;
+	print_double(d);
+	ul = 9223372036854775809ul;
+	float f;
+	f = ul;
+	print_double(f);
+	unsigned u;
+	u = 4294967295u;
+	print_double(u);
+	print_double(from_int(-17));
+	f = 16777217;
+	print_double(f);
+	d = f;
+	print_double(d);
+	f = 0.1;
+	d = f;
+	print_double(d);
+	_Bool b;
+	b = 0.5;
+	print_helper(b);
+	b = 0.0;
+	print_helper(b);
+	return 0;
+}
+
+int compare() {
+	double a;
+	double b;
+	double nan;
+	a = 1.5;
+	b = 2.5;
+	nan = 0.0 / 0.0;
+	print_helper(a < b);
+	print_helper(a > b);
+	print_helper(a <= 1.5);
+	print_helper(a >= 1.5);
+	print_helper(a == 1.5);
+	print_helper(a != 1.5);
+	print_helper(nan == nan);
+	print_helper(nan != nan);
+	print_helper(nan < a);
+	print_helper(nan > a);
+	print_helper(nan <= a);
+	print_helper(nan >= a);
+	print_helper(a < 2);
+	print_helper(3 > b);
+	print_helper(!a);
+	print_helper(!0.0);
+	print_helper(a && 0.0);
+	print_helper(0.0 || b);
+	print_helper(!nan);
+	if (0.25)
+		print_helper(1);
+	else
+		print_helper(0);
+	int n;
+	n = 0;
+	double x;
+	for (x = 1.0; x; x = x - 0.25)
+		n = n + 1;
+	print_helper(n);
+	x = 3.0;
+	while (x > 0)
+		x = x - 1.5;
+	print_double(x);
+	return 0;
+}
+
+int memory() {
+	double arr[4];
+	double *p;
+	int i;
+	for (i = 0; i < 4; i++)
+		arr[i] = i * 0.5;
+	p = arr;
+	print_double(*(p + 3));
+	print_double(p[1] + p[2]);
+	*p = 9.75;
+	print_double(arr[0]);
+	float farr[3];
+	farr[0] = 1.25f;
+	farr[1] = farr[0] * 2;
+	farr[2] = farr[1] - 10;
+	print_double(farr[0] + farr[1] + farr[2]);
+	print_double(g_d);
+	print_double(g_f);
+	print_double(g_i2d);
+	print_helper(g_d2i);
+	print_double(g_expr);
+	print_double(g_arr[0]);
+	print_double(g_arr[1]);
+	print_double(g_arr[2]);
+	print_double(g_zero);
+	g_d = g_d * 4;
+	print_double(g_d);
+	print_helper(sizeof(double));
+	print_helper(sizeof(float));
+	print_helper(sizeof(1.0f + 1));
+	print_helper(sizeof(1.0f + 1.0));
+	return 0;
+}
+
+int calls() {
+	print_double(add_d(1.25, 2));
+	print_double(mul_f(1.5f, 3.5f));
+	print_double(mixed(3, 1.5, 4, 2.5f, 5, 0.125));
+	print_double(many(1, 2, 3, 4, 5, 6, 7, 8.5));
+	print_double(add_d(add_d(1, 2), mul_f(2, 3)));
+	return 0;
+}
+
+int signed_zero() {
+	double w = 0.0;
+	double z = -0.0;
+	float f = 0.0f;
+	print_double(-0.0);
+	print_double(-w);
+	print_double(+z);
+	print_double(-f);
+	print_double(-(-w));
+	print_double(g_negzero);
+	print_double(g_fnegzero);
+	print_double(g_plus);
+	print_double(1 / -w);
+	print_helper(sizeof(-f));
+	print_helper(sizeof(+f));
+	return 0;
+}
+
+int main() {
+	literals();
+	arith();
+	convert();
+	compare();
+	memory();
+	calls();
+	signed_zero();
+	return to_int(4.9);
+}
@@ -0,0 +1,76 @@
+/*
+ * 関数ポインタと、式を介した関数の間接呼び出しのテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+
+int add(int a, int b) { return a + b; }
+int sub(int a, int b) { return a - b; }
+int mul(int a, int b) { return a * b; }
+double half(double x) { return x / 2; }
+long double ldmul(long double a, int b) { return a * b; }
+
+int (*g_op)(int, int) = add;
+int (*g_ops[3])(int, int) = {add, sub, &mul};
+
+int apply(int (*f)(int, int), int a, int b) {
+	return f(a, b);
+}
+
+int apply2(int f(int, int), int a, int b) {
+	return (*f)(a, b) + (**f)(a, b);
+}
+
+int (*pick(int i))(int, int) {
+	return g_ops[i];
+}
+
+char *hello(void) {
+	return "hello";
+}
+
+int first(const int *p, _Bool b) {
+	return p ? *p + b : -1;
+}
+
+int main() {
+	int (*fp)(int, int) = add;
+	print_helper(fp(3, 4));
+	print_helper((*fp)(3, 4));
+	fp = &sub;
+	print_helper(fp(3, 4));
+	print_helper(g_op(10, 20));
+	for (int i = 0; i < 3; i++)
+		print_helper(g_ops[i](7, 5));
+	print_helper(apply(mul, 6, 7));
+	print_helper(apply(sub, 6, 7));
+	print_helper(apply2(add, 1, 2));
+	print_helper(pick(2)(3, 3));
+	print_helper(fp == sub);
+	print_helper(fp != add);
+	print_helper(add == *add);
+	double (*dp)(double) = half;
+	print_double(dp(5.0));
+	long double (*lp)(long double, int) = ldmul;
+	print_double(lp(1.5L, 3));
+	char *(*hp)(void) = hello;
+	print_helper(hp()[1]);
+	int (*tbl[2])(int, int);
+	tbl[0] = add;
+	tbl[1] = g_ops[2];
+	print_helper(tbl[1](tbl[0](1, 2), 5));
+	void *vp = fp;
+	print_helper(vp != 0);
+	int (*const cfp)(int, int) = mul;
+	print_helper(cfp(2, 21));
+	int (*ph)(long long) = print_helper;
+	ph(123456789012);
+	// 空ポインタ定数, void *, 配列, _Bool へのポインタは引数として渡せる
+	int arr[2] = {40, 2};
+	void *any = arr;
+	int (*fst)(const int *, _Bool) = first;
+	print_helper(fst(0, 0));
+	print_helper(fst(any, any));
+	print_helper(first(arr + 1, (void *)0));
+	return 0;
+}
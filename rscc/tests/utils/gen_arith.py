@@ -0,0 +1,76 @@
+#!/usr/bin/env python3
+# tests/utils/arith.c を生成する: python3 tests/utils/gen_arith.py > tests/utils/arith.c
+# 各整数型の組み合わせについて、二項演算・シフト・単項演算・代入の結果を全て出力する
+
+TYPES = ["char", "unsigned char", "short", "unsigned short", "int", "unsigned int", "long", "unsigned long"]
+NAMES = ["c", "uc", "s", "us", "i", "ui", "l", "ul"]
+OPS = ["+", "-", "*", "/", "%", "&", "|", "^", "==", "!=", "<", "<=", ">", ">="]
+# 値の組: 負の値は unsigned な型では大きな値に変換される
+VALS = [(-7, 3), (100, -9), (2000000000, 70000)]
+LITERALS = ["2147483647", "2147483648", "4294967295u", "4294967296u", "1l", "1ul", "9223372036854775807", "18446744073709551615u"]
+
+
+# 各型の変数 a_*, b_* を宣言し、それぞれ x, y を代入する
+def declare(out, x, y):
+    for t, n in zip(TYPES, NAMES):
+        out.append(f"\t{t} a_{n};\n\t{t} b_{n};\n\ta_{n} = {x};\n\tb_{n} = {y};")
+
+
+def main():
+    out = ["/*\n * 整数拡張と通常の算術型変換のテスト: gcc でコンパイルした結果と出力を比較する\n"
+           " * tests/utils/gen_arith.py で生成しているので、直接編集しないこと\n */\n"
+           "int print_helper(long long x);\n"]
+
+    for k, (x, y) in enumerate(VALS):
+        out.append(f"int binary{k}() {{")
+        declare(out, x, y)
+        for n1 in NAMES:
+            for n2 in NAMES:
+                for op in OPS:
+                    out.append(f"\tprint_helper(a_{n1} {op} b_{n2});")
+        out.append("\treturn 0;\n}\n")
+
+    out.append("int shift() {")
+    declare(out, -100, 3)
+    for n1 in NAMES:
+        for n2 in NAMES:
+            for op in ["<<", ">>"]:
+                out.append(f"\tprint_helper(a_{n1} {op} b_{n2});")
+    out.append("\treturn 0;\n}\n")
+
+    out.append("int unary() {")
+    for t, n in zip(TYPES, NAMES):
+        out.append(f"\t{t} a_{n};\n\ta_{n} = -100;")
+        for op in ["-", "+", "~", "!"]:
+            out.append(f"\tprint_helper({op}a_{n});")
+        out.append(f"\tprint_helper(sizeof(a_{n}));\n\tprint_helper(sizeof(+a_{n}));\n\tprint_helper(sizeof(~a_{n}));")
+    out.append("\treturn 0;\n}\n")
+
+    out.append("int assign() {")
+    for t, n in zip(TYPES, NAMES):
+        out.append(f"\t{t} a_{n};\n\t{t} b_{n};")
+    for n1 in NAMES:
+        for n2 in NAMES:
+            out.append(f"\ta_{n1} = 300000; b_{n2} = -77777; a_{n1} = b_{n2}; print_helper(a_{n1});")
+            for op in ["+=", "-=", "*=", "/=", "<<="]:
+                rhs = "2" if op == "<<=" else f"b_{n2}"
+                out.append(f"\ta_{n1} = 1234567; b_{n2} = -3; a_{n1} {op} {rhs}; print_helper(a_{n1});")
+    out.append("\treturn 0;\n}\n")
+
+    out.append("long ret_long(int x) {\n\treturn x;\n}\n")
+    out.append("unsigned char ret_uchar(int x) {\n\treturn x;\n}\n")
+    out.append("int ret_int(unsigned long x) {\n\treturn x;\n}\n")
+    out.append("long arg_long(long x) {\n\treturn x;\n}\n")
+    out.append("int literal() {")
+    for lit in LITERALS:
+        out.append(f"\tprint_helper({lit});\n\tprint_helper(sizeof({lit}));\n\tprint_helper({lit} + 1);\n\tprint_helper(-1 < {lit});")
+    out.append("\tprint_helper(ret_long(-5));\n\tprint_helper(ret_uchar(-5));\n\tprint_helper(ret_int(-5));\n\tprint_helper(arg_long(-5));")
+    out.append("\tprint_helper(-1 < 1u);\n\tprint_helper(-1 < 1l);\n\tprint_helper(-1l < 1u);\n\tprint_helper(-1 > sizeof(int));")
+    out.append("\treturn 0;\n}\n")
+
+    out.append("int main() {\n\tbinary0();\n\tbinary1();\n\tbinary2();\n\tshift();\n\tunary();\n\tassign();\n\tliteral();\n\treturn 0;\n}")
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,90 @@
+/*
+ * goto とラベルのテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+int backward(int n) {
+	int s = 0;
+	int i = 0;
+loop:
+	if (i >= n)
+		goto end;
+	s += i;
+	i++;
+	goto loop;
+end:
+	return s;
+}
+
+int forward(int x) {
+	if (x > 0)
+		goto positive;
+	{
+		x = -x;
+	positive:
+		x *= 2;
+	}
+	return x;
+}
+
+int nested_break(int n) {
+	int count = 0;
+	for (int i = 0; i < n; i++)
+		for (int j = 0; j < n; j++) {
+			if (i * j > 20)
+				goto out;
+			count++;
+		}
+out:
+	return count;
+}
+
+/* GNU 拡張のラベルのアドレスによる計算型 goto */
+int interp(char *code) {
+	static void *table[] = {&&op_inc, &&op_dbl, &&op_halt};
+	int acc = 1;
+	int pc = 0;
+	goto *table[code[pc]];
+op_inc:
+	acc++;
+	pc++;
+	goto *table[code[pc]];
+op_dbl:
+	acc *= 2;
+	pc++;
+	goto *table[code[pc]];
+op_halt:
+	return acc;
+}
+
+int label_value(int k) {
+	void *p = &&a;
+	if (k)
+		p = &&b;
+	goto *p;
+a:
+	return 1;
+b:
+	return 2;
+}
+
+int main() {
+	print_helper(backward(10));
+	print_helper(forward(5));
+	print_helper(forward(-3));
+	print_helper(nested_break(10));
+	char code[6] = {0, 1, 1, 0, 1, 2};
+	print_helper(interp(code));
+	print_helper(label_value(0));
+	print_helper(label_value(1));
+	int x = 0;
+	switch (x) {
+	case 0:
+		goto skip;
+	case 1:
+	skip:
+		x = 42;
+	}
+	print_helper(x);
+	return 0;
+}
@@ -13,4 +13,24 @@ int printf_wrap(const char *fmt, char x, char c) {
 int showChar(char c1, char c2, char c3, char c4, char c5, char c6) {
 	printf("showChar called, message is \"%c%c%c%c%c%c\"\n", c1, c2, c3, c4, c5, c6);
 	return 0;
-}
\ No newline at end of file
+}
+
+int print_double(double x) {
+	printf("I got %.17g as argument.\n", x);
+	return 0;
+}
+
+int print_ldouble(long double x) {
+	printf("I got %.21Lg as argument.\n", x);
+	return 0;
+}
+
+int helper_global = 100;
+
+int call_variadic(int (*f)(int, ...)) {
+	return f(4, 1, 20, 300, 4000);
+}
+
+double call_variadic_double(double (*f)(int, ...)) {
+	return f(9, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 4.5);
+}
@@ -0,0 +1,76 @@
+/*
+ * break, continue のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+int find(int *a, int n, int x) {
+	int i;
+	for (i = 0; i < n; i++)
+		if (a[i] == x)
+			break;
+	return i;
+}
+
+int sum_odd(int n) {
+	int s = 0;
+	for (int i = 0; i < n; i++) {
+		if (i % 2 == 0)
+			continue;
+		s += i;
+	}
+	return s;
+}
+
+int nested(int n) {
+	int count = 0;
+	for (int i = 0; i < n; i++) {
+		int j = 0;
+		while (1) {
+			j++;
+			if (j > i)
+				break;
+			if (j % 3 == 0)
+				continue;
+			count += j;
+		}
+		if (i == 7)
+			break;
+	}
+	return count;
+}
+
+int while_continue(int n) {
+	int i = 0;
+	int s = 0;
+	while (i < n) {
+		i++;
+		if (i % 4 == 0)
+			continue;
+		s += i;
+	}
+	return s;
+}
+
+int main() {
+	int a[6] = {3, 1, 4, 1, 5, 9};
+	print_helper(find(a, 6, 5));
+	print_helper(find(a, 6, 7));
+	print_helper(sum_odd(10));
+	print_helper(nested(20));
+	print_helper(while_continue(20));
+	int k = 0;
+	for (;;) {
+		if (++k >= 10)
+			break;
+	}
+	print_helper(k);
+	for (int i = 0; i < 3; i++) {
+		int n = i + 2;
+		int v[n];
+		v[0] = i;
+		if (i == 1)
+			continue;
+		print_helper(v[0] + sizeof(v));
+	}
+	return 0;
+}
@@ -0,0 +1,233 @@
+/*
+ * long double のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+int print_ldouble(long double x);
+
+long double g_ld = 0.1L;
+long double g_neg = -1.1L;
+long double g_from_d = 0.1;
+long double g_int = 42;
+long double g_arr[3] = {1.5L, 2, 0.3L};
+double long g_zero;
+char g_pad = 1;
+long double g_aligned = 3.25L;
+double g_d = 2.5L;
+long double g_third = 1.0L / 3;
+long double g_umax = 18446744073709551615UL;
+long double g_odd = 9007199254740993L;
+long double g_negzero = -0.0L;
+long double g_folded = (0.1L + 0.2L) * 3 - 1;
+long double g_tiny = 1e-4940L;
+long double g_huge = 1e4933L;
+double g_from_ld = 1.0L / 3;
+unsigned long g_ul = 18446744073709551615.0L;
+
+long double add_ld(long double a, long double b) {
+	return a + b;
+}
+
+long double mixed(int a, long double b, double c, long double d, long e, float f) {
+	return a * b - c / d + e * f;
+}
+
+int arith() {
+	long double a;
+	long double b;
+	a = 10.5L;
+	b = 3;
+	print_ldouble(a + b);
+	print_ldouble(a - b);
+	print_ldouble(a * b);
+	print_ldouble(a / b);
+	print_ldouble(-a);
+	print_ldouble(1.0L / 3);
+	print_ldouble(0.1L);
+	a += 0.25L;
+	print_ldouble(a);
+	a -= 1;
+	a *= 2;
+	a /= 7;
+	print_ldouble(a);
+	a++;
+	print_ldouble(a);
+	double d;
+	d = 1.0 / 3;
+	print_ldouble(d);
+	print_ldouble(a + d);
+	float f;
+	f = 0.1f;
+	print_ldouble(f);
+	print_ldouble(f * b);
+	long double c;
+	c = a = b = 2.75L;
+	print_ldouble(a + b + c);
+	return 0;
+}
+
+int convert() {
+	long double x;
+	x = -7.9L;
+	int i;
+	i = x;
+	print_helper(i);
+	char ch;
+	ch = x;
+	print_helper(ch);
+	unsigned short us;
+	us = 65535.9L;
+	print_helper(us);
+	long l;
+	l = -123456789012345.75L;
+	print_helper(l);
+	unsigned long ul;
+	ul = 18446744073709551615.0L;
+	print_helper(ul);
+	ul = 9223372036854775808.0L;
+	print_helper(ul / 2);
+	ul = 1234.5L;
+	print_helper(ul);
+	ul = 18446744073709551615ul;
+	x = ul;
+	print_ldouble(x);
+	ul = 9223372036854775807ul;
+	x = ul;
+	print_ldouble(x);
+	x = -5;
+	print_ldouble(x);
+	unsigned u;
+	u = 4294967295u;
+	x = u;
+	print_ldouble(x);
+	double d;
+	d = 0.1L;
+	print_double(d);
+	float f;
+	f = 0.1L;
+	print_double(f);
+	_Bool b;
+	b = x;
+	print_helper(b);
+	b = 0.0L;
+	print_helper(b);
+	return 0;
+}
+
+int compare() {
+	long double a;
+	long double b;
+	long double nan;
+	a = 1.5L;
+	b = 2.5L;
+	nan = 0.0L / 0.0L;
+	print_helper(a < b);
+	print_helper(a > b);
+	print_helper(a <= 1.5L);
+	print_helper(a >= 1.5L);
+	print_helper(a == 1.5L);
+	print_helper(a != 1.5L);
+	print_helper(nan == nan);
+	print_helper(nan != nan);
+	print_helper(nan < a);
+	print_helper(nan <= a);
+	print_helper(nan > a);
+	print_helper(nan >= a);
+	print_helper(a < 2);
+	print_helper(!a);
+	print_helper(a && 0.0L);
+	print_helper(0.0L || b);
+	int n;
+	n = 0;
+	long double x;
+	for (x = 0; x < 1; x = x + 0.125L)
+		n = n + 1;
+	print_helper(n);
+	if (b - 2.5L)
+		print_helper(1);
+	else
+		print_helper(0);
+	return 0;
+}
+
+int discard() {
+	long double x;
+	int i;
+	/* 値を捨てる式を多く評価しても x87 のスタックが溢れないことを確認する */
+	for (i = 0; i < 20; i++) {
+		x = i;
+		x + 1;
+		add_ld(x, x);
+		(void)x;
+		x, x;
+	}
+	print_ldouble(x);
+	return 0;
+}
+
+int memory() {
+	long double arr[4];
+	long double *p;
+	int i;
+	for (i = 0; i < 4; i++)
+		arr[i] = i / 3.0L;
+	p = arr;
+	print_ldouble(*(p + 3));
+	print_ldouble(p[1] + p[2]);
+	print_helper(&arr[1] - &arr[0]);
+	print_helper(sizeof(long double));
+	print_helper(sizeof(arr));
+	print_helper(sizeof(1.0L + 1.0));
+	print_ldouble(g_ld);
+	print_ldouble(g_neg);
+	print_ldouble(g_from_d);
+	print_ldouble(g_int);
+	print_ldouble(g_arr[0]);
+	print_ldouble(g_arr[1]);
+	print_ldouble(g_arr[2]);
+	print_ldouble(g_zero);
+	print_ldouble(g_aligned);
+	print_double(g_d);
+	print_ldouble(g_third);
+	print_ldouble(g_umax);
+	print_ldouble(g_odd);
+	print_ldouble(g_negzero);
+	print_ldouble(1 / g_negzero);
+	print_ldouble(g_folded);
+	print_ldouble(g_tiny);
+	print_ldouble(g_huge);
+	print_double(g_from_ld);
+	print_helper(g_ul);
+	return 0;
+}
+
+int calls() {
+	print_ldouble(add_ld(1.25L, 2));
+	print_ldouble(add_ld(0.1L, 0.2L));
+	print_ldouble(mixed(3, 1.5L, 4.0, 0.5L, 7, 0.25f));
+	print_ldouble(add_ld(add_ld(1, 2), add_ld(3, 4)));
+	return 0;
+}
+
+int signed_zero() {
+	long double w = 0;
+	long double z = -0.0L;
+	print_ldouble(-w);
+	print_ldouble(+z);
+	print_ldouble(-(-w));
+	print_ldouble(-g_ld);
+	print_ldouble(1 / -w);
+	print_helper(sizeof(+z));
+	return 0;
+}
+
+int main() {
+	arith();
+	convert();
+	compare();
+	discard();
+	memory();
+	calls();
+	signed_zero();
+	return 0;
+}
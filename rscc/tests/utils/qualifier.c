@@ -0,0 +1,89 @@
+/*
+ * 型修飾子(const, volatile, restrict)のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+const int g_limit = 10;
+const char g_msg[] = "const";
+const int g_table[4] = {1, 2, 4, 8};
+int const g_zero;
+int g_var = 3;
+int *const g_ptr = &g_var;
+const int *g_cptr = &g_limit;
+volatile int g_counter;
+const volatile long g_cv = 7;
+
+int sum(const int *restrict p, int n) {
+	int s = 0;
+	for (int i = 0; i < n; i++)
+		s += p[i];
+	return s;
+}
+
+const int *pick(const int *a, const int *b, int first) {
+	if (first)
+		return a;
+	return b;
+}
+
+void fill(int *restrict dst, const int *restrict src, int n) {
+	for (int i = 0; i < n; i++)
+		dst[i] = src[i] * 2;
+}
+
+int main() {
+	const int x = 5;
+	int const y = x + 1;
+	const int *p = &x;
+	int z = 0;
+	int *const q = &z;
+	const int *const r = &y;
+	volatile int v = 0;
+	volatile int *vp = &v;
+	const volatile unsigned char cv = 200;
+	int arr[4];
+	const int carr[3] = {3, 1, 4};
+
+	print_helper(x + y);
+	print_helper(*p + *r);
+	*q = 42;
+	print_helper(z);
+	p = &z;
+	print_helper(*p);
+	p++;
+	p = &y;
+	print_helper(*p);
+
+	for (int i = 0; i < 100; i++)
+		v++;
+	print_helper(v);
+	*vp = *vp + 1;
+	v;
+	print_helper(*vp);
+	print_helper(cv);
+
+	print_helper(g_limit);
+	print_helper(sizeof(g_msg));
+	print_helper(g_msg[2]);
+	print_helper(sum(g_table, 4));
+	print_helper(g_zero);
+	*g_ptr = 30;
+	print_helper(g_var);
+	print_helper(*g_cptr);
+	g_cptr = g_table + 3;
+	print_helper(*g_cptr);
+	g_counter += 5;
+	g_counter++;
+	print_helper(g_counter);
+	print_helper(g_cv);
+
+	print_helper(*pick(&x, carr, 0));
+	print_helper(*pick(&x, carr, 1));
+	fill(arr, carr, 3);
+	print_helper(sum(arr, 3));
+	print_helper(sum(carr, 3));
+	const void *cvp = &x;
+	const int *back = cvp;
+	print_helper(*back);
+	return 0;
+}
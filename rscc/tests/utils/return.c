@@ -0,0 +1,92 @@
+/*
+ * return 文の解析のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+int sign(int x) {
+	if (x > 0) {
+		return 1;
+	} else if (x < 0) {
+		return -1;
+	} else {
+		return 0;
+	}
+}
+
+int first_over(int limit) {
+	int i = 0;
+	while (1) {
+		if (i * i > limit)
+			return i;
+		i++;
+	}
+}
+
+int loop_forever(int n) {
+	for (;;) {
+		n += 3;
+		if (n % 7 == 0)
+			return n;
+	}
+}
+
+int classify(int x) {
+	switch (x) {
+	case 0:
+		return 10;
+	case 1:
+	case 2:
+		return 20;
+	default:
+		return 30;
+	}
+}
+
+int once(int x) {
+	do {
+		return x * 2;
+	} while (x);
+}
+
+int jump(int x) {
+	goto out;
+	x = 100;
+out:
+	return x + 1;
+}
+
+int nested_label(int x) {
+	if (x)
+		goto found;
+	return 0;
+	{
+	found:
+		return x + 1;
+	}
+}
+
+void fill(int *p, int n) {
+	for (int i = 0; i < n; i++) {
+		if (i > 3)
+			return;
+		p[i] = i;
+	}
+}
+
+int main() {
+	int a[5] = {9, 9, 9, 9, 9};
+	print_helper(sign(5));
+	print_helper(sign(-3));
+	print_helper(sign(0));
+	print_helper(first_over(50));
+	print_helper(loop_forever(1));
+	print_helper(classify(0));
+	print_helper(classify(2));
+	print_helper(classify(7));
+	print_helper(once(21));
+	print_helper(jump(4));
+	print_helper(nested_label(0));
+	print_helper(nested_label(6));
+	fill(a, 5);
+	print_helper(a[0] + a[1] + a[2] + a[3] + a[4]);
+}
@@ -0,0 +1,43 @@
+/*
+ * 7つ以上の引数(スタック渡し)のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+void print_double(double x);
+
+long many(int a, long b, short c, char d, long e, int f, int g, long h, char i, short j) {
+	return a + 2 * b + 3 * c + 4 * d + 5 * e + 6 * f + 7 * g + 8 * h + 9 * i + 10 * j;
+}
+
+double fmany(double a, double b, double c, double d, double e, double f, double g, double h,
+             double i, float j, double k) {
+	return a - b + c - d + e - f + g - h + i * 100 + j * 1000 + k * 10000;
+}
+
+double mixed(int a, double b, int c, double d, int e, int f, int g, int h, int i, long double x,
+             int j, float k) {
+	return a + b + c + d + e + f + g + h + i + x + j + k;
+}
+
+int last(int a, int b, int c, int d, int e, int f, int g, int *h) {
+	return *h = a + b + c + d + e + f + g;
+}
+
+long rec(int n, int a, int b, int c, int d, int e, int f, int g) {
+	if (n == 0)
+		return g;
+	return rec(n - 1, a, b, c, d, e, f, g + n);
+}
+
+int main() {
+	int v = 0;
+	int (*fp)(int, int, int, int, int, int, int, int *) = last;
+	print_helper(many(1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
+	print_helper(many(-1, -2, -3, -4, -5, -6, -7, -8, -9, -10));
+	print_double(fmany(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11));
+	print_double(mixed(1, 2.5, 3, 4.5, 5, 6, 7, 8, 9, 10.25L, 11, 12.5f));
+	print_helper(fp(1, 2, 3, 4, 5, 6, 7, &v));
+	print_helper(v);
+	print_helper(rec(5, 0, 0, 0, 0, 0, 0, 100));
+	print_helper(many(rec(1, 1, 1, 1, 1, 1, 1, 1), 2, 3, 4, 5, 6, 7, 8, 9, many(1, 2, 3, 4, 5, 6, 7, 8, 9, 10)));
+	return 0;
+}
@@ -0,0 +1,118 @@
+/*
+ * 可変長引数と stdarg.h のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+#include <stdarg.h>
+
+int print_helper(long long x);
+void print_double(double x);
+void print_ldouble(long double x);
+int printf(const char *fmt, ...);
+int vprintf(const char *fmt, va_list ap);
+int call_variadic(int (*f)(int, ...));
+double call_variadic_double(double (*f)(int, ...));
+
+int sum(int n, ...) {
+	va_list ap;
+	va_start(ap, n);
+	int s = 0;
+	for (int i = 0; i < n; i++)
+		s += va_arg(ap, int);
+	va_end(ap);
+	return s;
+}
+
+double dsum(int n, ...) {
+	va_list ap;
+	va_start(ap, n);
+	double s = 0;
+	for (int i = 0; i < n; i++)
+		s += va_arg(ap, double);
+	va_end(ap);
+	return s;
+}
+
+/* i: int, l: long, d: double, L: long double, p: char * */
+void show(const char *fmt, ...) {
+	va_list ap;
+	va_start(ap, fmt);
+	for (; *fmt; fmt++) {
+		switch (*fmt) {
+		case 'i':
+			print_helper(va_arg(ap, int));
+			break;
+		case 'l':
+			print_helper(va_arg(ap, long));
+			break;
+		case 'd':
+			print_double(va_arg(ap, double));
+			break;
+		case 'L':
+			print_ldouble(va_arg(ap, long double));
+			break;
+		case 'p':
+			print_helper(*va_arg(ap, char *));
+			break;
+		}
+	}
+	va_end(ap);
+}
+
+/* 名前付きの引数だけでレジスタを使い切る場合 */
+long after_many(int a, int b, int c, int d, int e, int f, int g, double x0, double x1, double x2,
+                double x3, double x4, double x5, double x6, double x7, double x8, ...) {
+	va_list ap;
+	va_start(ap, x8);
+	long s = a + b + c + d + e + f + g + (long)(x0 + x1 + x2 + x3 + x4 + x5 + x6 + x7 + x8);
+	s += va_arg(ap, int) * 1000;
+	s += (long)va_arg(ap, double) * 100000;
+	s += va_arg(ap, long);
+	va_end(ap);
+	return s;
+}
+
+int vsum(int n, va_list ap) {
+	int s = 0;
+	for (int i = 0; i < n; i++)
+		s += va_arg(ap, int);
+	return s;
+}
+
+int twice(int n, ...) {
+	va_list ap, aq;
+	va_start(ap, n);
+	va_copy(aq, ap);
+	int s = vsum(n, ap);
+	s += vsum(n, aq);
+	va_end(aq);
+	va_end(ap);
+	return s;
+}
+
+void logf(const char *fmt, ...) {
+	va_list ap;
+	va_start(ap, fmt);
+	vprintf(fmt, ap);
+	va_end(ap);
+}
+
+int main() {
+	char c = 'x';
+	short sh = -3;
+	float f = 1.5f;
+	print_helper(sum(0));
+	print_helper(sum(3, 1, 2, 3));
+	print_helper(sum(10, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10));
+	print_double(dsum(3, 0.5, 1.25, 2.0));
+	print_double(dsum(10, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, f));
+	show("ildLpi", 1, 1L << 40, 2.5, 3.25L, "hello", c);
+	show("dididididididididi", 0.5, 1, 1.5, 2, 2.5, 3, 3.5, 4, 4.5, 5, 5.5, 6, 6.5, 7, 7.5, 8, 8.5, sh);
+	print_helper(after_many(1, 2, 3, 4, 5, 6, 7, 1, 2, 3, 4, 5, 6, 7, 8, 9, 4, 5.0, 6L));
+	print_helper(twice(4, 1, 2, 3, 4));
+	int (*fp)(int, ...) = sum;
+	print_helper(fp(2, 7, 8));
+	print_helper(call_variadic(sum));
+	print_double(call_variadic_double(dsum));
+	printf("%d %c %s %.2f %ld\n", 42, c, "printf", f, 1L << 35);
+	logf("%d-%d-%d %g %s\n", sh, 2, 3, 0.25, "vprintf");
+	return 0;
+}
@@ -0,0 +1,75 @@
+/*
+ * 記憶域クラス指定子(static, extern)のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+extern int helper_global;
+extern int late_defined;
+static int file_count = 5;
+static long file_arr[3];
+static int twice(int x);
+
+int counter() {
+	static int count;
+	count = count + 1;
+	return count;
+}
+
+int counter_init() {
+	static int count = 10;
+	static int *p = &count;
+	*p += 10;
+	return count;
+}
+
+int shadow() {
+	static int x = 1;
+	{
+		static int x = 100;
+		x++;
+		print_helper(x);
+	}
+	return x++;
+}
+
+static int twice(int x) { return 2 * x; }
+
+static char *hello() {
+	static char msg[] = "hello";
+	msg[0]++;
+	return msg;
+}
+
+int use_extern() {
+	extern int helper_global;
+	int add_ten(int);
+	return add_ten(helper_global);
+}
+
+int add_ten(int x) { return x + 10; }
+
+int late_defined = 7;
+
+int main() {
+	counter();
+	counter();
+	print_helper(counter());
+	counter_init();
+	print_helper(counter_init());
+	shadow();
+	print_helper(shadow());
+	print_helper(twice(21));
+	print_helper(file_count++);
+	print_helper(file_count);
+	file_arr[2] = 9;
+	print_helper(file_arr[0] + file_arr[2]);
+	print_helper(hello()[0]);
+	print_helper(hello()[0]);
+	print_helper(helper_global);
+	helper_global = 1;
+	print_helper(use_extern());
+	print_helper(late_defined);
+	int (*fp)(int) = twice;
+	print_helper(fp(late_defined));
+	return counter();
+}
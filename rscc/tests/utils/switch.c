@@ -0,0 +1,141 @@
+/*
+ * switch 文のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+/* case が密集しているのでジャンプテーブルになる */
+int dense(int x) {
+	switch (x) {
+	case 0:
+		return 10;
+	case 1:
+	case 2:
+		return 20;
+	case 3:
+		x += 100;
+	case 5:
+		return x + 1;
+	case 6:
+		break;
+	default:
+		return -1;
+	}
+	return 60;
+}
+
+/* case がまばらなので比較の連鎖になる */
+long sparse(long x) {
+	long r = 0;
+	switch (x) {
+	case -1000:
+		r = 1;
+		break;
+	case 7:
+		r = 2;
+		break;
+	case 100000:
+		r = 3;
+		break;
+	case 10000000000:
+		r = 4;
+		break;
+	}
+	return r;
+}
+
+int state_machine(char *s) {
+	int state = 0;
+	int count = 0;
+	for (int i = 0; s[i]; i++) {
+		switch (state) {
+		case 0:
+			if (s[i] == 'a')
+				state = 1;
+			break;
+		case 1:
+			if (s[i] == 'b')
+				state = 2;
+			else if (s[i] != 'a')
+				state = 0;
+			break;
+		case 2:
+			count++;
+			state = 0;
+			continue;
+		}
+		count += 100;
+	}
+	return count;
+}
+
+int chars(char c) {
+	switch (c) {
+	case 'a': return 1;
+	case 'b': return 2;
+	case 'c': return 3;
+	case 'd': return 4;
+	case -1: return 5;
+	}
+	return 0;
+}
+
+unsigned neg(unsigned x) {
+	switch (x) {
+	case -1:
+		return 1;
+	case 0:
+		return 2;
+	}
+	return 3;
+}
+
+int nested(int a, int b) {
+	switch (a) {
+	case 1:
+		switch (b) {
+		case 1:
+			return 11;
+		default:
+			break;
+		}
+		return 10;
+	default:
+		switch (b) {
+		case 2:
+			return 22;
+		}
+	}
+	return 0;
+}
+
+int main() {
+	for (int i = -2; i < 9; i++)
+		print_helper(dense(i));
+	print_helper(sparse(-1000));
+	print_helper(sparse(7));
+	print_helper(sparse(100000));
+	print_helper(sparse(10000000000));
+	print_helper(sparse(8));
+	print_helper(state_machine("xxabyabab"));
+	print_helper(chars('c'));
+	print_helper(chars(-1));
+	print_helper(chars('z'));
+	print_helper(neg(4294967295));
+	print_helper(neg(0));
+	print_helper(neg(5));
+	print_helper(nested(1, 1));
+	print_helper(nested(1, 2));
+	print_helper(nested(2, 2));
+	print_helper(nested(2, 3));
+	int s = 0;
+	switch (3) {
+		s = 100;
+	case 3:
+		s += 1;
+	}
+	print_helper(s);
+	switch (1)
+	default:
+		print_helper(42);
+	return 0;
+}
@@ -0,0 +1,58 @@
+/*
+ * 仮定義(tentative definition)のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+
+int x;
+int x;
+int x = 3;
+int x;
+
+int y;
+int y;
+
+long arr[];
+long arr[4];
+
+int z[];
+
+extern int w;
+int w;
+int w = 8;
+
+static int s;
+static int s;
+extern int s;
+
+double d;
+long double ld;
+char *ptr;
+char *ptr = 0;
+
+const int cq;
+const int cq = 6;
+const char *cptr;
+const char *cptr = "qualified";
+
+int twice_x() { return x * 2; }
+
+int main() {
+	print_helper(x);
+	print_helper(cq + cptr[1]);
+	print_helper(twice_x());
+	print_helper(y);
+	y = 5;
+	print_helper(y);
+	arr[3] = 11;
+	print_helper(arr[0] + arr[3]);
+	print_helper(sizeof(arr));
+	z[0] = 4;
+	print_helper(z[0]);
+	print_helper(w);
+	s = 6;
+	print_helper(s);
+	print_helper(d == 0);
+	print_helper(ld == 0);
+	print_helper(ptr == 0);
+	return x + y;
+}
@@ -0,0 +1,65 @@
+/*
+ * 引数の情報を持たない関数宣言と、ポインタを返す外部の関数のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double();
+char *strchr(const char *s, int c);
+long strtol(const char *s, char **end, int base);
+unsigned long strlen(const char *s);
+void *memset(void *p, int c, unsigned long n);
+
+int show_int();
+long add();
+
+long use_add() {
+	return add(40000000000L, 2L);
+}
+
+long add(long a, long b) {
+	return a + b;
+}
+
+int show_int(int x) {
+	return print_helper(x);
+}
+
+int one() {
+	return 1;
+}
+
+int five(void);
+int five() {
+	return 5;
+}
+
+double half(double x) {
+	return x / 2;
+}
+
+int main() {
+	float f = 2.5f;
+	char c = -7;
+	short s = 300;
+	print_double(f);
+	show_int(c);
+	show_int(s);
+	double (*fp)() = half;
+	print_double(fp(f));
+	print_helper(use_add());
+	print_helper(one(2) + one(3.5, 'x'));
+	print_helper(five());
+
+	char *str = "rscc compiler";
+	char *p = strchr(str, 'c');
+	print_helper(p - str);
+	char *end;
+	print_helper(strtol("12345xyz", &end, 10));
+	print_helper(*end);
+	print_helper(strlen(strchr(str, 'p')));
+	char buf[8];
+	char *q = memset(buf, 'a', 7);
+	buf[7] = 0;
+	print_helper(q == buf);
+	print_helper(strlen(q));
+	return 0;
+}
@@ -0,0 +1,227 @@
+/*
+ * 可変長配列と __builtin_alloca のテスト: gcc でコンパイルした結果と出力を比較する
+ */
+int print_helper(long long x);
+int print_double(double x);
+
+int deref_len(int *p);
+int star_param(int n, int a[*]);
+int g_len = 3;
+int *gp = &g_len;
+
+long sum(int n, int m) {
+	int a[n][m];
+	for (int i = 0; i < n; i = i + 1)
+		for (int j = 0; j < m; j = j + 1)
+			a[i][j] = i * 10 + j;
+	print_helper(sizeof(a));
+	print_helper(sizeof(a[0]));
+	print_helper(sizeof a / sizeof a[0]);
+	long s = 0;
+	for (int i = 0; i < n; i = i + 1)
+		for (int j = 0; j < m; j = j + 1)
+			s += a[i][j];
+	return s + a[n - 1][m - 1];
+}
+
+void matmul(int n, double a[n][n], double b[n][n], double c[n][n]) {
+	for (int i = 0; i < n; i++)
+		for (int j = 0; j < n; j++) {
+			double t = 0;
+			for (int k = 0; k < n; k++)
+				t += a[i][k] * b[k][j];
+			c[i][j] = t;
+		}
+}
+
+double trace(int n, double (*m)[n]) {
+	double t = 0;
+	for (int i = 0; i < n; i++)
+		t += m[i][i];
+	return t;
+}
+
+int fixed_inner(int n) {
+	char buf[n][3];
+	int (*p)[n] = 0;
+	print_helper(sizeof(buf));
+	print_helper((long)(p + 1));
+	print_helper(&buf[2][0] - &buf[0][0]);
+	return sizeof(int[n][2]);
+}
+
+int loop_vla(int n) {
+	long first = 0;
+	long last = 0;
+	for (int i = 0; i < 1000; i++) {
+		char big[n];
+		big[0] = i;
+		if (i == 0)
+			first = (long)big;
+		last = (long)big;
+	}
+	return first == last;
+}
+
+int break_vla(int n) {
+	long first = 0;
+	long last = 0;
+	for (int i = 0; i < 100000; i++) {
+		for (int j = 0;; j++) {
+			char big[n];
+			big[0] = j;
+			if (i == 0)
+				first = (long)big;
+			last = (long)big;
+			break;
+		}
+	}
+	return first == last;
+}
+
+int continue_vla(int n) {
+	long first = 0;
+	long last = 0;
+	int s = 0;
+	for (int i = 0; i < 100000; i++) {
+		int v[n];
+		v[n - 1] = i;
+		if (i == 0)
+			first = (long)v;
+		last = (long)v;
+		if (i % 2)
+			continue;
+		s += v[n - 1] % 7;
+	}
+	return (first == last) + s;
+}
+
+int goto_vla(int n) {
+	long first = 0;
+	long last = 0;
+	int i = 0;
+top:
+	if (i < 100000) {
+		int v[n];
+		v[0] = i;
+		if (i == 0)
+			first = (long)v;
+		last = (long)v;
+		i++;
+		goto top;
+	}
+	return first == last;
+}
+
+int goto_back_vla(int n) {
+	int i = 0;
+	long first = 0;
+	long last = 0;
+	{
+	again:;
+		char big[n];
+		big[0] = i;
+		if (i == 0)
+			first = (long)big;
+		last = (long)big;
+		if (++i < 100000)
+			goto again;
+	}
+	return first == last;
+}
+
+int switch_vla(int n) {
+	long first = 0;
+	long last = 0;
+	int s = 0;
+	for (int i = 0; i < 100000; i++) {
+		switch (i % 3) {
+		case 0: {
+			int v[n];
+			v[0] = i;
+			if (i == 0)
+				first = (long)v;
+			last = (long)v;
+			s += v[0] % 5;
+			break;
+		}
+		default:
+			s++;
+		}
+	}
+	return (first == last) + s;
+}
+
+int deref_len(int *p) {
+	int a[*p];
+	char buf[*gp * 2];
+	a[*p - 1] = 7;
+	return sizeof(a) + sizeof(buf) + a[*p - 1];
+}
+
+int star_param(int n, int a[n]) {
+	return a[n - 1];
+}
+
+int switch_in_vla(int n) {
+	int v[n];
+	v[n - 1] = n;
+	switch (n) {
+	case 2:
+		return v[1] * 10;
+	default: {
+		int w[n];
+		w[0] = v[n - 1];
+		return w[0] + 1;
+	}
+	}
+}
+
+int use_alloca(int n) {
+	int *p = __builtin_alloca(n * sizeof(int));
+	for (int i = 0; i < n; i++)
+		p[i] = i * i;
+	int s = 0;
+	for (int i = 0; i < n; i++)
+		s += p[i];
+	print_helper((long)p % 16);
+	return s + 1000 * (1 + (long)__builtin_alloca(8) % 16);
+}
+
+int mixed(int n) {
+	return n + (int)(long)__builtin_alloca(24) % 16 + n * 2;
+}
+
+int main() {
+	print_helper(sum(3, 4));
+	int n = 3;
+	double a[n][n], b[n][n], c[n][n];
+	for (int i = 0; i < n; i++)
+		for (int j = 0; j < n; j++) {
+			a[i][j] = i + j;
+			b[i][j] = (i == j) * 2;
+		}
+	matmul(n, a, b, c);
+	print_double(c[1][2]);
+	print_double(trace(n, c));
+	print_helper(fixed_inner(5));
+	print_helper(loop_vla(100));
+	print_helper(break_vla(1 << 16));
+	print_helper(continue_vla(1 << 14));
+	print_helper(goto_vla(1 << 14));
+	print_helper(goto_back_vla(1 << 16));
+	print_helper(switch_vla(1 << 14));
+	int len = 5;
+	print_helper(deref_len(&len));
+	int arr[4] = {1, 2, 3, 4};
+	print_helper(star_param(4, arr));
+	print_helper(switch_in_vla(2));
+	print_helper(switch_in_vla(5));
+	print_helper(use_alloca(10));
+	print_helper(mixed(7));
+	int k = 2;
+	int v[k + 1];
+	v[k] = 42;
+	print_helper(v[2] + sizeof(v));
+	return 0;
+}